@@ -6,6 +6,7 @@ const cli = @import("../cli/root.zig");
 const commands = @import("../commands/root.zig");
 const config = @import("../config/root.zig");
 const ipc = @import("../ipc/root.zig");
+const logging = @import("../logging.zig");
 const modes = @import("../modes/root.zig");
 const terminal = @import("../terminal/root.zig");
 const test_ansi = @import("../test_support/ansi.zig");
@@ -29,6 +30,13 @@ pub fn exitCodeForError(err: anyerror) u8 {
         error.InvalidBool,
         error.ClientUnifiedConflict,
         error.MultipleUnifiedOrientations,
+        error.TooManyConfigFiles,
+        error.InvalidSetFlag,
+        error.TooManySetOverrides,
+        error.UnknownSetProcess,
+        error.MissingWaitFor,
+        error.UnknownRunFlag,
+        error.InvalidShutdownGrace,
         => 2,
         else => 1,
     };
@@ -46,16 +54,58 @@ pub fn shouldPrintGenericError(err: anyerror) bool {
         error.MissingName,
         error.UnknownSignalCommand,
         error.CommandFailed,
+        error.MissingWaitFor,
+        error.UnknownRunFlag,
+        error.InvalidShutdownGrace,
         => false,
         else => true,
     };
 }
 
-pub fn run(allocator: std.mem.Allocator, args: []const []const u8, output: Output) !void {
+/// Returns the stderr text for errors that reach the entrypoint. Startup failures
+/// get an actionable sentence; anything else falls back to the error name.
+pub fn genericErrorMessage(err: anyerror) []const u8 {
+    return switch (err) {
+        error.ConfigFileNotFound => "no config file found (looked for proctmux.yaml, proctmux.yml, procmux.yaml, procmux.yml)",
+        error.ConfigPathNotFound => "config file not found at the path given by -f or PROCTMUX_CONFIG",
+        error.ParseFailure => "config file is not valid YAML",
+        error.TypeMismatch => "config file has a value of the wrong type",
+        error.DuplicateMapKey => "config file repeats a key in one map (for example two processes with the same name)",
+        error.TooManyConfigFiles => std.fmt.comptimePrint("at most {d} -f config files may be given", .{cli.max_config_files}),
+        error.InvalidSetFlag => "--set expects <process>.<key>=<value>",
+        error.TooManySetOverrides => std.fmt.comptimePrint("at most {d} --set flags may be given", .{cli.max_set_overrides}),
+        error.UnknownSetProcess => "--set names a process that is not defined in the config",
+        error.IncludeCycle => "config include entries form a cycle",
+        error.IncludeNotFound => "a file named by a config include was not found",
+        error.DependencyCycle => "process depends_on entries form a cycle",
+        error.InvalidHotkey => "a process hotkey must be a single printable character",
+        error.UnknownColor => "a process color must be a color name, a 0-15 index, or a #rrggbb value",
+        error.HotkeyConflict => "a process hotkey is used by another process or keybinding",
+        error.MissingProcessCommand => "a process has no shell or cmd to run",
+        error.UnknownKeybindingAction => "keybinding names an unknown action",
+        error.InvalidRestartPolicy => "a process restart policy must be never, always or on-failure",
+        error.InvalidStartWhenRunning => "start_when_running must be notice or focus",
+        error.ProcessNotFound => "no process with that name is defined in the config",
+        error.SocketWaitTimeout => "timed out waiting for the primary server socket",
+        error.SetupFailed => "the setup command failed; its stderr is logged above",
+        error.PrimaryServerGone => "the primary server exited or its socket was closed; the client has shut down",
+        error.MissingShell => "completions needs a shell name: " ++ commands.completions.supported_shells,
+        error.UnsupportedShell => "unsupported shell for completions; expected " ++ commands.completions.supported_shells,
+        else => @errorName(err),
+    };
+}
+
+/// Runs the binary's arguments and returns the status to exit with.
+pub fn run(allocator: std.mem.Allocator, args: []const []const u8, output: Output) !u8 {
     var stdin = std.fs.File.stdin();
-    var terminal_mode = terminal.mode.Mode.enterIfNeeded(argsNeedRawTerminal(args), stdin.handle);
+    const runtime_mode = argsNeedRawTerminal(args);
+    var terminal_mode = terminal.mode.Mode.enterIfNeeded(runtime_mode, stdin.handle);
     defer terminal_mode.restore();
-    try runWithInput(allocator, args, FileInput.reader(&stdin), output);
+    if (runtime_mode or argsRunHeadless(args)) try terminal.shutdown.install();
+    const cleanup_slot = try terminal.shutdown.register(terminal_mode.cleanup());
+    defer terminal.shutdown.unregister(cleanup_slot);
+    var stopped = std.atomic.Value(bool).init(false);
+    return runInDirUntilStoppedWithInput(allocator, std.fs.cwd(), args, FileInput.reader(&stdin), output, &stopped);
 }
 
 pub fn runWithInput(allocator: std.mem.Allocator, args: []const []const u8, input: Input, output: Output) !void {
@@ -68,7 +118,7 @@ pub fn runInDir(allocator: std.mem.Allocator, dir: std.fs.Dir, args: []const []c
 
 pub fn runInDirWithInput(allocator: std.mem.Allocator, dir: std.fs.Dir, args: []const []const u8, input: Input, output: Output) !void {
     var stopped = std.atomic.Value(bool).init(false);
-    try runInDirUntilStoppedWithInput(allocator, dir, args, input, output, &stopped);
+    _ = try runInDirUntilStoppedWithInput(allocator, dir, args, input, output, &stopped);
 }
 
 pub fn runInDirUntilStopped(
@@ -78,11 +128,12 @@ pub fn runInDirUntilStopped(
     output: Output,
     stopped: *std.atomic.Value(bool),
 ) !void {
-    try runInDirUntilStoppedWithInput(allocator, dir, args, EmptyInput.reader(), output, stopped);
+    _ = try runInDirUntilStoppedWithInput(allocator, dir, args, EmptyInput.reader(), output, stopped);
 }
 
 /// Shared app runner used by production and tests. The explicit directory,
-/// input, output, and stop flag keep runtime side effects injectable.
+/// input, output, and stop flag keep runtime side effects injectable. Returns
+/// the exit status for a successful run; only `run` reports a non-zero one.
 pub fn runInDirUntilStoppedWithInput(
     allocator: std.mem.Allocator,
     dir: std.fs.Dir,
@@ -90,7 +141,7 @@ pub fn runInDirUntilStoppedWithInput(
     input: Input,
     output: Output,
     stopped: *std.atomic.Value(bool),
-) !void {
+) !u8 {
     const parsed = cli.parse(args) catch |err| switch (err) {
         error.DeprecatedFlag => {
             if (cli.deprecatedFlagMessage(args)) |message| {
@@ -101,7 +152,7 @@ pub fn runInDirUntilStoppedWithInput(
         },
         error.HelpRequested => {
             try output.writeAll(cli.usage_text);
-            return;
+            return 0;
         },
         error.ClientUnifiedConflict => {
             try output.writeAll("--client cannot be combined with unified mode options\n");
@@ -142,62 +193,137 @@ pub fn runInDirUntilStoppedWithInput(
         },
         else => return err,
     };
+    if (parsed.verbose) logging.setLevel(.debug);
     if (parsed.version_requested) {
         try output.writeAll(version.banner());
         try output.writeAll("\n");
-        return;
+        try output.writeAll(version.buildInfo());
+        try output.writeAll("\n");
+        return 0;
+    }
+    if (parsed.dry_run) {
+        var loaded = try config.runtime.loadFilesWithOverridesInDir(allocator, dir, parsed.configFiles(), parsed.setOverrides());
+        defer loaded.deinit();
+        const text = try commands.dry_run.format(allocator, &loaded.config);
+        defer allocator.free(text);
+        try output.writeAll(text);
+        return 0;
+    }
+    if (parsed.print_config) {
+        var loaded = try config.runtime.loadFilesWithOverridesInDir(allocator, dir, parsed.configFiles(), parsed.setOverrides());
+        defer loaded.deinit();
+        const text = try config.print.format(allocator, &loaded.config);
+        defer allocator.free(text);
+        try output.writeAll(text);
+        return 0;
     }
     if (std.mem.eql(u8, parsed.subcommand, "config-init")) {
         const path = try commands.config_init.runInDir(dir, parsed.args);
         try output.writeAll("Created starter configuration at ");
         try output.writeAll(path);
         try output.writeAll("\n");
-        return;
+        return 0;
+    }
+
+    if (std.mem.eql(u8, parsed.subcommand, "completions")) {
+        try output.writeAll(try commands.completions.run(parsed.args));
+        return 0;
+    }
+
+    if (std.mem.eql(u8, parsed.subcommand, "run")) {
+        return modes.run.run(allocator, dir, parsed.configFiles(), parsed.setOverrides(), parsed.args, output, stopped);
     }
 
     if (isSignalCommand(parsed.subcommand)) {
         try modes.signal.run(
             allocator,
             dir,
-            parsed.config_file,
+            parsed.configFiles(),
             parsed.subcommand,
             parsed.args,
             output,
         );
-        return;
+        return 0;
     }
 
     if (parsed.mode == .client and !parsed.unified) {
-        try modes.client.run(allocator, dir, parsed.config_file, input, output);
-        return;
+        try modes.client.run(allocator, dir, parsed.configFiles(), input, output);
+        return 0;
     }
 
+    const debug_log = if (parsed.debug_pane) try startDebugLog(allocator) else null;
+    defer if (debug_log) |log_file| stopDebugLog(allocator, log_file);
+
     if (parsed.unified) {
-        try unified.runtime.run(allocator, dir, args, parsed.config_file, parsed.unified_orientation, input, output);
-        return;
+        try unified.runtime.run(allocator, dir, args, parsed.configFiles(), parsed.setOverrides(), parsed.unified_orientation, input, output);
+        return 0;
     }
 
     if (parsed.mode == .primary and
         !parsed.unified and
         std.mem.eql(u8, parsed.subcommand, "start"))
     {
-        try modes.primary.runUntilStopped(allocator, dir, parsed.config_file, input, output, stopped);
-        return;
+        try modes.primary.runUntilStopped(allocator, dir, parsed.configFiles(), parsed.setOverrides(), input, output, stopped);
+        return 0;
     }
 
     try output.writeAll(version.banner());
     try output.writeAll("\n");
+    return 0;
+}
+
+const DebugLog = struct {
+    path: []const u8,
+    /// Passed down by a unified parent, which removes the file itself.
+    inherited: bool,
+};
+
+/// Copies log output, at debug level, to a per-run file in the temp dir and
+/// has config loads add the `proctmux-log` process that tails it. A unified
+/// child primary appends to its parent's file instead.
+fn startDebugLog(allocator: std.mem.Allocator) !DebugLog {
+    const inherited = std.posix.getenv(config.debug_pane.env_var) orelse "";
+    const shared = std.fs.path.isAbsolute(inherited);
+    const path = if (shared) try allocator.dupe(u8, inherited) else try tempDebugLogPath(allocator);
+    errdefer allocator.free(path);
+
+    try logging.openFile(path, !shared);
+    logging.setLevel(.debug);
+    config.debug_pane.log_path = path;
+    return .{ .path = path, .inherited = shared };
+}
+
+fn tempDebugLogPath(allocator: std.mem.Allocator) ![]const u8 {
+    const env_tmp = std.posix.getenv("TMPDIR") orelse "";
+    const tmp_dir = if (std.fs.path.isAbsolute(env_tmp)) env_tmp else "/tmp";
+    const file_name = try std.fmt.allocPrint(allocator, "proctmux-debug-{d}.log", .{std.c.getpid()});
+    defer allocator.free(file_name);
+    return std.fs.path.join(allocator, &.{ tmp_dir, file_name });
+}
+
+fn stopDebugLog(allocator: std.mem.Allocator, log_file: DebugLog) void {
+    config.debug_pane.log_path = "";
+    logging.closeFile();
+    if (!log_file.inherited) std.fs.deleteFileAbsolute(log_file.path) catch {};
+    allocator.free(log_file.path);
 }
 
 fn isSignalCommand(subcommand: []const u8) bool {
     return std.mem.startsWith(u8, subcommand, "signal-");
 }
 
+fn argsRunHeadless(args: []const []const u8) bool {
+    const parsed = cli.parse(args) catch return false;
+    return std.mem.eql(u8, parsed.subcommand, "run");
+}
+
 fn argsNeedRawTerminal(args: []const []const u8) bool {
     const parsed = cli.parse(args) catch return false;
-    if (parsed.version_requested) return false;
+    if (parsed.version_requested or parsed.dry_run or parsed.print_config) return false;
     if (isSignalCommand(parsed.subcommand)) return false;
     if (std.mem.eql(u8, parsed.subcommand, "config-init")) return false;
+    if (std.mem.eql(u8, parsed.subcommand, "completions")) return false;
+    if (std.mem.eql(u8, parsed.subcommand, "run")) return false;
     return parsed.unified or parsed.mode == .client or std.mem.eql(u8, parsed.subcommand, "start");
 }
 
@@ -305,6 +431,14 @@ test "app suppresses generic stderr for signal command failures like legacy beha
     try std.testing.expect(!shouldPrintGenericError(error.CommandFailed));
 }
 
+test "startup errors map to actionable messages and exit non-zero" {
+    try std.testing.expectEqual(@as(u8, 1), exitCodeForError(error.ConfigFileNotFound));
+    try std.testing.expect(shouldPrintGenericError(error.ConfigFileNotFound));
+    try std.testing.expect(std.mem.indexOf(u8, genericErrorMessage(error.ConfigFileNotFound), "proctmux.yaml") != null);
+    try std.testing.expectEqualStrings("config file is not valid YAML", genericErrorMessage(error.ParseFailure));
+    try std.testing.expectEqualStrings("OutOfMemory", genericErrorMessage(error.OutOfMemory));
+}
+
 test "app prints legacy-compatible CLI help for help flag" {
     var tmp = std.testing.tmpDir(.{});
     defer tmp.cleanup();
@@ -324,6 +458,39 @@ test "app prints legacy-compatible CLI help for help flag" {
     try std.testing.expect(std.mem.indexOf(u8, out.items, "signal-stop-running") != null);
 }
 
+test "app prints completion script without loading config" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+
+    var out = std.array_list.Managed(u8).init(std.testing.allocator);
+    defer out.deinit();
+
+    try runInDir(std.testing.allocator, tmp.dir, &.{ "completions", "zsh" }, test_io.TestOutput.writer(&out));
+    try std.testing.expect(std.mem.startsWith(u8, out.items, "#compdef proctmux"));
+
+    try std.testing.expectError(error.UnsupportedShell, runInDir(std.testing.allocator, tmp.dir, &.{ "completions", "tcsh" }, test_io.NullOutput.writer()));
+    try std.testing.expect(std.mem.indexOf(u8, genericErrorMessage(error.UnsupportedShell), "bash, zsh, or fish") != null);
+}
+
+test "app dry run prints process commands without starting them" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    try tmp.dir.writeFile(.{ .sub_path = "proctmux.yaml", .data =
+        \\procs:
+        \\  api:
+        \\    shell: "touch started"
+        \\    autostart: true
+        \\
+    });
+
+    var out = std.array_list.Managed(u8).init(std.testing.allocator);
+    defer out.deinit();
+
+    try runInDir(std.testing.allocator, tmp.dir, &.{ "-f", "proctmux.yaml", "--dry-run" }, test_io.TestOutput.writer(&out));
+    try std.testing.expectEqualStrings("api: sh -c 'touch started'\n", out.items);
+    try std.testing.expectError(error.FileNotFound, tmp.dir.access("started", .{}));
+}
+
 test "app prints version for version flag without starting TUI" {
     var tmp = std.testing.tmpDir(.{});
     defer tmp.cleanup();
@@ -333,9 +500,7 @@ test "app prints version for version flag without starting TUI" {
 
     try runInDir(std.testing.allocator, tmp.dir, &.{"--version"}, test_io.TestOutput.writer(&out));
 
-    try std.testing.expect(out.items.len > 0);
-    try std.testing.expectEqual(@as(u8, '\n'), out.items[out.items.len - 1]);
-    try std.testing.expectEqualStrings(version.banner(), out.items[0 .. out.items.len - 1]);
+    try std.testing.expectEqualStrings(version.banner() ++ "\n" ++ version.buildInfo() ++ "\n", out.items);
 }
 
 test "app routes signal-list through config-derived socket" {
@@ -881,7 +1046,7 @@ test "app client mode quit stops running primary processes" {
     }
     try waitForSocketFile(socket_path);
 
-    var input = test_io.BytesInput{ .data = "q" };
+    var input = test_io.BytesInput{ .data = "qq" };
     var out = std.array_list.Managed(u8).init(std.testing.allocator);
     defer out.deinit();
     try runInDirWithInput(std.testing.allocator, dir, &.{"--client"}, test_io.BytesInput.reader(&input), test_io.TestOutput.writer(&out));
@@ -961,6 +1126,42 @@ test "app primary mode serves signal start list and stop commands" {
     if (run_state.err) |err| return err;
 }
 
+test "app run mode waits for a process and returns its exit status" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    try tmp.dir.writeFile(.{
+        .sub_path = "proctmux.yaml",
+        .data =
+        \\procs:
+        \\  db:
+        \\    shell: "sleep 30"
+        \\    stop_timeout_ms: 500
+        \\  tests:
+        \\    shell: "echo suite-done; exit 3"
+        \\
+        ,
+    });
+
+    var out = std.array_list.Managed(u8).init(std.testing.allocator);
+    defer out.deinit();
+    var stopped = std.atomic.Value(bool).init(false);
+
+    const status = try runInDirUntilStoppedWithInput(
+        std.testing.allocator,
+        tmp.dir,
+        &.{ "run", "db", "--wait-for", "tests" },
+        EmptyInput.reader(),
+        test_io.TestOutput.writer(&out),
+        &stopped,
+    );
+    try std.testing.expectEqual(@as(u8, 3), status);
+    try std.testing.expect(std.mem.indexOf(u8, out.items, "suite-done") != null);
+
+    out.clearRetainingCapacity();
+    try std.testing.expectError(error.MissingWaitFor, runInDir(std.testing.allocator, tmp.dir, &.{ "run", "db", "tests" }, test_io.TestOutput.writer(&out)));
+    try std.testing.expectError(error.ProcessNotFound, runInDir(std.testing.allocator, tmp.dir, &.{ "run", "nope" }, test_io.NullOutput.writer()));
+}
+
 test "app primary mode forwards stdin to selected running process" {
     const tmp_path = "/tmp/proctmux-zig-app-primary-stdin-test";
     const config_path = tmp_path ++ "/proctmux.yaml";
@@ -1176,7 +1377,7 @@ test "app unified mode process output redraws avoid repeated full-screen clears"
         .path = "done.txt",
         .needle = "done",
         .first = "j",
-        .second = "q",
+        .second = "qq",
     };
     var out = std.array_list.Managed(u8).init(std.testing.allocator);
     defer out.deinit();
@@ -1209,8 +1410,9 @@ fn runPrimaryApp(state: *AppPrimaryRun) void {
     };
     defer dir.close();
 
-    runInDirUntilStoppedWithInput(std.testing.allocator, dir, &.{}, EmptyInput.reader(), test_io.NullOutput.writer(), state.stopped) catch |err| {
+    _ = runInDirUntilStoppedWithInput(std.testing.allocator, dir, &.{}, EmptyInput.reader(), test_io.NullOutput.writer(), state.stopped) catch |err| {
         state.err = err;
+        return;
     };
 }
 
@@ -1221,8 +1423,9 @@ fn runPrimaryAppWithInput(state: *AppPrimaryInputRun) void {
     };
     defer dir.close();
 
-    runInDirUntilStoppedWithInput(std.testing.allocator, dir, &.{}, test_io.BlockingInput.reader(state.input), test_io.NullOutput.writer(), state.stopped) catch |err| {
+    _ = runInDirUntilStoppedWithInput(std.testing.allocator, dir, &.{}, test_io.BlockingInput.reader(state.input), test_io.NullOutput.writer(), state.stopped) catch |err| {
         state.err = err;
+        return;
     };
 }
 
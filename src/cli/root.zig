@@ -2,6 +2,7 @@
 //! The parser intentionally produces small domain-shaped options so app startup can decide runtime behavior without re-reading raw argv.
 
 const std = @import("std");
+const config = @import("../config/root.zig");
 
 pub const Mode = enum {
     primary,
@@ -16,14 +17,53 @@ pub const UnifiedSplit = enum {
     bottom,
 };
 
+/// Most `-f` flags accepted in one invocation.
+pub const max_config_files = 8;
+
+/// Most `--set` flags accepted in one invocation.
+pub const max_set_overrides = 32;
+
 pub const Config = struct {
+    /// First `-f` path; later ones are kept in `config_file_list`.
     config_file: []const u8 = "",
+    config_file_list: [max_config_files][]const u8 = undefined,
+    config_file_count: usize = 0,
+    set_override_list: [max_set_overrides][]const u8 = undefined,
+    set_override_count: usize = 0,
     mode: Mode = .primary,
     subcommand: []const u8 = "start",
     args: []const []const u8 = &.{},
     unified: bool = false,
     unified_orientation: UnifiedSplit = .none,
     version_requested: bool = false,
+    dry_run: bool = false,
+    print_config: bool = false,
+    verbose: bool = false,
+    debug_pane: bool = false,
+
+    /// Every `-f` path in command-line order, for merged loading.
+    pub fn configFiles(self: *const Config) []const []const u8 {
+        return self.config_file_list[0..self.config_file_count];
+    }
+
+    /// Every `--set <process>.<key>=<value>` in command-line order.
+    pub fn setOverrides(self: *const Config) []const []const u8 {
+        return self.set_override_list[0..self.set_override_count];
+    }
+
+    fn addSetOverride(self: *Config, value: []const u8) !void {
+        _ = try config.overrides.parse(value);
+        if (self.set_override_count == max_set_overrides) return error.TooManySetOverrides;
+        self.set_override_list[self.set_override_count] = value;
+        self.set_override_count += 1;
+    }
+
+    fn addConfigFile(self: *Config, path: []const u8) !void {
+        if (self.config_file_count == max_config_files) return error.TooManyConfigFiles;
+        if (self.config_file_count == 0) self.config_file = path;
+        self.config_file_list[self.config_file_count] = path;
+        self.config_file_count += 1;
+    }
 };
 
 pub const deprecated_unified_toggle_message =
@@ -36,12 +76,22 @@ pub const usage_text =
     \\Usage: proctmux [options] [command]
     \\
     \\Options:
+    \\  -c, -config string
+    \\        alias for -f
     \\  -client
     \\        run in client mode (connects to primary)
+    \\  -debug-pane
+    \\        log debug details to a temporary file and add a proctmux-log process that tails it
+    \\  -dry-run
+    \\        print the command each process would run, then exit without starting anything
+    \\  -print-config
+    \\        print the fully resolved config as YAML, then exit without starting anything
     \\  -f string
-    \\        path to config file (default: searches for proctmux.yaml in current directory)
+    \\        path to config file (default: $PROCTMUX_CONFIG, then searches for proctmux.yaml in current directory); repeat to merge files, later ones win
     \\  -mode string
     \\        mode: primary (process server) or client (UI only) (default "primary")
+    \\  -set process.KEY=value
+    \\        set an environment variable for one process at launch; repeatable
     \\  -unified
     \\        run in unified mode (client + server split view; shorthand for --unified-left)
     \\  -unified-bottom
@@ -52,10 +102,12 @@ pub const usage_text =
     \\        run in unified mode with process list on the right
     \\  -unified-top
     \\        run in unified mode with process list above the output
+    \\  -v, -verbose
+    \\        log debug details, including every command started and its exit status, to stderr
     \\  -version
-    \\        print version and exit
+    \\        print version and build target and exit
     \\  --version
-    \\        print version and exit
+    \\        print version and build target and exit
     \\
     \\Modes:
     \\  (default)                Run primary server (manages processes)
@@ -68,13 +120,20 @@ pub const usage_text =
     \\
     \\Commands:
     \\  config-init [path]       Create a starter proctmux.yaml configuration file
+    \\  completions <shell>      Print a bash, zsh, or fish completion script
     \\  start                    Start the TUI (default)
-    \\  signal-list              List all processes and their statuses (tab-delimited)
+    \\  run [--wait-for <name>] [--shutdown-grace <secs>] <name>...
+    \\                           Start processes without a UI, wait for one to exit, and exit with its status
+    \\  signal-list [--json]     List all processes and their statuses (tab-delimited, or JSON)
     \\  signal-start <name>      Start a process
     \\  signal-stop <name>       Stop a process
     \\  signal-restart <name>    Restart a process
     \\  signal-restart-running   Restart all running processes
     \\  signal-stop-running      Stop all running processes
+    \\  signal-start-all         Start every process, dependencies first
+    \\  signal-stop-all          Stop every process, dependents first
+    \\  signal-reload            Reload the config file
+    \\  signal-clear-output <name>  Clear the retained output of a process
     \\
 ;
 
@@ -168,7 +227,7 @@ pub fn parse(args: []const []const u8) !Config {
 
         const parsed = try parseFlagToken(arg);
         const value = parsed.value orelse switch (parsed.kind) {
-            .config_file, .mode => blk: {
+            .config_file, .mode, .set => blk: {
                 i += 1;
                 if (i >= args.len) return error.MissingFlagValue;
                 break :blk args[i];
@@ -177,7 +236,8 @@ pub fn parse(args: []const []const u8) !Config {
         };
 
         switch (parsed.kind) {
-            .config_file => cfg.config_file = value,
+            .config_file => try cfg.addConfigFile(value),
+            .set => try cfg.addSetOverride(value),
             .mode => cfg.mode = parseMode(value),
             .client => client_mode = try parseBool(value),
             .unified => cfg.unified = try parseBool(value),
@@ -186,6 +246,10 @@ pub fn parse(args: []const []const u8) !Config {
             .unified_top => try applyOrientation(&cfg, &orientation_count, .top, try parseBool(value)),
             .unified_bottom => try applyOrientation(&cfg, &orientation_count, .bottom, try parseBool(value)),
             .version => cfg.version_requested = true,
+            .dry_run => cfg.dry_run = try parseBool(value),
+            .print_config => cfg.print_config = try parseBool(value),
+            .verbose => cfg.verbose = try parseBool(value),
+            .debug_pane => cfg.debug_pane = try parseBool(value),
             .help => return error.HelpRequested,
         }
         i += 1;
@@ -202,6 +266,7 @@ pub fn parse(args: []const []const u8) !Config {
 
 const FlagKind = enum {
     config_file,
+    set,
     mode,
     client,
     unified,
@@ -210,6 +275,10 @@ const FlagKind = enum {
     unified_top,
     unified_bottom,
     version,
+    dry_run,
+    print_config,
+    verbose,
+    debug_pane,
     help,
 };
 
@@ -230,8 +299,11 @@ fn parseFlagToken(arg: []const u8) !ParsedFlag {
     const name = if (eq_index) |index| without_prefix[0..index] else without_prefix;
     const value = if (eq_index) |index| without_prefix[index + 1 ..] else null;
 
-    if (std.mem.eql(u8, name, "f")) return .{ .kind = .config_file, .value = value };
+    if (std.mem.eql(u8, name, "f") or std.mem.eql(u8, name, "c") or std.mem.eql(u8, name, "config")) {
+        return .{ .kind = .config_file, .value = value };
+    }
     if (std.mem.eql(u8, name, "mode")) return .{ .kind = .mode, .value = value };
+    if (std.mem.eql(u8, name, "set")) return .{ .kind = .set, .value = value };
     if (std.mem.eql(u8, name, "client")) return .{ .kind = .client, .value = value };
     if (std.mem.eql(u8, name, "unified")) return .{ .kind = .unified, .value = value };
     if (std.mem.eql(u8, name, "unified-left")) return .{ .kind = .unified_left, .value = value };
@@ -239,6 +311,10 @@ fn parseFlagToken(arg: []const u8) !ParsedFlag {
     if (std.mem.eql(u8, name, "unified-top")) return .{ .kind = .unified_top, .value = value };
     if (std.mem.eql(u8, name, "unified-bottom")) return .{ .kind = .unified_bottom, .value = value };
     if (std.mem.eql(u8, name, "version")) return .{ .kind = .version, .value = value };
+    if (std.mem.eql(u8, name, "dry-run")) return .{ .kind = .dry_run, .value = value };
+    if (std.mem.eql(u8, name, "print-config")) return .{ .kind = .print_config, .value = value };
+    if (std.mem.eql(u8, name, "v") or std.mem.eql(u8, name, "verbose")) return .{ .kind = .verbose, .value = value };
+    if (std.mem.eql(u8, name, "debug-pane")) return .{ .kind = .debug_pane, .value = value };
     if (std.mem.eql(u8, name, "h") or std.mem.eql(u8, name, "help")) return .{ .kind = .help, .value = value };
     return error.UnknownFlag;
 }
@@ -285,7 +361,7 @@ fn flagName(arg: []const u8) []const u8 {
 
 fn flagRequiresValue(kind: FlagKind) bool {
     return switch (kind) {
-        .config_file, .mode => true,
+        .config_file, .mode, .set => true,
         else => false,
     };
 }
@@ -298,6 +374,10 @@ fn flagRequiresBool(kind: FlagKind) bool {
         .unified_right,
         .unified_top,
         .unified_bottom,
+        .dry_run,
+        .print_config,
+        .verbose,
+        .debug_pane,
         => true,
         else => false,
     };
@@ -333,6 +413,20 @@ test "config file client mode and subcommand parse like legacy behavior" {
     try std.testing.expectEqualStrings("signal-list", cfg.args[0]);
 }
 
+test "config flag aliases set the config file" {
+    const short = try parse(&.{ "-c", "short.yaml" });
+    try std.testing.expectEqualStrings("short.yaml", short.config_file);
+
+    const long = try parse(&.{ "--config", "long.yaml", "signal-list" });
+    try std.testing.expectEqualStrings("long.yaml", long.config_file);
+    try std.testing.expectEqualStrings("signal-list", long.subcommand);
+
+    const inline_value = try parse(&.{"--config=inline.yaml"});
+    try std.testing.expectEqualStrings("inline.yaml", inline_value.config_file);
+
+    try std.testing.expectError(error.MissingFlagValue, parse(&.{"--config"}));
+}
+
 test "unified flags choose legacy-compatible orientation" {
     const unified = try parse(&.{"--unified"});
     try std.testing.expect(unified.unified);
@@ -399,3 +493,42 @@ test "deprecated unified toggle message matches deprecated flag migration guidan
     try std.testing.expect(std.mem.indexOf(u8, msg, "--unified") != null);
     try std.testing.expect(std.mem.indexOf(u8, msg, "hide_process_list_when_unfocused: true") != null);
 }
+
+test "verbose flag accepts short and long forms" {
+    try std.testing.expect((try parse(&.{"-v"})).verbose);
+    try std.testing.expect((try parse(&.{"--verbose"})).verbose);
+    try std.testing.expect(!(try parse(&.{})).verbose);
+}
+
+test "debug-pane flag is parsed as a bool flag" {
+    try std.testing.expect((try parse(&.{"--debug-pane"})).debug_pane);
+    try std.testing.expect(!(try parse(&.{"-debug-pane=false"})).debug_pane);
+}
+
+test "dry-run flag is parsed as a bool flag" {
+    try std.testing.expect((try parse(&.{"--dry-run"})).dry_run);
+    try std.testing.expect(!(try parse(&.{"-dry-run=false"})).dry_run);
+    try std.testing.expectError(error.InvalidBool, parse(&.{"--dry-run=maybe"}));
+    try std.testing.expect((try parse(&.{"--print-config"})).print_config);
+}
+
+test "repeated config flags keep every file in order" {
+    const cfg = try parse(&.{ "-f", "base.yaml", "--config=local.yaml", "-c", "ci.yaml" });
+    try std.testing.expectEqualStrings("base.yaml", cfg.config_file);
+    try std.testing.expectEqual(@as(usize, 3), cfg.configFiles().len);
+    try std.testing.expectEqualStrings("local.yaml", cfg.configFiles()[1]);
+    try std.testing.expectEqualStrings("ci.yaml", cfg.configFiles()[2]);
+
+    const none = try parse(&.{});
+    try std.testing.expectEqual(@as(usize, 0), none.configFiles().len);
+}
+
+test "repeated set flags accumulate in order" {
+    const cfg = try parse(&.{ "--set", "worker.CONCURRENCY=4", "-set=api.PORT=8080", "--set", "worker.CONCURRENCY=8" });
+    try std.testing.expectEqual(@as(usize, 3), cfg.setOverrides().len);
+    try std.testing.expectEqualStrings("api.PORT=8080", cfg.setOverrides()[1]);
+    try std.testing.expectEqualStrings("worker.CONCURRENCY=8", cfg.setOverrides()[2]);
+
+    try std.testing.expectError(error.InvalidSetFlag, parse(&.{ "--set", "worker=4" }));
+    try std.testing.expectError(error.MissingFlagValue, parse(&.{"--set"}));
+}
@@ -0,0 +1,196 @@
+//! Implementation of `proctmux completions <shell>`.
+//! Scripts are static text so generating them never loads config; process-name completion shells out to `signal-list` at completion time instead.
+
+const std = @import("std");
+
+pub const supported_shells = "bash, zsh, or fish";
+
+const bash_script =
+    \\# bash completion for proctmux
+    \\_proctmux() {
+    \\    local cur prev
+    \\    cur="${COMP_WORDS[COMP_CWORD]}"
+    \\    prev="${COMP_WORDS[COMP_CWORD-1]}"
+    \\    local flags="-f -c --config --client --mode --unified --unified-left --unified-right --unified-top --unified-bottom --set --dry-run --print-config -v --verbose --debug-pane --version --help"
+    \\    local commands="start run config-init completions signal-list signal-start signal-stop signal-restart signal-switch signal-restart-running signal-stop-running signal-start-all signal-stop-all signal-reload signal-clear-output"
+    \\
+    \\    case "$prev" in
+    \\        -f|-c|--config|-config)
+    \\            COMPREPLY=( $(compgen -f -- "$cur") )
+    \\            return ;;
+    \\        --mode|-mode)
+    \\            COMPREPLY=( $(compgen -W "primary client" -- "$cur") )
+    \\            return ;;
+    \\        --set|-set)
+    \\            return ;;
+    \\        signal-start|signal-stop|signal-restart|signal-switch|signal-clear-output)
+    \\            COMPREPLY=( $(compgen -W "$(proctmux signal-list 2>/dev/null | tail -n +2 | cut -f1)" -- "$cur") )
+    \\            return ;;
+    \\        signal-list)
+    \\            COMPREPLY=( $(compgen -W "--json" -- "$cur") )
+    \\            return ;;
+    \\        completions)
+    \\            COMPREPLY=( $(compgen -W "bash zsh fish" -- "$cur") )
+    \\            return ;;
+    \\        config-init)
+    \\            COMPREPLY=( $(compgen -f -- "$cur") )
+    \\            return ;;
+    \\    esac
+    \\
+    \\    if [[ "$cur" == -* ]]; then
+    \\        COMPREPLY=( $(compgen -W "$flags" -- "$cur") )
+    \\    else
+    \\        COMPREPLY=( $(compgen -W "$commands" -- "$cur") )
+    \\    fi
+    \\}
+    \\complete -o filenames -F _proctmux proctmux
+    \\
+;
+
+const zsh_script =
+    \\#compdef proctmux
+    \\
+    \\_proctmux() {
+    \\  local -a commands
+    \\  commands=(
+    \\    'start:start the TUI (default)'
+    \\    'run:start processes headless and wait for one to exit'
+    \\    'config-init:create a starter proctmux.yaml'
+    \\    'completions:print a shell completion script'
+    \\    'signal-list:list processes and their statuses'
+    \\    'signal-start:start a process'
+    \\    'signal-stop:stop a process'
+    \\    'signal-restart:restart a process'
+    \\    'signal-switch:show a process in the UI'
+    \\    'signal-restart-running:restart all running processes'
+    \\    'signal-stop-running:stop all running processes'
+    \\    'signal-start-all:start every process'
+    \\    'signal-stop-all:stop every process'
+    \\    'signal-reload:reload the config file'
+    \\    'signal-clear-output:clear the retained output of a process'
+    \\  )
+    \\
+    \\  _arguments -C \
+    \\    '(-f -c --config)'{-f,-c,--config}'[path to config file]:config file:_files' \
+    \\    '--client[run in client mode]' \
+    \\    '--mode[process mode]:mode:(primary client)' \
+    \\    '--unified[run in unified mode]' \
+    \\    '--unified-left[unified mode with process list on the left]' \
+    \\    '--unified-right[unified mode with process list on the right]' \
+    \\    '--unified-top[unified mode with process list above the output]' \
+    \\    '--unified-bottom[unified mode with process list below the output]' \
+    \\    '*--set[set an environment variable for one process]:process.KEY=value:' \
+    \\    '--dry-run[print process commands without starting them]' \
+    \\    '--print-config[print the resolved config as YAML]' \
+    \\    {-v,--verbose}'[log debug details to stderr]' \
+    \\    '--debug-pane[show proctmux debug logs as a process]' \
+    \\    '--version[print version and exit]' \
+    \\    '--help[show usage]' \
+    \\    '1:command:->command' \
+    \\    '*::arg:->args'
+    \\
+    \\  case $state in
+    \\    command)
+    \\      _describe 'command' commands ;;
+    \\    args)
+    \\      case $words[1] in
+    \\        signal-start|signal-stop|signal-restart|signal-switch|signal-clear-output)
+    \\          local -a procs
+    \\          procs=(${(f)"$(proctmux signal-list 2>/dev/null | tail -n +2 | cut -f1)"})
+    \\          _describe 'process' procs ;;
+    \\        signal-list)
+    \\          _values 'option' --json ;;
+    \\        completions)
+    \\          _values 'shell' bash zsh fish ;;
+    \\        config-init)
+    \\          _files ;;
+    \\      esac ;;
+    \\  esac
+    \\}
+    \\
+    \\_proctmux "$@"
+    \\
+;
+
+const fish_script =
+    \\# fish completion for proctmux
+    \\set -l proctmux_commands start run config-init completions signal-list signal-start signal-stop signal-restart signal-switch signal-restart-running signal-stop-running signal-start-all signal-stop-all signal-reload signal-clear-output
+    \\
+    \\complete -c proctmux -f
+    \\complete -c proctmux -s f -r -F -d 'Path to config file'
+    \\complete -c proctmux -s c -l config -r -F -d 'Path to config file'
+    \\complete -c proctmux -l client -d 'Run in client mode'
+    \\complete -c proctmux -l mode -x -a 'primary client' -d 'Process mode'
+    \\complete -c proctmux -l unified -d 'Run in unified mode'
+    \\complete -c proctmux -l unified-left -d 'Unified mode with process list on the left'
+    \\complete -c proctmux -l unified-right -d 'Unified mode with process list on the right'
+    \\complete -c proctmux -l unified-top -d 'Unified mode with process list above the output'
+    \\complete -c proctmux -l unified-bottom -d 'Unified mode with process list below the output'
+    \\complete -c proctmux -l set -x -d 'Set an environment variable for one process (process.KEY=value)'
+    \\complete -c proctmux -l dry-run -d 'Print process commands without starting them'
+    \\complete -c proctmux -l print-config -d 'Print the resolved config as YAML'
+    \\complete -c proctmux -s v -l verbose -d 'Log debug details to stderr'
+    \\complete -c proctmux -l debug-pane -d 'Show proctmux debug logs as a process'
+    \\complete -c proctmux -l version -d 'Print version and exit'
+    \\complete -c proctmux -s h -l help -d 'Show usage'
+    \\
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a start -d 'Start the TUI (default)'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a run -d 'Start processes headless and wait for one to exit'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a config-init -d 'Create a starter proctmux.yaml'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a completions -d 'Print a shell completion script'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a signal-list -d 'List processes and their statuses'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a signal-start -d 'Start a process'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a signal-stop -d 'Stop a process'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a signal-restart -d 'Restart a process'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a signal-switch -d 'Show a process in the UI'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a signal-restart-running -d 'Restart all running processes'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a signal-stop-running -d 'Stop all running processes'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a signal-start-all -d 'Start every process'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a signal-stop-all -d 'Stop every process'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a signal-reload -d 'Reload the config file'
+    \\complete -c proctmux -n "not __fish_seen_subcommand_from $proctmux_commands" -a signal-clear-output -d 'Clear the retained output of a process'
+    \\
+    \\complete -c proctmux -n "__fish_seen_subcommand_from signal-start signal-stop signal-restart signal-switch signal-clear-output" -a "(proctmux signal-list 2>/dev/null | tail -n +2 | cut -f1)"
+    \\complete -c proctmux -n "__fish_seen_subcommand_from signal-list" -l json -d 'Print JSON'
+    \\complete -c proctmux -n "__fish_seen_subcommand_from run" -l wait-for -x -d 'Process whose exit ends the run'
+    \\complete -c proctmux -n "__fish_seen_subcommand_from run" -l shutdown-grace -x -d 'Seconds every process gets to exit before being killed'
+    \\complete -c proctmux -n "__fish_seen_subcommand_from completions" -a 'bash zsh fish'
+    \\complete -c proctmux -n "__fish_seen_subcommand_from config-init" -F
+    \\
+;
+
+/// Returns the completion script named by `args[1]`. The text is static, so
+/// callers can write it straight to stdout.
+pub fn run(args: []const []const u8) ![]const u8 {
+    if (args.len < 2) return error.MissingShell;
+    if (args.len > 2) return error.TooManyArguments;
+    return script(args[1]) orelse error.UnsupportedShell;
+}
+
+pub fn script(shell: []const u8) ?[]const u8 {
+    if (std.mem.eql(u8, shell, "bash")) return bash_script;
+    if (std.mem.eql(u8, shell, "zsh")) return zsh_script;
+    if (std.mem.eql(u8, shell, "fish")) return fish_script;
+    return null;
+}
+
+test "completions return a script per supported shell" {
+    try std.testing.expect(std.mem.indexOf(u8, try run(&.{ "completions", "bash" }), "complete -o filenames -F _proctmux proctmux") != null);
+    try std.testing.expect(std.mem.startsWith(u8, try run(&.{ "completions", "zsh" }), "#compdef proctmux"));
+    try std.testing.expect(std.mem.indexOf(u8, try run(&.{ "completions", "fish" }), "complete -c proctmux -s c -l config -r -F") != null);
+}
+
+test "completions cover config flags and subcommands" {
+    for ([_][]const u8{ "bash", "zsh", "fish" }) |shell| {
+        const text = script(shell).?;
+        try std.testing.expect(std.mem.indexOf(u8, text, "config") != null);
+        try std.testing.expect(std.mem.indexOf(u8, text, "signal-restart-running") != null);
+        try std.testing.expect(std.mem.indexOf(u8, text, "unified-bottom") != null);
+    }
+}
+
+test "completions reject missing or unknown shells" {
+    try std.testing.expectError(error.MissingShell, run(&.{"completions"}));
+    try std.testing.expectError(error.UnsupportedShell, run(&.{ "completions", "tcsh" }));
+    try std.testing.expectError(error.TooManyArguments, run(&.{ "completions", "bash", "zsh" }));
+}
@@ -0,0 +1,77 @@
+//! Implementation of `proctmux --dry-run`.
+//! Resolves every process launch the way the controller would and prints it instead of spawning anything, so configs can be checked in CI.
+
+const std = @import("std");
+const config = @import("../config/root.zig");
+const proc_mod = @import("../proc/root.zig");
+
+/// Formats one entry per process in config order: the label, the argv that
+/// would be executed, and the working directory when one is set.
+pub fn format(allocator: std.mem.Allocator, cfg: *const config.schema.Config) ![]u8 {
+    var out = std.array_list.Managed(u8).init(allocator);
+    errdefer out.deinit();
+
+    var it = cfg.procs.iterator();
+    while (it.next()) |entry| {
+        const proc_cfg = entry.value_ptr;
+        try out.appendSlice(entry.key_ptr.*);
+        try out.appendSlice(":");
+
+        const spec = try proc_mod.builder.buildCommand(allocator, proc_cfg, cfg);
+        if (spec) |command| {
+            defer command.deinit(allocator);
+            for (command.argv) |arg| {
+                try out.append(' ');
+                try proc_mod.builder.appendShellQuoted(&out, arg);
+            }
+        } else {
+            try out.appendSlice(" (no command)");
+        }
+        try out.append('\n');
+
+        if (proc_cfg.cwd.len > 0) {
+            try out.appendSlice("  cwd: ");
+            try out.appendSlice(proc_cfg.cwd);
+            try out.append('\n');
+        }
+        if (proc_cfg.depends_on.items.len > 0) {
+            const deps = try std.mem.join(allocator, ", ", proc_cfg.depends_on.items);
+            defer allocator.free(deps);
+            try out.appendSlice("  depends_on: ");
+            try out.appendSlice(deps);
+            try out.append('\n');
+        }
+    }
+    return out.toOwnedSlice();
+}
+
+test "dry run prints resolved argv and cwd in config order" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+
+    var api = config.schema.ProcessConfig.empty(std.testing.allocator);
+    api.shell = try std.testing.allocator.dupe(u8, "echo 'hi there'");
+    api.cwd = try std.testing.allocator.dupe(u8, "/work/api");
+    api.owns_scalar_strings = true;
+    try config.schema.appendOwned(std.testing.allocator, &api.depends_on, "db");
+    try cfg.procs.put(try std.testing.allocator.dupe(u8, "api"), api);
+
+    var db = config.schema.ProcessConfig.empty(std.testing.allocator);
+    try config.schema.appendOwned(std.testing.allocator, &db.cmd, "postgres");
+    try config.schema.appendOwned(std.testing.allocator, &db.cmd, "-D");
+    try config.schema.appendOwned(std.testing.allocator, &db.cmd, "./data");
+    try cfg.procs.put(try std.testing.allocator.dupe(u8, "db"), db);
+
+    try cfg.procs.put(try std.testing.allocator.dupe(u8, "idle"), config.schema.ProcessConfig.empty(std.testing.allocator));
+
+    const text = try format(std.testing.allocator, &cfg);
+    defer std.testing.allocator.free(text);
+    try std.testing.expectEqualStrings(
+        "api: sh -c 'echo '\\''hi there'\\'''\n" ++
+            "  cwd: /work/api\n" ++
+            "  depends_on: db\n" ++
+            "db: postgres -D ./data\n" ++
+            "idle: (no command)\n",
+        text,
+    );
+}
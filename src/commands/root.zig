@@ -1,10 +1,16 @@
 //! Commands namespace for non-interactive subcommands.
 //! Keeping command modules behind this small import surface lets app routing stay independent of individual command implementations.
 
+pub const completions = @import("completions.zig");
 pub const config_init = @import("config_init.zig");
+pub const dry_run = @import("dry_run.zig");
+pub const run = @import("run.zig");
 pub const signal = @import("signal.zig");
 
 test {
+    _ = completions;
     _ = config_init;
+    _ = dry_run;
+    _ = run;
     _ = signal;
 }
@@ -0,0 +1,86 @@
+//! `run` subcommand argument parsing.
+//! The headless run mode starts the named processes and waits for one of them; this module only turns the arguments into that plan.
+
+const std = @import("std");
+
+pub const max_names = 64;
+
+pub const Plan = struct {
+    names_buf: [max_names][]const u8 = undefined,
+    names_len: usize = 0,
+    /// Process whose exit ends the run and provides the exit code.
+    wait_for: []const u8 = "",
+    /// With `--shutdown-grace`, how long every process together gets to exit
+    /// at the end of the run before the rest are killed.
+    shutdown_grace_secs: ?u32 = null,
+
+    pub fn names(self: *const Plan) []const []const u8 {
+        return self.names_buf[0..self.names_len];
+    }
+
+    /// Whether `wait_for` also has to be started because it was not named.
+    pub fn startsWaitFor(self: *const Plan) bool {
+        for (self.names()) |name| {
+            if (std.mem.eql(u8, name, self.wait_for)) return false;
+        }
+        return true;
+    }
+};
+
+/// Parses `run [--wait-for <name>] [--shutdown-grace <secs>] <name>...`.
+/// Without `--wait-for` exactly one name must be given, and the run waits for it.
+pub fn parse(args: []const []const u8) !Plan {
+    var plan = Plan{};
+    var index: usize = 1;
+    while (index < args.len) : (index += 1) {
+        const arg = args[index];
+        if (std.mem.eql(u8, arg, "--wait-for") or std.mem.eql(u8, arg, "-wait-for")) {
+            index += 1;
+            if (index >= args.len) return error.MissingWaitFor;
+            plan.wait_for = args[index];
+            continue;
+        }
+        if (std.mem.eql(u8, arg, "--shutdown-grace") or std.mem.eql(u8, arg, "-shutdown-grace")) {
+            index += 1;
+            if (index >= args.len) return error.InvalidShutdownGrace;
+            plan.shutdown_grace_secs = std.fmt.parseInt(u32, args[index], 10) catch return error.InvalidShutdownGrace;
+            continue;
+        }
+        if (std.mem.startsWith(u8, arg, "-")) return error.UnknownRunFlag;
+        if (plan.names_len == max_names) return error.TooManyArguments;
+        plan.names_buf[plan.names_len] = arg;
+        plan.names_len += 1;
+    }
+
+    if (plan.wait_for.len == 0) {
+        if (plan.names_len == 0) return error.MissingName;
+        if (plan.names_len > 1) return error.MissingWaitFor;
+        plan.wait_for = plan.names_buf[0];
+    }
+    return plan;
+}
+
+test "run parser waits for the only name or the --wait-for process" {
+    const single = try parse(&.{ "run", "tests" });
+    try std.testing.expectEqual(@as(usize, 1), single.names().len);
+    try std.testing.expectEqualStrings("tests", single.wait_for);
+    try std.testing.expect(!single.startsWaitFor());
+
+    const multi = try parse(&.{ "run", "db", "api", "--wait-for", "tests" });
+    try std.testing.expectEqual(@as(usize, 2), multi.names().len);
+    try std.testing.expectEqualStrings("api", multi.names()[1]);
+    try std.testing.expectEqualStrings("tests", multi.wait_for);
+    try std.testing.expect(multi.startsWaitFor());
+
+    try std.testing.expectError(error.MissingName, parse(&.{"run"}));
+    try std.testing.expectError(error.MissingWaitFor, parse(&.{ "run", "db", "api" }));
+    try std.testing.expectError(error.MissingWaitFor, parse(&.{ "run", "db", "--wait-for" }));
+    try std.testing.expectError(error.UnknownRunFlag, parse(&.{ "run", "--detach", "db" }));
+}
+
+test "run parser reads a shutdown grace in whole seconds" {
+    try std.testing.expectEqual(@as(?u32, null), (try parse(&.{ "run", "tests" })).shutdown_grace_secs);
+    try std.testing.expectEqual(@as(?u32, 10), (try parse(&.{ "run", "--shutdown-grace", "10", "tests" })).shutdown_grace_secs);
+    try std.testing.expectError(error.InvalidShutdownGrace, parse(&.{ "run", "tests", "--shutdown-grace" }));
+    try std.testing.expectError(error.InvalidShutdownGrace, parse(&.{ "run", "--shutdown-grace", "1.5", "tests" }));
+}
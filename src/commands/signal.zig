@@ -12,11 +12,16 @@ pub const ProcessCommand = struct {
     label: []const u8 = "",
 };
 
+pub const ListFormat = enum {
+    table,
+    json,
+};
+
 /// Parsed signal-command intent. Listing is separate from Process Commands so
 /// the IPC protocol does not need a request/response shape for process lists.
 pub const Plan = union(enum) {
     command: ProcessCommand,
-    list,
+    list: ListFormat,
 };
 
 pub const Sender = struct {
@@ -54,18 +59,38 @@ pub fn parse(subcommand: []const u8, args: []const []const u8) !Plan {
     if (std.mem.eql(u8, subcommand, "signal-switch")) {
         return commandPlan(.switch_process, try requiredName(args));
     }
+    if (std.mem.eql(u8, subcommand, "signal-clear-output")) {
+        return commandPlan(.clear_output, try requiredName(args));
+    }
     if (std.mem.eql(u8, subcommand, "signal-restart-running")) {
         return commandPlan(.restart_running, "");
     }
     if (std.mem.eql(u8, subcommand, "signal-stop-running")) {
         return commandPlan(.stop_running, "");
     }
+    if (std.mem.eql(u8, subcommand, "signal-start-all")) {
+        return commandPlan(.start_all, "");
+    }
+    if (std.mem.eql(u8, subcommand, "signal-stop-all")) {
+        return commandPlan(.stop_all, "");
+    }
+    if (std.mem.eql(u8, subcommand, "signal-reload")) {
+        return commandPlan(.reload, "");
+    }
     if (std.mem.eql(u8, subcommand, "signal-list")) {
-        return .list;
+        return .{ .list = try listFormat(args) };
     }
     return error.UnknownSignalCommand;
 }
 
+fn listFormat(args: []const []const u8) !ListFormat {
+    if (args.len < 2) return .table;
+    for (args[1..]) |arg| {
+        if (!std.mem.eql(u8, arg, "--json")) return error.UnknownSignalCommand;
+    }
+    return .json;
+}
+
 fn commandPlan(action: ipc.protocol.Command, label: []const u8) Plan {
     return .{ .command = .{ .action = action, .label = label } };
 }
@@ -92,21 +117,25 @@ pub fn runWithSender(
 pub fn runWithSocketPath(
     allocator: std.mem.Allocator,
     socket_path: []const u8,
+    timeout_ms: i32,
     subcommand: []const u8,
     args: []const []const u8,
     output: Output,
 ) !void {
     const plan = try parse(subcommand, args);
     switch (plan) {
-        .list => {
-            var snapshot_update = try ipc.client.readInitialSnapshotFromPath(allocator, socket_path);
+        .list => |format| {
+            var snapshot_update = try ipc.client.readInitialSnapshotFromPath(allocator, socket_path, timeout_ms);
             defer snapshot_update.deinit();
-            const table = try formatProcessList(allocator, snapshot_update.snapshot());
+            const table = switch (format) {
+                .table => try formatProcessList(allocator, snapshot_update.snapshot()),
+                .json => try domain.client_snapshot.formatProcessListJson(allocator, snapshot_update.snapshot()),
+            };
             defer allocator.free(table);
             try output.writeAll(table);
         },
         .command => |command| {
-            var response = try ipc.client.sendCommandToPath(allocator, socket_path, 1, command.action, command.label);
+            var response = try ipc.client.sendCommandToPathWithTimeout(allocator, socket_path, 1, command.action, command.label, timeout_ms);
             defer response.deinit(allocator);
             if (!response.success) return error.CommandFailed;
         },
@@ -123,7 +152,7 @@ pub fn runWithConfig(
     const socket_path = try ipc.socket.getPathForConfig(allocator, cfg);
     defer allocator.free(socket_path);
 
-    try runWithSocketPath(allocator, socket_path, subcommand, args, output);
+    try runWithSocketPath(allocator, socket_path, ipc.client.responseTimeoutMs(cfg), subcommand, args, output);
 }
 
 /// Formats the snapshot's process summaries for scripting-friendly output.
@@ -162,6 +191,9 @@ test "signal command parser maps named process commands" {
 
     const switch_cmd = try parse("signal-switch", &.{ "signal-switch", "web" });
     try expectCommandPlan(switch_cmd, .switch_process, "web");
+
+    const clear = try parse("signal-clear-output", &.{ "signal-clear-output", "web" });
+    try expectCommandPlan(clear, .clear_output, "web");
 }
 
 test "signal command parser maps running and list commands" {
@@ -171,8 +203,22 @@ test "signal command parser maps running and list commands" {
     const stop_running = try parse("signal-stop-running", &.{"signal-stop-running"});
     try expectCommandPlan(stop_running, .stop_running, "");
 
+    const start_all = try parse("signal-start-all", &.{"signal-start-all"});
+    try expectCommandPlan(start_all, .start_all, "");
+
+    const stop_all = try parse("signal-stop-all", &.{"signal-stop-all"});
+    try expectCommandPlan(stop_all, .stop_all, "");
+
+    const reload = try parse("signal-reload", &.{"signal-reload"});
+    try expectCommandPlan(reload, .reload, "");
+
     const list = try parse("signal-list", &.{"signal-list"});
-    try std.testing.expectEqual(Plan.list, list);
+    try std.testing.expectEqual(Plan{ .list = .table }, list);
+
+    const json = try parse("signal-list", &.{ "signal-list", "--json" });
+    try std.testing.expectEqual(Plan{ .list = .json }, json);
+
+    try std.testing.expectError(error.UnknownSignalCommand, parse("signal-list", &.{ "signal-list", "--yaml" }));
 }
 
 fn expectCommandPlan(plan: Plan, action: ipc.protocol.Command, label: []const u8) !void {
@@ -250,12 +296,12 @@ test "signal socket runner sends mutation command" {
     var out = std.array_list.Managed(u8).init(std.testing.allocator);
     defer out.deinit();
 
-    try runWithSocketPath(std.testing.allocator, path, "signal-stop", &.{ "signal-stop", "api" }, TestOutput.writer(&out));
+    try runWithSocketPath(std.testing.allocator, path, ipc.client.default_response_timeout_ms, "signal-stop", &.{ "signal-stop", "api" }, TestOutput.writer(&out));
     thread.join();
     if (capture.err) |err| return err;
 
     try std.testing.expectEqualStrings(
-        "{\"type\":\"command\",\"protocol_version\":1,\"request_id\":1,\"action\":\"stop\",\"target\":\"api\"}\n",
+        "{\"type\":\"command\",\"protocol_version\":2,\"request_id\":1,\"action\":\"stop\",\"target\":\"api\"}\n",
         capture.requestLine(),
     );
 }
@@ -280,7 +326,7 @@ test "signal socket runner formats list from initial snapshot" {
     var out = std.array_list.Managed(u8).init(std.testing.allocator);
     defer out.deinit();
 
-    try runWithSocketPath(std.testing.allocator, path, "signal-list", &.{"signal-list"}, TestOutput.writer(&out));
+    try runWithSocketPath(std.testing.allocator, path, ipc.client.default_response_timeout_ms, "signal-list", &.{"signal-list"}, TestOutput.writer(&out));
     thread.join();
     if (server_result.err) |err| return err;
 
@@ -0,0 +1,116 @@
+//! Terminal color values accepted in config.
+//! Named ANSI colors, 0-15 palette indexes and `#rgb`/`#rrggbb` hex values are understood here so config validation and the TUI renderer agree on the same set.
+
+const std = @import("std");
+
+pub const Foreground = union(enum) {
+    /// SGR foreground code such as 31 for red or 92 for bright green.
+    ansi: u8,
+    rgb: [3]u8,
+};
+
+const named_colors = [_]struct {
+    name: []const u8,
+    code: u8,
+}{
+    .{ .name = "black", .code = 30 },
+    .{ .name = "red", .code = 31 },
+    .{ .name = "green", .code = 32 },
+    .{ .name = "yellow", .code = 33 },
+    .{ .name = "blue", .code = 34 },
+    .{ .name = "magenta", .code = 35 },
+    .{ .name = "cyan", .code = 36 },
+    .{ .name = "white", .code = 37 },
+    .{ .name = "brightblack", .code = 90 },
+    .{ .name = "gray", .code = 90 },
+    .{ .name = "grey", .code = 90 },
+    .{ .name = "brightred", .code = 91 },
+    .{ .name = "lightred", .code = 91 },
+    .{ .name = "brightgreen", .code = 92 },
+    .{ .name = "lightgreen", .code = 92 },
+    .{ .name = "brightyellow", .code = 93 },
+    .{ .name = "brightblue", .code = 94 },
+    .{ .name = "brightmagenta", .code = 95 },
+    .{ .name = "brightcyan", .code = 96 },
+    .{ .name = "brightwhite", .code = 97 },
+    .{ .name = "ansiblack", .code = 30 },
+    .{ .name = "ansired", .code = 31 },
+    .{ .name = "ansigreen", .code = 32 },
+    .{ .name = "ansiyellow", .code = 33 },
+    .{ .name = "ansiblue", .code = 34 },
+    .{ .name = "ansimagenta", .code = 35 },
+    .{ .name = "ansicyan", .code = 36 },
+    .{ .name = "ansiwhite", .code = 37 },
+    .{ .name = "ansibrightblack", .code = 90 },
+    .{ .name = "ansigray", .code = 90 },
+    .{ .name = "ansigrey", .code = 90 },
+    .{ .name = "ansibrightred", .code = 91 },
+    .{ .name = "ansibrightgreen", .code = 92 },
+    .{ .name = "ansibrightyellow", .code = 93 },
+    .{ .name = "ansibrightblue", .code = 94 },
+    .{ .name = "ansibrightmagenta", .code = 95 },
+    .{ .name = "ansibrightcyan", .code = 96 },
+    .{ .name = "ansibrightwhite", .code = 97 },
+};
+
+/// Parses a color value; null for empty, `none`, or anything unknown.
+pub fn parse(color: []const u8) ?Foreground {
+    const trimmed = std.mem.trim(u8, color, " \t\r\n");
+    if (trimmed.len == 0 or std.ascii.eqlIgnoreCase(trimmed, "none")) return null;
+
+    for (named_colors) |entry| {
+        if (std.ascii.eqlIgnoreCase(trimmed, entry.name)) return .{ .ansi = entry.code };
+    }
+    if (trimmed[0] == '#') return parseHex(trimmed[1..]);
+
+    const color_index = std.fmt.parseUnsigned(u8, trimmed, 10) catch return null;
+    if (color_index <= 7) return .{ .ansi = 30 + color_index };
+    if (color_index <= 15) return .{ .ansi = 90 + color_index - 8 };
+    return null;
+}
+
+/// True for values `parse` understands, plus empty and `none` for "no color".
+pub fn isValid(color: []const u8) bool {
+    const trimmed = std.mem.trim(u8, color, " \t\r\n");
+    if (trimmed.len == 0 or std.ascii.eqlIgnoreCase(trimmed, "none")) return true;
+    return parse(trimmed) != null;
+}
+
+/// Writes the SGR sequence that switches to `foreground`.
+pub fn writeSgr(writer: anytype, foreground: Foreground) !void {
+    switch (foreground) {
+        .ansi => |code| try writer.print("\x1b[{d}m", .{code}),
+        .rgb => |rgb| try writer.print("\x1b[38;2;{d};{d};{d}m", .{ rgb[0], rgb[1], rgb[2] }),
+    }
+}
+
+fn parseHex(digits: []const u8) ?Foreground {
+    var rgb: [3]u8 = undefined;
+    switch (digits.len) {
+        3 => for (&rgb, digits) |*channel, digit| {
+            const value = std.fmt.charToDigit(digit, 16) catch return null;
+            channel.* = value * 17;
+        },
+        6 => for (&rgb, 0..) |*channel, index| {
+            channel.* = std.fmt.parseUnsigned(u8, digits[index * 2 ..][0..2], 16) catch return null;
+        },
+        else => return null,
+    }
+    return .{ .rgb = rgb };
+}
+
+test "colors parse names, palette indexes and hex values" {
+    try std.testing.expectEqual(Foreground{ .ansi = 31 }, parse("Red").?);
+    try std.testing.expectEqual(Foreground{ .ansi = 92 }, parse("ansibrightgreen").?);
+    try std.testing.expectEqual(Foreground{ .ansi = 93 }, parse("11").?);
+    try std.testing.expectEqual(Foreground{ .rgb = .{ 0xff, 0x88, 0x00 } }, parse("#ff8800").?);
+    try std.testing.expectEqual(Foreground{ .rgb = .{ 0xff, 0x88, 0x00 } }, parse("#f80").?);
+
+    try std.testing.expect(parse("none") == null);
+    try std.testing.expect(isValid(""));
+    try std.testing.expect(isValid("none"));
+    try std.testing.expect(!isValid("purple"));
+    try std.testing.expect(!isValid("#12345"));
+    try std.testing.expect(!isValid("#gg0000"));
+    try std.testing.expect(!isValid("16"));
+}
@@ -0,0 +1,58 @@
+//! The `--debug-pane` process that shows proctmux's own log.
+//! While a log path is set, every config load adds a process tailing it, so the process survives reloads and stops with the others on exit.
+
+const std = @import("std");
+const schema = @import("schema.zig");
+
+const log = std.log.scoped(.config);
+
+pub const label = "proctmux-log";
+
+/// File the log sink copies messages to; set by `--debug-pane` for the life
+/// of the run. Empty leaves loaded configs untouched.
+pub var log_path: []const u8 = "";
+
+/// Hands `log_path` to a unified child primary, so its messages land in the
+/// parent's file and there is one debug process tailing one log.
+pub const env_var = "PROCTMUX_DEBUG_LOG";
+
+/// Adds the process tailing `log_path`. A config that already defines
+/// `label` keeps its own process.
+pub fn apply(allocator: std.mem.Allocator, cfg: *schema.Config) !void {
+    if (log_path.len == 0) return;
+    try addProcess(allocator, cfg, log_path);
+}
+
+fn addProcess(allocator: std.mem.Allocator, cfg: *schema.Config, path: []const u8) !void {
+    if (cfg.procs.contains(label)) {
+        log.warn("a process is already named '{s}'; --debug-pane does not add its own", .{label});
+        return;
+    }
+
+    var proc = schema.ProcessConfig.empty(allocator);
+    errdefer proc.deinit(allocator);
+    proc.owns_scalar_strings = true;
+    for ([_][]const u8{ "tail", "-n", "+1", "-F", path }) |part| try schema.appendOwned(allocator, &proc.cmd, part);
+    proc.description = try allocator.dupe(u8, "proctmux's own log (--debug-pane)");
+    proc.autostart = true;
+
+    const key = try allocator.dupe(u8, label);
+    errdefer allocator.free(key);
+    try cfg.procs.put(key, proc);
+}
+
+test "debug pane tails the log file and yields to a process of the same name" {
+    var cfg = schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+
+    try addProcess(std.testing.allocator, &cfg, "/tmp/proctmux-debug-1.log");
+    const proc = cfg.procs.getPtr(label).?;
+    try std.testing.expect(proc.autostart);
+    try std.testing.expectEqual(@as(usize, 5), proc.cmd.items.len);
+    try std.testing.expectEqualStrings("tail", proc.cmd.items[0]);
+    try std.testing.expectEqualStrings("/tmp/proctmux-debug-1.log", proc.cmd.items[4]);
+
+    try addProcess(std.testing.allocator, &cfg, "/tmp/other.log");
+    try std.testing.expectEqual(@as(usize, 1), cfg.procs.count());
+    try std.testing.expectEqualStrings("/tmp/proctmux-debug-1.log", cfg.procs.getPtr(label).?.cmd.items[4]);
+}
@@ -24,20 +24,38 @@ pub fn apply(cfg: *schema.Config, allocator: schema.Allocator) !void {
     try setListDefault(allocator, &cfg.keybinding.start, &.{ "s", "enter" });
     try setListDefault(allocator, &cfg.keybinding.stop, &.{"x"});
     try setListDefault(allocator, &cfg.keybinding.restart, &.{"r"});
+    try setListDefault(allocator, &cfg.keybinding.start_all, &.{"S"});
+    try setListDefault(allocator, &cfg.keybinding.stop_all, &.{"X"});
     try setListDefault(allocator, &cfg.keybinding.filter, &.{"/"});
     try setListDefault(allocator, &cfg.keybinding.submit_filter, &.{"enter"});
     try setListDefault(allocator, &cfg.keybinding.toggle_running, &.{"R"});
+    try setListDefault(allocator, &cfg.keybinding.next_running, &.{"n"});
+    try setListDefault(allocator, &cfg.keybinding.next_stopped, &.{"N"});
     try setListDefault(allocator, &cfg.keybinding.toggle_help, &.{"?"});
     try setListDefault(allocator, &cfg.keybinding.toggle_focus, &.{"ctrl+w"});
     try setListDefault(allocator, &cfg.keybinding.focus_client, &.{"ctrl+left"});
     try setListDefault(allocator, &cfg.keybinding.focus_server, &.{"ctrl+right"});
+    try setListDefault(allocator, &cfg.keybinding.toggle_zoom, &.{"f5"});
+    try setListDefault(allocator, &cfg.keybinding.grow_output, &.{"f8"});
+    try setListDefault(allocator, &cfg.keybinding.shrink_output, &.{"f7"});
+    try setListDefault(allocator, &cfg.keybinding.copy_mode, &.{"f6"});
+    try setListDefault(allocator, &cfg.keybinding.send_input, &.{"i"});
+    try setListDefault(allocator, &cfg.keybinding.reload_config, &.{"ctrl+r"});
+    try setListDefault(allocator, &cfg.keybinding.edit_config, &.{"e"});
+    try setListDefault(allocator, &cfg.keybinding.clear_output, &.{"ctrl+l"});
+    try setListDefault(allocator, &cfg.keybinding.copy_output, &.{"y"});
+    try setListDefault(allocator, &cfg.keybinding.command_palette, &.{":"});
     try setListDefault(allocator, &cfg.keybinding.docs, &.{"d"});
 
     if (cfg.layout.category_search_prefix.len == 0) cfg.layout.category_search_prefix = "cat:";
     if (cfg.layout.placeholder_banner.len == 0) cfg.layout.placeholder_banner = banner;
+    if (cfg.layout.filter_fields.len == 0) cfg.layout.filter_fields = "name,description,categories";
     if (cfg.layout.processes_list_width <= 0 or cfg.layout.processes_list_width > 100) {
         cfg.layout.processes_list_width = 30;
     }
+    if (cfg.layout.process_list_percent < 0 or cfg.layout.process_list_percent >= 100) {
+        cfg.layout.process_list_percent = 0;
+    }
 
     if (cfg.style.pointer_char.len == 0) cfg.style.pointer_char = "▶";
     if (cfg.style.selected_process_color.len == 0) cfg.style.selected_process_color = "white";
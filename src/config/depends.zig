@@ -0,0 +1,148 @@
+//! Process start ordering for `depends_on` and `order`.
+//! Cycles are rejected when config loads, so start paths can walk dependencies without guarding against loops again.
+
+const std = @import("std");
+const schema = @import("schema.zig");
+
+const log = std.log.scoped(.config);
+
+const Mark = enum {
+    visiting,
+    done,
+};
+
+/// Fails with `error.DependencyCycle` and logs the cycle when `depends_on`
+/// entries loop. Unknown names are left for start time because discovered
+/// processes are added after load.
+pub fn validate(allocator: schema.Allocator, cfg: *const schema.Config) !void {
+    const cycle = try findCycle(allocator, cfg) orelse return;
+    defer allocator.free(cycle);
+
+    const text = try std.mem.join(allocator, " -> ", cycle);
+    defer allocator.free(text);
+    log.warn("process dependency cycle: {s}", .{text});
+    return error.DependencyCycle;
+}
+
+/// Returns the first dependency cycle in config order as a label path that
+/// repeats its first label at the end, or null when the graph is acyclic.
+pub fn findCycle(allocator: schema.Allocator, cfg: *const schema.Config) !?[]const []const u8 {
+    var marks = std.StringHashMap(Mark).init(allocator);
+    defer marks.deinit();
+    var path = std.array_list.Managed([]const u8).init(allocator);
+    defer path.deinit();
+
+    for (cfg.procs.keys()) |label| {
+        if (try visitForCycle(allocator, cfg, &marks, &path, label)) |cycle| return cycle;
+    }
+    return null;
+}
+
+fn visitForCycle(
+    allocator: schema.Allocator,
+    cfg: *const schema.Config,
+    marks: *std.StringHashMap(Mark),
+    path: *std.array_list.Managed([]const u8),
+    label: []const u8,
+) !?[]const []const u8 {
+    if (marks.get(label)) |mark| switch (mark) {
+        .done => return null,
+        .visiting => {
+            var start: usize = 0;
+            for (path.items, 0..) |item, index| {
+                if (std.mem.eql(u8, item, label)) start = index;
+            }
+            var cycle = std.array_list.Managed([]const u8).init(allocator);
+            errdefer cycle.deinit();
+            try cycle.appendSlice(path.items[start..]);
+            try cycle.append(label);
+            return try cycle.toOwnedSlice();
+        },
+    };
+
+    const proc = cfg.procs.getPtr(label) orelse return null;
+    try marks.put(label, .visiting);
+    try path.append(label);
+    for (proc.depends_on.items) |dependency| {
+        if (try visitForCycle(allocator, cfg, marks, path, dependency)) |cycle| return cycle;
+    }
+    _ = path.pop();
+    try marks.put(label, .done);
+    return null;
+}
+
+/// Lists the labels to start for `label`, dependencies first and `label`
+/// last. A dependency that names no configured process is an error.
+pub fn startOrder(allocator: schema.Allocator, cfg: *const schema.Config, label: []const u8) ![]const []const u8 {
+    var seen = std.StringHashMap(void).init(allocator);
+    defer seen.deinit();
+    var order = std.array_list.Managed([]const u8).init(allocator);
+    errdefer order.deinit();
+
+    try appendStartOrder(cfg, &seen, &order, label);
+    return order.toOwnedSlice();
+}
+
+/// Lists every configured process by `order`, lowest first. Equal values
+/// keep config order, and processes without one follow in config order.
+/// `depends_on` is not applied; starting a process starts its chain first.
+pub fn launchOrder(allocator: schema.Allocator, cfg: *const schema.Config) ![]const []const u8 {
+    const labels = try allocator.dupe([]const u8, cfg.procs.keys());
+    std.sort.block([]const u8, labels, cfg, launchesBefore);
+    return labels;
+}
+
+fn launchesBefore(cfg: *const schema.Config, a: []const u8, b: []const u8) bool {
+    const a_order = cfg.procs.getPtr(a).?.order orelse return false;
+    const b_order = cfg.procs.getPtr(b).?.order orelse return true;
+    return a_order < b_order;
+}
+
+/// Lists every configured process with its dependencies ahead of it, in
+/// `launchOrder` otherwise; reversed, it stops dependents first. Unknown
+/// dependencies are skipped here because starting reports them.
+pub fn allStartOrder(allocator: schema.Allocator, cfg: *const schema.Config) ![]const []const u8 {
+    const labels = try launchOrder(allocator, cfg);
+    defer allocator.free(labels);
+    var seen = std.StringHashMap(void).init(allocator);
+    defer seen.deinit();
+    var order = std.array_list.Managed([]const u8).init(allocator);
+    errdefer order.deinit();
+
+    for (labels) |label| try appendAllStartOrder(cfg, &seen, &order, label);
+    return order.toOwnedSlice();
+}
+
+fn appendAllStartOrder(
+    cfg: *const schema.Config,
+    seen: *std.StringHashMap(void),
+    order: *std.array_list.Managed([]const u8),
+    label: []const u8,
+) !void {
+    if (seen.contains(label)) return;
+    try seen.put(label, {});
+
+    const proc = cfg.procs.getPtr(label) orelse return;
+    for (proc.depends_on.items) |dependency| try appendAllStartOrder(cfg, seen, order, dependency);
+    try order.append(label);
+}
+
+fn appendStartOrder(
+    cfg: *const schema.Config,
+    seen: *std.StringHashMap(void),
+    order: *std.array_list.Managed([]const u8),
+    label: []const u8,
+) !void {
+    if (seen.contains(label)) return;
+    try seen.put(label, {});
+
+    const proc = cfg.procs.getPtr(label) orelse return error.ProcessNotFound;
+    for (proc.depends_on.items) |dependency| {
+        if (!cfg.procs.contains(dependency)) {
+            log.warn("process '{s}' depends on unknown process '{s}'", .{ label, dependency });
+            return error.UnknownDependency;
+        }
+        try appendStartOrder(cfg, seen, order, dependency);
+    }
+    try order.append(label);
+}
@@ -0,0 +1,96 @@
+//! `$NAME` / `${NAME}` variable expansion for config strings.
+//! Process commands and env values share this scanner so both accept the same syntax, including `\$` for a literal dollar sign.
+
+const std = @import("std");
+
+pub const Options = struct {
+    /// Keep references to unset names as written instead of dropping them,
+    /// so shell-local variables like `$1` still reach the shell.
+    keep_unknown: bool = false,
+    /// Keep the backslash of `\$` for strings a shell will parse again.
+    keep_escape: bool = false,
+};
+
+/// Expands `$NAME` and `${NAME}` references against `env_map`. A `$` not
+/// followed by a name, or written as `\$`, is never expanded.
+pub fn expand(
+    allocator: std.mem.Allocator,
+    value: []const u8,
+    env_map: *const std.process.EnvMap,
+    options: Options,
+) ![]u8 {
+    var out = std.array_list.Managed(u8).init(allocator);
+    errdefer out.deinit();
+
+    var index: usize = 0;
+    while (index < value.len) {
+        const ch = value[index];
+        if (ch == '\\' and index + 1 < value.len and value[index + 1] == '$') {
+            try out.appendSlice(if (options.keep_escape) "\\$" else "$");
+            index += 2;
+            continue;
+        }
+        if (ch != '$' or index + 1 >= value.len) {
+            try out.append(ch);
+            index += 1;
+            continue;
+        }
+
+        if (value[index + 1] == '{') {
+            const close = std.mem.indexOfScalarPos(u8, value, index + 2, '}') orelse {
+                try out.appendSlice(value[index..]);
+                break;
+            };
+            try appendReference(&out, value[index .. close + 1], value[index + 2 .. close], env_map, options);
+            index = close + 1;
+            continue;
+        }
+
+        var end = index + 1;
+        while (end < value.len and isNameChar(value[end])) end += 1;
+        if (end == index + 1) {
+            try out.append(ch);
+            index += 1;
+            continue;
+        }
+        try appendReference(&out, value[index..end], value[index + 1 .. end], env_map, options);
+        index = end;
+    }
+
+    return out.toOwnedSlice();
+}
+
+fn appendReference(
+    out: *std.array_list.Managed(u8),
+    written: []const u8,
+    name: []const u8,
+    env_map: *const std.process.EnvMap,
+    options: Options,
+) !void {
+    if (env_map.get(name)) |replacement| {
+        try out.appendSlice(replacement);
+    } else if (options.keep_unknown) {
+        try out.appendSlice(written);
+    }
+}
+
+fn isNameChar(ch: u8) bool {
+    return std.ascii.isAlphanumeric(ch) or ch == '_';
+}
+
+test "expansion keeps unknown names and escapes when asked" {
+    var env_map = std.process.EnvMap.init(std.testing.allocator);
+    defer env_map.deinit();
+    try env_map.put("PORT", "8080");
+
+    const shell = try expand(std.testing.allocator, "serve --port $PORT ${PORT} $1 \\$PORT", &env_map, .{
+        .keep_unknown = true,
+        .keep_escape = true,
+    });
+    defer std.testing.allocator.free(shell);
+    try std.testing.expectEqualStrings("serve --port 8080 8080 $1 \\$PORT", shell);
+
+    const plain = try expand(std.testing.allocator, "${MISSING}x \\$PORT", &env_map, .{});
+    defer std.testing.allocator.free(plain);
+    try std.testing.expectEqualStrings("x $PORT", plain);
+}
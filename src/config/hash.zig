@@ -1,5 +1,5 @@
-//! Stable Project Config hashing for socket identity.
-//! Only fields that should distinguish running proctmux instances participate, so clients and primaries agree on a Unix socket path for the same effective project.
+//! Stable Project Config hashing for socket identity and reload comparison.
+//! Loaded projects are keyed by their config file paths so an edit keeps the socket; `toHash` covers configs built in code, and `processEql` tells reload which processes changed.
 
 const std = @import("std");
 const schema = @import("schema.zig");
@@ -16,6 +16,33 @@ pub fn toHash(allocator: schema.Allocator, cfg: *const schema.Config) ![]const u
     return allocator.dupe(u8, &hex);
 }
 
+/// Hashes the resolved config file paths of a project, in load order. Clients
+/// and primaries started from the same files agree on it whatever the files
+/// contain.
+pub fn pathsHash(allocator: schema.Allocator, file_paths: []const []const u8) ![]const u8 {
+    var md5 = std.crypto.hash.Md5.init(.{});
+    for (file_paths) |path| {
+        md5.update(path);
+        md5.update("\n");
+    }
+    var digest: [std.crypto.hash.Md5.digest_length]u8 = undefined;
+    md5.final(&digest);
+    const hex = std.fmt.bytesToHex(digest, .lower);
+    return allocator.dupe(u8, &hex);
+}
+
+/// Compares two definitions of one process over the fields `toHash` covers,
+/// which is how config reload decides that a process changed.
+pub fn processEql(allocator: schema.Allocator, label: []const u8, a: schema.ProcessConfig, b: schema.ProcessConfig) !bool {
+    var a_buf = std.array_list.Managed(u8).init(allocator);
+    defer a_buf.deinit();
+    var b_buf = std.array_list.Managed(u8).init(allocator);
+    defer b_buf.deinit();
+    try writeProcess(allocator, &a_buf, label, a);
+    try writeProcess(allocator, &b_buf, label, b);
+    return std.mem.eql(u8, a_buf.items, b_buf.items);
+}
+
 fn writeConfig(allocator: schema.Allocator, buf: *std.array_list.Managed(u8), cfg: *const schema.Config) !void {
     try writeLine(buf, "file_path", cfg.file_path);
     try writeStringList(buf, "keybinding.quit", cfg.keybinding.quit);
@@ -24,23 +51,41 @@ fn writeConfig(allocator: schema.Allocator, buf: *std.array_list.Managed(u8), cf
     try writeStringList(buf, "keybinding.start", cfg.keybinding.start);
     try writeStringList(buf, "keybinding.stop", cfg.keybinding.stop);
     try writeStringList(buf, "keybinding.restart", cfg.keybinding.restart);
+    try writeStringList(buf, "keybinding.start_all", cfg.keybinding.start_all);
+    try writeStringList(buf, "keybinding.stop_all", cfg.keybinding.stop_all);
     try writeStringList(buf, "keybinding.filter", cfg.keybinding.filter);
     try writeStringList(buf, "keybinding.submit_filter", cfg.keybinding.submit_filter);
     try writeStringList(buf, "keybinding.toggle_running", cfg.keybinding.toggle_running);
+    try writeStringList(buf, "keybinding.next_running", cfg.keybinding.next_running);
+    try writeStringList(buf, "keybinding.next_stopped", cfg.keybinding.next_stopped);
     try writeStringList(buf, "keybinding.toggle_help", cfg.keybinding.toggle_help);
     try writeStringList(buf, "keybinding.toggle_focus", cfg.keybinding.toggle_focus);
     try writeStringList(buf, "keybinding.focus_client", cfg.keybinding.focus_client);
     try writeStringList(buf, "keybinding.focus_server", cfg.keybinding.focus_server);
+    try writeStringList(buf, "keybinding.toggle_zoom", cfg.keybinding.toggle_zoom);
+    try writeStringList(buf, "keybinding.grow_output", cfg.keybinding.grow_output);
+    try writeStringList(buf, "keybinding.shrink_output", cfg.keybinding.shrink_output);
+    try writeStringList(buf, "keybinding.copy_mode", cfg.keybinding.copy_mode);
+    try writeStringList(buf, "keybinding.send_input", cfg.keybinding.send_input);
+    try writeStringList(buf, "keybinding.reload_config", cfg.keybinding.reload_config);
+    try writeStringList(buf, "keybinding.edit_config", cfg.keybinding.edit_config);
+    try writeStringList(buf, "keybinding.clear_output", cfg.keybinding.clear_output);
+    try writeStringList(buf, "keybinding.copy_output", cfg.keybinding.copy_output);
+    try writeStringList(buf, "keybinding.command_palette", cfg.keybinding.command_palette);
     try writeStringList(buf, "keybinding.docs", cfg.keybinding.docs);
 
     try writeLine(buf, "layout.category_search_prefix", cfg.layout.category_search_prefix);
     try writeInt(buf, "layout.processes_list_width", cfg.layout.processes_list_width);
+    try writeInt(buf, "layout.process_list_percent", cfg.layout.process_list_percent);
     try writeBool(buf, "layout.hide_process_description_panel", cfg.layout.hide_process_description_panel);
     try writeBool(buf, "layout.hide_process_list_when_unfocused", cfg.layout.hide_process_list_when_unfocused);
     try writeBool(buf, "layout.sort_process_list_alpha", cfg.layout.sort_process_list_alpha);
     try writeBool(buf, "layout.sort_process_list_running_first", cfg.layout.sort_process_list_running_first);
     try writeLine(buf, "layout.placeholder_banner", cfg.layout.placeholder_banner);
     try writeBool(buf, "layout.enable_debug_process_info", cfg.layout.enable_debug_process_info);
+    try writeBool(buf, "layout.show_status_bar", cfg.layout.show_status_bar);
+    try writeLine(buf, "layout.filter_fields", cfg.layout.filter_fields);
+    try writeBool(buf, "layout.show_last_line", cfg.layout.show_last_line);
 
     try writeLine(buf, "style.selected_process_color", cfg.style.selected_process_color);
     try writeLine(buf, "style.selected_process_bg_color", cfg.style.selected_process_bg_color);
@@ -53,8 +98,23 @@ fn writeConfig(allocator: schema.Allocator, buf: *std.array_list.Managed(u8), cf
     try writeBool(buf, "general.procs_from_make_targets", cfg.general.procs_from_make_targets);
     try writeBool(buf, "general.procs_from_package_json", cfg.general.procs_from_package_json);
     try writeStringList(buf, "shell_cmd", cfg.shell_cmd);
+    try writeStringMap(allocator, buf, "environment", cfg.environment);
     try writeLine(buf, "log_file", cfg.log_file);
     try writeLine(buf, "stdout_debug_log_file", cfg.stdout_debug_log_file);
+    try writeLine(buf, "control_socket", cfg.control_socket);
+    try writeLine(buf, "http_status_addr", cfg.http_status_addr);
+    try writeLine(buf, "clipboard_command", cfg.clipboard_command);
+    try writeLine(buf, "state_file", cfg.state_file);
+    try writeLine(buf, "pause", cfg.pause);
+    try writeLine(buf, "setup", cfg.setup);
+    try writeLine(buf, "teardown", cfg.teardown);
+    try writeInt(buf, "start_delay_ms", cfg.start_delay_ms);
+    try writeBool(buf, "confirm_quit_when_running", cfg.confirm_quit_when_running);
+    try writeBool(buf, "mouse", cfg.mouse);
+    try writeBool(buf, "remain_on_exit", cfg.remain_on_exit);
+    try writeLine(buf, "start_when_running", @tagName(cfg.start_when_running));
+    try writeInt(buf, "ipc_timeout_ms", cfg.ipc_timeout_ms);
+    try writeInt(buf, "refresh_interval_ms", cfg.refresh_interval_ms);
 
     var keys = try allocator.alloc([]const u8, cfg.procs.count());
     defer allocator.free(keys);
@@ -73,20 +133,47 @@ fn writeProcess(allocator: schema.Allocator, buf: *std.array_list.Managed(u8), l
     try writeLine(buf, "proc.label", label);
     try writeLine(buf, "proc.shell", proc.shell);
     try writeStringList(buf, "proc.cmd", proc.cmd);
+    try writeStringList(buf, "proc.args", proc.args);
+    try writeLine(buf, "proc.pause", proc.pause);
     try writeLine(buf, "proc.cwd", proc.cwd);
     try writeStringMap(allocator, buf, "proc.env", proc.env);
     try writeInt(buf, "proc.stop", proc.stop);
     try writeInt(buf, "proc.stop_timeout_ms", proc.stop_timeout_ms);
+    try writeLine(buf, "proc.stop_sequence", proc.stop_sequence);
+    try writeInt(buf, "proc.max_runtime_secs", proc.max_runtime_secs);
     try writeBool(buf, "proc.autostart", proc.autostart);
+    if (proc.order) |position| try writeInt(buf, "proc.order", position) else try writeLine(buf, "proc.order", "");
+    try writeInt(buf, "proc.start_delay_ms", proc.start_delay_ms);
     try writeBool(buf, "proc.autofocus", proc.autofocus);
     try writeLine(buf, "proc.description", proc.description);
     try writeLine(buf, "proc.docs", proc.docs);
+    try writeLine(buf, "proc.hotkey", proc.hotkey);
+    try writeLine(buf, "proc.color", proc.color);
     try writeStringList(buf, "proc.meta_tags", proc.meta_tags);
     try writeStringList(buf, "proc.categories", proc.categories);
+    try writeStringList(buf, "proc.depends_on", proc.depends_on);
+    try writeStringList(buf, "proc.shell_cmd", proc.shell_cmd);
     try writeStringList(buf, "proc.add_path", proc.add_path);
     try writeInt(buf, "proc.terminal_rows", proc.terminal_rows);
     try writeInt(buf, "proc.terminal_cols", proc.terminal_cols);
     try writeStringList(buf, "proc.on_kill", proc.on_kill);
+    try writeLine(buf, "proc.on_start", proc.on_start);
+    try writeLine(buf, "proc.on_stop", proc.on_stop);
+    try writeLine(buf, "proc.on_success", proc.on_success);
+    try writeLine(buf, "proc.on_failure", proc.on_failure);
+    try writeLine(buf, "proc.log_file", proc.log_file);
+    try writeLine(buf, "proc.ready_when.command", proc.ready_when.command);
+    try writeLine(buf, "proc.ready_when.output", proc.ready_when.output);
+    try writeInt(buf, "proc.ready_when.timeout_ms", proc.ready_when.timeout_ms);
+    try writeInt(buf, "proc.ready_when.interval_ms", proc.ready_when.interval_ms);
+    try writeBool(buf, "proc.log_append", proc.log_append);
+    try writeBool(buf, "proc.reuse_pane", proc.reuse_pane);
+    try writeLine(buf, "proc.restart", @tagName(proc.restart));
+    try writeInt(buf, "proc.restart_max_retries", proc.restart_max_retries);
+    try writeInt(buf, "proc.restart_backoff_ms", proc.restart_backoff_ms);
+    try writeBool(buf, "proc.task", proc.task);
+    try writeBool(buf, "proc.keep_on_failure", proc.keep_on_failure);
+    try writeLine(buf, "proc.remain_on_exit", if (proc.remain_on_exit) |remain| (if (remain) "true" else "false") else "");
 }
 
 fn writeLine(buf: *std.array_list.Managed(u8), key: []const u8, value: []const u8) !void {
@@ -4,7 +4,12 @@
 const std = @import("std");
 const yaml_mod = @import("yaml");
 const schema = @import("schema.zig");
+const color = @import("color.zig");
 const defaults = @import("defaults.zig");
+const depends = @import("depends.zig");
+const paths = @import("paths.zig");
+
+const log = std.log.scoped(.config);
 
 const Yaml = yaml_mod.Yaml;
 const Value = Yaml.Value;
@@ -38,10 +43,15 @@ pub fn loadFile(allocator: schema.Allocator, path: []const u8) !LoadedConfig {
 pub fn loadFileInDir(allocator: schema.Allocator, dir: std.fs.Dir, path: []const u8) !LoadedConfig {
     const data = dir.readFileAlloc(allocator, path, 1024 * 1024) catch |err| switch (err) {
         error.FileNotFound => return error.FileNotFound,
-        else => return err,
+        else => {
+            log.warn("failed to open config '{s}': {s}", .{ path, @errorName(err) });
+            return err;
+        },
     };
     defer allocator.free(data);
 
+    if (findKeyLine(data, "include") > 0) return loadMergedInDir(allocator, dir, &.{path});
+
     const absolute_path = try dir.realpathAlloc(allocator, path);
     defer allocator.free(absolute_path);
 
@@ -66,6 +76,228 @@ pub fn loadDefaultInDir(allocator: schema.Allocator, dir: std.fs.Dir) !LoadedCon
 /// Parses YAML into an owned Project Config plus non-fatal warnings. Ownership
 /// transfer happens here so callers can deinit the result without YAML context.
 pub fn loadFromSlice(allocator: schema.Allocator, source: []const u8, source_path: []const u8) !LoadedConfig {
+    const trimmed = std.mem.trim(u8, source, " \t\r\n");
+    if (std.mem.eql(u8, trimmed, "{}")) return buildLoaded(allocator, null, source_path);
+
+    var yml = try parseSource(allocator, source, source_path);
+    defer yml.deinit(allocator);
+    var loaded = try buildLoaded(allocator, firstDocument(yml), source_path);
+    for (loaded.warnings.items) |*warning| warning.line = findKeyLine(source, warning.path);
+    return loaded;
+}
+
+/// Loads several config files as one. Later files override earlier ones key
+/// by key: nested maps such as `layout` and each `procs.<name>` merge field
+/// by field, while scalars and lists are replaced. Relative paths resolve
+/// against the first file, which also names the instance.
+pub fn loadFilesInDir(allocator: schema.Allocator, dir: std.fs.Dir, paths: []const []const u8) !LoadedConfig {
+    if (paths.len == 0) return loadDefaultInDir(allocator, dir);
+    if (paths.len == 1) return loadFileInDir(allocator, dir, paths[0]);
+    return loadMergedInDir(allocator, dir, paths);
+}
+
+fn loadMergedInDir(allocator: schema.Allocator, dir: std.fs.Dir, paths: []const []const u8) !LoadedConfig {
+    var merge_arena = std.heap.ArenaAllocator.init(allocator);
+    defer merge_arena.deinit();
+
+    var documents = std.array_list.Managed(Yaml).init(allocator);
+    defer {
+        for (documents.items) |*yml| yml.deinit(allocator);
+        documents.deinit();
+    }
+
+    var merger = Merger{
+        .allocator = allocator,
+        .merge_allocator = merge_arena.allocator(),
+        .documents = &documents,
+        .including = std.array_list.Managed([]const u8).init(merge_arena.allocator()),
+    };
+    var first_path: []const u8 = "";
+    var merged: ?Value = null;
+    for (paths) |path| {
+        const root = try merger.loadFile(dir, path, &first_path, .top_level);
+        merged = try merger.merge(merged, root);
+    }
+
+    return buildLoaded(allocator, merged, first_path);
+}
+
+/// Reads config files into one merged YAML value, expanding each file's
+/// `include:` list first so its own keys override what it includes.
+const Merger = struct {
+    allocator: schema.Allocator,
+    merge_allocator: schema.Allocator,
+    documents: *std.array_list.Managed(Yaml),
+    /// Files whose includes are being expanded, outermost first.
+    including: std.array_list.Managed([]const u8),
+
+    const Origin = enum { top_level, include };
+
+    fn loadFile(self: *Merger, dir: std.fs.Dir, path: []const u8, first_path: *[]const u8, origin: Origin) !?Value {
+        const data = dir.readFileAlloc(self.merge_allocator, path, 1024 * 1024) catch |err| switch (err) {
+            error.FileNotFound => {
+                log.warn("config not found at {s}", .{path});
+                return if (origin == .include) error.IncludeNotFound else error.FileNotFound;
+            },
+            else => {
+                log.warn("failed to open config '{s}': {s}", .{ path, @errorName(err) });
+                return err;
+            },
+        };
+        const absolute_path = try dir.realpathAlloc(self.merge_allocator, path);
+        if (first_path.len == 0) first_path.* = absolute_path;
+        for (self.including.items) |outer| {
+            if (!std.mem.eql(u8, outer, absolute_path)) continue;
+            log.warn("config '{s}' is included again by '{s}'", .{ absolute_path, self.including.items[self.including.items.len - 1] });
+            return error.IncludeCycle;
+        }
+
+        if (std.mem.eql(u8, std.mem.trim(u8, data, " \t\r\n"), "{}")) return null;
+        try self.documents.append(try parseSource(self.allocator, data, absolute_path));
+        const root = firstDocument(self.documents.items[self.documents.items.len - 1]) orelse return null;
+        const root_map = root.asMap() orelse return root;
+        const includes = root_map.get("include") orelse return root;
+
+        try self.including.append(absolute_path);
+        defer _ = self.including.pop();
+        const include_dir_path = std.fs.path.dirname(absolute_path) orelse "/";
+        var include_dir = try std.fs.openDirAbsolute(include_dir_path, .{});
+        defer include_dir.close();
+
+        var merged: ?Value = null;
+        const single = [_]Value{includes};
+        const include_paths: []const Value = includes.asList() orelse &single;
+        for (include_paths) |include_path| {
+            const written = include_path.asScalar() orelse return error.TypeMismatch;
+            const include_name = (try paths.resolve(self.merge_allocator, written, include_dir_path, std.posix.getenv("HOME"))) orelse written;
+            var ignored_path: []const u8 = "";
+            const included = try self.loadFile(include_dir, include_name, &ignored_path, .include);
+            merged = try self.merge(merged, included);
+        }
+
+        var local = try root_map.clone(self.merge_allocator);
+        _ = local.orderedRemove("include");
+        return self.merge(merged, .{ .map = local });
+    }
+
+    fn merge(self: *Merger, base: ?Value, overlay: ?Value) !?Value {
+        const value = overlay orelse return base;
+        return if (base) |existing| try mergeValues(self.merge_allocator, existing, value) else value;
+    }
+};
+
+/// Rejects processes with neither `shell` nor `cmd` at load time, so a typo
+/// surfaces before anything starts instead of when the process is selected.
+fn validateProcessCommands(cfg: *const schema.Config) !void {
+    var it = cfg.procs.iterator();
+    while (it.next()) |entry| {
+        const proc = entry.value_ptr;
+        if (std.mem.trim(u8, proc.shell, " \t\r\n").len > 0) continue;
+        if (proc.cmd.items.len > 0 and proc.cmd.items[0].len > 0) continue;
+        log.warn("process '{s}' needs a non-empty shell or cmd", .{entry.key_ptr.*});
+        return error.MissingProcessCommand;
+    }
+}
+
+/// Rejects process colors the list renderer would not understand, so a typo
+/// does not silently fall back to the default color.
+fn validateColors(cfg: *const schema.Config) !void {
+    var it = cfg.procs.iterator();
+    while (it.next()) |entry| {
+        const proc_color = entry.value_ptr.color;
+        if (color.isValid(proc_color)) continue;
+        log.warn("process '{s}' color '{s}' is not a known color name, 0-15 index, or #rrggbb value", .{ entry.key_ptr.*, proc_color });
+        return error.UnknownColor;
+    }
+}
+
+/// Hotkeys must be one printable character that no other process or
+/// keybinding claims; checked after defaults so default bindings count too.
+fn validateHotkeys(cfg: *const schema.Config) !void {
+    const labels = cfg.procs.keys();
+    const procs = cfg.procs.values();
+    for (labels, procs, 0..) |label, proc, index| {
+        const hotkey = proc.hotkey;
+        if (hotkey.len == 0) continue;
+        if (hotkey.len != 1 or hotkey[0] <= 0x20 or hotkey[0] > 0x7e) {
+            log.warn("process '{s}' hotkey '{s}' must be a single printable character", .{ label, hotkey });
+            return error.InvalidHotkey;
+        }
+        for (labels[0..index], procs[0..index]) |other_label, other| {
+            if (!std.mem.eql(u8, other.hotkey, hotkey)) continue;
+            log.warn("processes '{s}' and '{s}' both use hotkey '{s}'", .{ other_label, label, hotkey });
+            return error.HotkeyConflict;
+        }
+        inline for (std.meta.fields(schema.KeybindingConfig)) |field| {
+            for (@field(cfg.keybinding, field.name).items) |binding| {
+                if (std.mem.eql(u8, binding, hotkey)) {
+                    log.warn("process '{s}' hotkey '{s}' is already bound to {s}", .{ label, hotkey, field.name });
+                    return error.HotkeyConflict;
+                }
+            }
+        }
+    }
+}
+
+fn parseSource(allocator: schema.Allocator, source: []const u8, source_path: []const u8) !Yaml {
+    var yml: Yaml = .{ .source = source };
+    errdefer yml.deinit(allocator);
+
+    yml.load(allocator) catch |err| switch (err) {
+        error.ParseFailure => {
+            if (parseDiagnostic(yml)) |diagnostic| {
+                log.warn("failed to parse config '{s}' at line {d}, column {d}: {s}", .{
+                    source_path,
+                    diagnostic.line,
+                    diagnostic.column,
+                    diagnostic.message,
+                });
+            } else {
+                log.warn("failed to parse config '{s}'", .{source_path});
+            }
+            return error.ParseFailure;
+        },
+        error.DuplicateMapKey => {
+            if (duplicateKey(yml)) |duplicate| {
+                log.warn("config '{s}' repeats key '{s}' at line {d}, column {d}", .{
+                    source_path,
+                    duplicate.key,
+                    duplicate.line,
+                    duplicate.column,
+                });
+            } else {
+                log.warn("config '{s}' repeats a key in one map, such as two processes with the same name", .{source_path});
+            }
+            return err;
+        },
+        else => return err,
+    };
+    return yml;
+}
+
+fn firstDocument(yml: Yaml) ?Value {
+    if (yml.docs.items.len == 0) return null;
+    if (yml.docs.items[0] == .empty) return null;
+    return yml.docs.items[0];
+}
+
+/// Overlays `overlay` onto `base`. Only maps merge; any other overlay value
+/// replaces the base value. The result borrows strings from both inputs.
+fn mergeValues(allocator: schema.Allocator, base: Value, overlay: Value) !Value {
+    const base_map = base.asMap() orelse return overlay;
+    const overlay_map = overlay.asMap() orelse return overlay;
+
+    var out: Map = .empty;
+    try out.ensureTotalCapacity(allocator, base_map.count() + overlay_map.count());
+    for (base_map.keys(), base_map.values()) |key, value| out.putAssumeCapacity(key, value);
+    for (overlay_map.keys(), overlay_map.values()) |key, value| {
+        const gop = out.getOrPutAssumeCapacity(key);
+        gop.value_ptr.* = if (gop.found_existing) try mergeValues(allocator, gop.value_ptr.*, value) else value;
+    }
+    return .{ .map = out };
+}
+
+fn buildLoaded(allocator: schema.Allocator, root: ?Value, source_path: []const u8) !LoadedConfig {
     const arena = try allocator.create(std.heap.ArenaAllocator);
     errdefer allocator.destroy(arena);
     arena.* = std.heap.ArenaAllocator.init(allocator);
@@ -78,28 +310,21 @@ pub fn loadFromSlice(allocator: schema.Allocator, source: []const u8, source_pat
     var warnings = std.array_list.Managed(schema.Warning).init(allocator);
     errdefer deinitWarnings(allocator, &warnings);
 
-    const trimmed = std.mem.trim(u8, source, " \t\r\n");
-    if (std.mem.eql(u8, trimmed, "{}")) {
-        try defaults.apply(&cfg, arena_allocator);
-        cfg.file_path = try arena_allocator.dupe(u8, source_path);
-        return .{
-            .parent_allocator = allocator,
-            .arena = arena,
-            .config = cfg,
-            .warnings = warnings,
-        };
+    if (root) |value| {
+        try decodeDocument(arena_allocator, &cfg, &warnings, value, allocator);
+        if (cfg.version != 0 and cfg.version != schema.config_version) {
+            log.warn("config '{s}' declares version {d} but this proctmux reads version {d}; loading anyway", .{
+                source_path,
+                cfg.version,
+                schema.config_version,
+            });
+        }
+        try validateProcessCommands(&cfg);
+        try validateColors(&cfg);
+        try depends.validate(allocator, &cfg);
     }
-
-    var yml: Yaml = .{ .source = source };
-    defer yml.deinit(allocator);
-
-    yml.load(allocator) catch |err| switch (err) {
-        error.ParseFailure => return error.ParseFailure,
-        else => return err,
-    };
-
-    try decodeDocument(arena_allocator, &cfg, &warnings, yml, allocator);
     try defaults.apply(&cfg, arena_allocator);
+    try validateHotkeys(&cfg);
     cfg.file_path = try arena_allocator.dupe(u8, source_path);
 
     return .{
@@ -110,6 +335,94 @@ pub fn loadFromSlice(allocator: schema.Allocator, source: []const u8, source_pat
     };
 }
 
+/// First YAML parse error with a 1-based position, borrowed from the Yaml
+/// parse error bundle.
+pub const ParseDiagnostic = struct {
+    line: u32,
+    column: u32,
+    message: []const u8,
+};
+
+pub fn parseDiagnostic(yml: Yaml) ?ParseDiagnostic {
+    const bundle = yml.parse_errors;
+    if (bundle.errorMessageCount() == 0) return null;
+
+    const message = bundle.getErrorMessage(bundle.getMessages()[0]);
+    if (message.src_loc == .none) return null;
+    const location = bundle.getSourceLocation(message.src_loc);
+    return .{
+        .line = location.line + 1,
+        .column = location.column + 1,
+        .message = bundle.nullTerminatedString(message.msg),
+    };
+}
+
+/// Second occurrence of a repeated map key with a 1-based position. The Yaml
+/// loader only reports `DuplicateMapKey`, so this walks the parsed tree the
+/// same way it does. Keys borrow from the source.
+pub const DuplicateKey = struct {
+    key: []const u8,
+    line: u32,
+    column: u32,
+};
+
+pub fn duplicateKey(yml: Yaml) ?DuplicateKey {
+    const tree = yml.tree orelse return null;
+    for (tree.docs) |doc| {
+        if (duplicateKeyInNode(tree, doc)) |duplicate| return duplicate;
+    }
+    return null;
+}
+
+fn duplicateKeyInNode(tree: yaml_mod.Tree, node: yaml_mod.Tree.Node.Index) ?DuplicateKey {
+    const data = tree.nodeData(node);
+    switch (tree.nodeTag(node)) {
+        .doc => return duplicateKeyInChild(tree, data.maybe_node),
+        .doc_with_directive => return duplicateKeyInChild(tree, data.doc_with_directive.maybe_node),
+        .map_single => return duplicateKeyInChild(tree, data.map.maybe_node),
+        .map_many => {
+            const map = tree.extraData(yaml_mod.Tree.Map, data.extra);
+            var extra_end = map.end;
+            for (0..map.data.map_len) |index| {
+                const entry = tree.extraData(yaml_mod.Tree.Map.Entry, extra_end);
+                extra_end = entry.end;
+                const key = tree.rawString(entry.data.key, entry.data.key);
+
+                var earlier_end = map.end;
+                for (0..index) |_| {
+                    const earlier = tree.extraData(yaml_mod.Tree.Map.Entry, earlier_end);
+                    earlier_end = earlier.end;
+                    if (!std.mem.eql(u8, tree.rawString(earlier.data.key, earlier.data.key), key)) continue;
+                    const location = tree.tokens.items(.line_col)[@intFromEnum(entry.data.key)];
+                    return .{ .key = key, .line = location.line + 1, .column = location.col + 1 };
+                }
+                if (duplicateKeyInChild(tree, entry.data.maybe_node)) |duplicate| return duplicate;
+            }
+            return null;
+        },
+        .list_one => return duplicateKeyInNode(tree, data.node),
+        .list_two => {
+            if (duplicateKeyInNode(tree, data.list.el1)) |duplicate| return duplicate;
+            return duplicateKeyInNode(tree, data.list.el2);
+        },
+        .list_many => {
+            const list = tree.extraData(yaml_mod.Tree.List, data.extra);
+            var extra_end = list.end;
+            for (0..list.data.list_len) |_| {
+                const element = tree.extraData(yaml_mod.Tree.List.Entry, extra_end);
+                extra_end = element.end;
+                if (duplicateKeyInNode(tree, element.data.node)) |duplicate| return duplicate;
+            }
+            return null;
+        },
+        .list_empty, .value, .string_value => return null,
+    }
+}
+
+fn duplicateKeyInChild(tree: yaml_mod.Tree, child: yaml_mod.Tree.Node.OptionalIndex) ?DuplicateKey {
+    return duplicateKeyInNode(tree, child.unwrap() orelse return null);
+}
+
 fn deinitWarnings(allocator: schema.Allocator, warnings: *std.array_list.Managed(schema.Warning)) void {
     for (warnings.items) |warning| {
         allocator.free(warning.path);
@@ -136,33 +449,70 @@ fn decodeDocument(
     allocator: schema.Allocator,
     cfg: *schema.Config,
     warnings: *std.array_list.Managed(schema.Warning),
-    yml: Yaml,
+    document: Value,
     warning_allocator: schema.Allocator,
 ) !void {
-    if (yml.docs.items.len == 0) return;
-    if (yml.docs.items[0] == .empty) return;
-    var root = yml.docs.items[0].asMap() orelse return error.TypeMismatch;
+    var root = document.asMap() orelse return error.TypeMismatch;
 
     var it = root.iterator();
     while (it.next()) |entry| {
         const key = entry.key_ptr.*;
         const value = entry.value_ptr.*;
-        if (std.mem.eql(u8, key, "keybinding")) {
+        if (std.mem.eql(u8, key, "version")) {
+            cfg.version = try decodeInt(value);
+        } else if (std.mem.eql(u8, key, "keybinding")) {
             try decodeKeybinding(allocator, &cfg.keybinding, value);
         } else if (std.mem.eql(u8, key, "layout")) {
-            try decodeLayout(allocator, &cfg.layout, value);
+            try decodeLayout(allocator, &cfg.layout, value, warnings, warning_allocator);
         } else if (std.mem.eql(u8, key, "style")) {
             try decodeStyle(allocator, &cfg.style, value, warnings, warning_allocator);
         } else if (std.mem.eql(u8, key, "general")) {
             try decodeGeneral(allocator, &cfg.general, value, warnings, warning_allocator);
         } else if (std.mem.eql(u8, key, "shell_cmd")) {
             try decodeStringList(allocator, &cfg.shell_cmd, value);
+        } else if (std.mem.eql(u8, key, "environment")) {
+            try decodeStringMap(allocator, &cfg.environment, value);
         } else if (std.mem.eql(u8, key, "log_file")) {
             cfg.log_file = try dupeString(allocator, value);
         } else if (std.mem.eql(u8, key, "stdout_debug_log_file")) {
             cfg.stdout_debug_log_file = try dupeString(allocator, value);
+        } else if (std.mem.eql(u8, key, "control_socket")) {
+            cfg.control_socket = try dupeString(allocator, value);
+        } else if (std.mem.eql(u8, key, "http_status_addr")) {
+            cfg.http_status_addr = try dupeString(allocator, value);
+        } else if (std.mem.eql(u8, key, "clipboard_command")) {
+            cfg.clipboard_command = try dupeString(allocator, value);
+        } else if (std.mem.eql(u8, key, "state_file")) {
+            cfg.state_file = try dupeString(allocator, value);
+        } else if (std.mem.eql(u8, key, "pause")) {
+            cfg.pause = try dupeString(allocator, value);
+        } else if (std.mem.eql(u8, key, "setup")) {
+            cfg.setup = try dupeString(allocator, value);
+        } else if (std.mem.eql(u8, key, "teardown")) {
+            cfg.teardown = try dupeString(allocator, value);
+        } else if (std.mem.eql(u8, key, "start_delay_ms")) {
+            cfg.start_delay_ms = try decodeInt(value);
+        } else if (std.mem.eql(u8, key, "confirm_quit_when_running")) {
+            cfg.confirm_quit_when_running = try decodeBool(value);
+        } else if (std.mem.eql(u8, key, "mouse")) {
+            cfg.mouse = try decodeBool(value);
+        } else if (std.mem.eql(u8, key, "remain_on_exit")) {
+            cfg.remain_on_exit = try decodeBool(value);
+        } else if (std.mem.eql(u8, key, "start_when_running")) {
+            cfg.start_when_running = schema.StartWhenRunning.parse(scalar(value)) orelse {
+                log.warn("unknown start_when_running '{s}'; use notice or focus", .{scalar(value)});
+                return error.InvalidStartWhenRunning;
+            };
+        } else if (std.mem.eql(u8, key, "ipc_timeout_ms")) {
+            cfg.ipc_timeout_ms = try decodeInt(value);
+        } else if (std.mem.eql(u8, key, "refresh_interval_ms")) {
+            cfg.refresh_interval_ms = try decodeInt(value);
         } else if (std.mem.eql(u8, key, "procs")) {
-            try decodeProcs(allocator, &cfg.procs, value, warnings, warning_allocator);
+            try decodeProcs(allocator, &cfg.procs, value, root.get("default_process"), warnings, warning_allocator);
+        } else if (std.mem.eql(u8, key, "default_process")) {
+            // Folded into each process by decodeProcs; checked there once
+            // even when `procs` is empty.
+            if (root.get("procs") == null) _ = try processDefaults(allocator, value, warnings, warning_allocator);
         } else if (isDeadTopLevel(key)) {
             try addWarning(warning_allocator, warnings, .dead_field, key, "dead config field ignored");
         } else {
@@ -171,17 +521,31 @@ fn decodeDocument(
     }
 }
 
+/// Keybinding keys are action names; an unknown one is a config error so a
+/// typo cannot silently leave the default binding in place.
 fn decodeKeybinding(allocator: schema.Allocator, cfg: *schema.KeybindingConfig, value: Value) !void {
     var map = value.asMap() orelse return error.TypeMismatch;
     var it = map.iterator();
-    while (it.next()) |entry| {
+    entries: while (it.next()) |entry| {
         const key = entry.key_ptr.*;
-        const v = entry.value_ptr.*;
-        if (std.mem.eql(u8, key, "quit")) try decodeStringList(allocator, &cfg.quit, v) else if (std.mem.eql(u8, key, "up")) try decodeStringList(allocator, &cfg.up, v) else if (std.mem.eql(u8, key, "down")) try decodeStringList(allocator, &cfg.down, v) else if (std.mem.eql(u8, key, "start")) try decodeStringList(allocator, &cfg.start, v) else if (std.mem.eql(u8, key, "stop")) try decodeStringList(allocator, &cfg.stop, v) else if (std.mem.eql(u8, key, "restart")) try decodeStringList(allocator, &cfg.restart, v) else if (std.mem.eql(u8, key, "filter")) try decodeStringList(allocator, &cfg.filter, v) else if (std.mem.eql(u8, key, "submit_filter")) try decodeStringList(allocator, &cfg.submit_filter, v) else if (std.mem.eql(u8, key, "toggle_running")) try decodeStringList(allocator, &cfg.toggle_running, v) else if (std.mem.eql(u8, key, "toggle_help")) try decodeStringList(allocator, &cfg.toggle_help, v) else if (std.mem.eql(u8, key, "toggle_focus")) try decodeStringList(allocator, &cfg.toggle_focus, v) else if (std.mem.eql(u8, key, "focus_client")) try decodeStringList(allocator, &cfg.focus_client, v) else if (std.mem.eql(u8, key, "focus_server")) try decodeStringList(allocator, &cfg.focus_server, v) else if (std.mem.eql(u8, key, "docs")) try decodeStringList(allocator, &cfg.docs, v);
+        inline for (std.meta.fields(schema.KeybindingConfig)) |field| {
+            if (std.mem.eql(u8, key, field.name)) {
+                try decodeStringList(allocator, &@field(cfg, field.name), entry.value_ptr.*);
+                continue :entries;
+            }
+        }
+        log.warn("unknown keybinding action '{s}'", .{key});
+        return error.UnknownKeybindingAction;
     }
 }
 
-fn decodeLayout(allocator: schema.Allocator, cfg: *schema.LayoutConfig, value: Value) !void {
+fn decodeLayout(
+    allocator: schema.Allocator,
+    cfg: *schema.LayoutConfig,
+    value: Value,
+    warnings: *std.array_list.Managed(schema.Warning),
+    warning_allocator: schema.Allocator,
+) !void {
     var map = value.asMap() orelse return error.TypeMismatch;
     var it = map.iterator();
     while (it.next()) |entry| {
@@ -191,6 +555,8 @@ fn decodeLayout(allocator: schema.Allocator, cfg: *schema.LayoutConfig, value: V
             cfg.category_search_prefix = try dupeString(allocator, v);
         } else if (std.mem.eql(u8, key, "processes_list_width")) {
             cfg.processes_list_width = try decodeInt(v);
+        } else if (std.mem.eql(u8, key, "process_list_percent")) {
+            cfg.process_list_percent = try decodeInt(v);
         } else if (std.mem.eql(u8, key, "hide_process_description_panel")) {
             cfg.hide_process_description_panel = try decodeBool(v);
         } else if (std.mem.eql(u8, key, "hide_process_list_when_unfocused")) {
@@ -203,7 +569,36 @@ fn decodeLayout(allocator: schema.Allocator, cfg: *schema.LayoutConfig, value: V
             cfg.placeholder_banner = try dupeString(allocator, v);
         } else if (std.mem.eql(u8, key, "enable_debug_process_info")) {
             cfg.enable_debug_process_info = try decodeBool(v);
+        } else if (std.mem.eql(u8, key, "show_status_bar")) {
+            cfg.show_status_bar = try decodeBool(v);
+        } else if (std.mem.eql(u8, key, "show_last_line")) {
+            cfg.show_last_line = try decodeBool(v);
+        } else if (std.mem.eql(u8, key, "filter_fields")) {
+            cfg.filter_fields = try dupeString(allocator, v);
+            try warnUnknownFilterFields(cfg.filter_fields, warnings, warning_allocator);
+        } else {
+            const path = try std.fmt.allocPrint(warning_allocator, "layout.{s}", .{key});
+            defer warning_allocator.free(path);
+            try addWarning(warning_allocator, warnings, .unknown_field, path, "layout field ignored");
+        }
+    }
+}
+
+fn warnUnknownFilterFields(
+    text: []const u8,
+    warnings: *std.array_list.Managed(schema.Warning),
+    warning_allocator: schema.Allocator,
+) !void {
+    var parts = std.mem.splitScalar(u8, text, ',');
+    next: while (parts.next()) |part| {
+        const field = std.mem.trim(u8, part, " \t");
+        if (field.len == 0) continue;
+        for (schema.filter_fields) |known| {
+            if (std.mem.eql(u8, field, known)) continue :next;
         }
+        const message = try std.fmt.allocPrint(warning_allocator, "unknown filter field '{s}' ignored; use name, description, or categories", .{field});
+        defer warning_allocator.free(message);
+        try addWarning(warning_allocator, warnings, .unknown_field, "layout.filter_fields", message);
     }
 }
 
@@ -266,20 +661,28 @@ fn decodeGeneral(
     }
 }
 
+/// Decodes `procs`, laying each process over `default_process` so shared
+/// fields are written once.
 fn decodeProcs(
     allocator: schema.Allocator,
     procs: *schema.ProcessMap,
     value: Value,
+    default_process: ?Value,
     warnings: *std.array_list.Managed(schema.Warning),
     warning_allocator: schema.Allocator,
 ) !void {
+    const base: ?Map = if (default_process) |d| try processDefaults(allocator, d, warnings, warning_allocator) else null;
+
     var map = value.asMap() orelse return error.TypeMismatch;
     var it = map.iterator();
     while (it.next()) |entry| {
         var proc = schema.ProcessConfig.empty(allocator);
         errdefer proc.deinit(allocator);
 
-        try decodeProcess(allocator, entry.key_ptr.*, &proc, entry.value_ptr.*, warnings, warning_allocator);
+        const merged = if (base) |b| try overProcessDefaults(allocator, b, entry.value_ptr.*) else entry.value_ptr.*;
+        const section = try std.fmt.allocPrint(warning_allocator, "procs.{s}", .{entry.key_ptr.*});
+        defer warning_allocator.free(section);
+        try decodeProcess(allocator, entry.key_ptr.*, section, &proc, merged, warnings, warning_allocator);
 
         const label = try allocator.dupe(u8, entry.key_ptr.*);
         errdefer allocator.free(label);
@@ -287,9 +690,47 @@ fn decodeProcs(
     }
 }
 
+/// Checks `default_process` once as a process of its own, so a bad value
+/// fails at its own path and an ignored key warns once instead of per
+/// process. Ignored keys are dropped from the returned map.
+fn processDefaults(
+    allocator: schema.Allocator,
+    value: Value,
+    warnings: *std.array_list.Managed(schema.Warning),
+    warning_allocator: schema.Allocator,
+) !Map {
+    const map = value.asMap() orelse return error.TypeMismatch;
+    var scratch = schema.ProcessConfig.empty(allocator);
+    defer scratch.deinit(allocator);
+    const first_warning = warnings.items.len;
+    try decodeProcess(allocator, "default_process", "default_process", &scratch, value, warnings, warning_allocator);
+
+    var out = try map.clone(allocator);
+    for (warnings.items[first_warning..]) |warning| {
+        _ = out.orderedRemove(warning.path["default_process.".len..]);
+    }
+    return out;
+}
+
+/// Lays a process over the defaults field by field; nested maps such as `env`
+/// and `ready_when` merge by key. `shell` and `cmd` are one field here, since
+/// an inherited `shell` would otherwise win over the process's own `cmd`.
+fn overProcessDefaults(allocator: schema.Allocator, base: Map, own: Value) !Value {
+    const own_map = own.asMap() orelse return own;
+    var inherited = base;
+    if (own_map.contains("shell") or own_map.contains("cmd")) {
+        inherited = try base.clone(allocator);
+        _ = inherited.orderedRemove("shell");
+        _ = inherited.orderedRemove("cmd");
+    }
+    return mergeValues(allocator, .{ .map = inherited }, own);
+}
+
+/// Warnings are reported under `section`, such as `procs.api`.
 fn decodeProcess(
     allocator: schema.Allocator,
     label: []const u8,
+    section: []const u8,
     proc: *schema.ProcessConfig,
     value: Value,
     warnings: *std.array_list.Managed(schema.Warning),
@@ -304,26 +745,49 @@ fn decodeProcess(
             proc.shell = try dupeString(allocator, v);
         } else if (std.mem.eql(u8, key, "cmd")) {
             try decodeStringList(allocator, &proc.cmd, v);
+        } else if (std.mem.eql(u8, key, "args")) {
+            try decodeStringList(allocator, &proc.args, v);
+        } else if (std.mem.eql(u8, key, "pause")) {
+            proc.pause = try dupeString(allocator, v);
         } else if (std.mem.eql(u8, key, "cwd")) {
             proc.cwd = try dupeString(allocator, v);
         } else if (std.mem.eql(u8, key, "env")) {
             try decodeStringMap(allocator, &proc.env, v);
         } else if (std.mem.eql(u8, key, "stop")) {
-            proc.stop = try decodeInt(v);
+            // Numeric values are signals; anything else is a key sequence
+            // typed into the process terminal, such as `q<Enter>`.
+            proc.stop = decodeInt(v) catch blk: {
+                proc.stop_sequence = try dupeString(allocator, v);
+                break :blk 0;
+            };
         } else if (std.mem.eql(u8, key, "stop_timeout_ms")) {
             proc.stop_timeout_ms = try decodeInt(v);
+        } else if (std.mem.eql(u8, key, "max_runtime_secs")) {
+            proc.max_runtime_secs = try decodeInt(v);
         } else if (std.mem.eql(u8, key, "autostart")) {
             proc.autostart = try decodeBool(v);
+        } else if (std.mem.eql(u8, key, "order")) {
+            proc.order = try decodeInt(v);
+        } else if (std.mem.eql(u8, key, "start_delay_ms")) {
+            proc.start_delay_ms = try decodeInt(v);
         } else if (std.mem.eql(u8, key, "autofocus")) {
             proc.autofocus = try decodeBool(v);
         } else if (std.mem.eql(u8, key, "description")) {
             proc.description = try dupeString(allocator, v);
         } else if (std.mem.eql(u8, key, "docs")) {
             proc.docs = try dupeString(allocator, v);
+        } else if (std.mem.eql(u8, key, "hotkey")) {
+            proc.hotkey = try dupeString(allocator, v);
+        } else if (std.mem.eql(u8, key, "color")) {
+            proc.color = try dupeString(allocator, v);
         } else if (std.mem.eql(u8, key, "meta_tags")) {
             try decodeStringList(allocator, &proc.meta_tags, v);
         } else if (std.mem.eql(u8, key, "categories")) {
             try decodeStringList(allocator, &proc.categories, v);
+        } else if (std.mem.eql(u8, key, "depends_on")) {
+            try decodeStringList(allocator, &proc.depends_on, v);
+        } else if (std.mem.eql(u8, key, "shell_cmd")) {
+            try decodeStringList(allocator, &proc.shell_cmd, v);
         } else if (std.mem.eql(u8, key, "add_path")) {
             try decodeStringList(allocator, &proc.add_path, v);
         } else if (std.mem.eql(u8, key, "terminal_rows")) {
@@ -332,14 +796,77 @@ fn decodeProcess(
             proc.terminal_cols = try decodeInt(v);
         } else if (std.mem.eql(u8, key, "on_kill")) {
             try decodeStringList(allocator, &proc.on_kill, v);
+        } else if (std.mem.eql(u8, key, "on_start")) {
+            proc.on_start = try dupeString(allocator, v);
+        } else if (std.mem.eql(u8, key, "on_stop")) {
+            proc.on_stop = try dupeString(allocator, v);
+        } else if (std.mem.eql(u8, key, "on_success")) {
+            proc.on_success = try dupeString(allocator, v);
+        } else if (std.mem.eql(u8, key, "on_failure")) {
+            proc.on_failure = try dupeString(allocator, v);
+        } else if (std.mem.eql(u8, key, "log_file")) {
+            proc.log_file = try dupeString(allocator, v);
+        } else if (std.mem.eql(u8, key, "log_append")) {
+            proc.log_append = try decodeBool(v);
+        } else if (std.mem.eql(u8, key, "reuse_pane")) {
+            proc.reuse_pane = try decodeBool(v);
+        } else if (std.mem.eql(u8, key, "ready_when")) {
+            const ready_section = try std.fmt.allocPrint(warning_allocator, "{s}.ready_when", .{section});
+            defer warning_allocator.free(ready_section);
+            try decodeReadyCheck(allocator, ready_section, &proc.ready_when, v, warnings, warning_allocator);
+        } else if (std.mem.eql(u8, key, "restart")) {
+            proc.restart = schema.RestartPolicy.parse(scalar(v)) orelse {
+                log.warn("process '{s}' has unknown restart policy '{s}'; use never, always or on-failure", .{ label, scalar(v) });
+                return error.InvalidRestartPolicy;
+            };
+        } else if (std.mem.eql(u8, key, "restart_max_retries")) {
+            proc.restart_max_retries = try decodeInt(v);
+        } else if (std.mem.eql(u8, key, "restart_backoff_ms")) {
+            proc.restart_backoff_ms = try decodeInt(v);
+        } else if (std.mem.eql(u8, key, "task")) {
+            proc.task = try decodeBool(v);
+        } else if (std.mem.eql(u8, key, "keep_on_failure")) {
+            proc.keep_on_failure = try decodeBool(v);
+        } else if (std.mem.eql(u8, key, "remain_on_exit")) {
+            proc.remain_on_exit = try decodeBool(v);
         } else {
-            const path = try std.fmt.allocPrint(warning_allocator, "procs.{s}.{s}", .{ label, key });
+            const path = try std.fmt.allocPrint(warning_allocator, "{s}.{s}", .{ section, key });
             defer warning_allocator.free(path);
             try addWarning(warning_allocator, warnings, if (isDeadProcessField(key)) .dead_field else .unknown_field, path, "process field ignored");
         }
     }
 }
 
+/// Warnings are reported under `section`, such as `procs.api.ready_when`.
+fn decodeReadyCheck(
+    allocator: schema.Allocator,
+    section: []const u8,
+    check: *schema.ReadyCheck,
+    value: Value,
+    warnings: *std.array_list.Managed(schema.Warning),
+    warning_allocator: schema.Allocator,
+) !void {
+    var map = value.asMap() orelse return error.TypeMismatch;
+    var it = map.iterator();
+    while (it.next()) |entry| {
+        const key = entry.key_ptr.*;
+        const v = entry.value_ptr.*;
+        if (std.mem.eql(u8, key, "command")) {
+            check.command = try dupeString(allocator, v);
+        } else if (std.mem.eql(u8, key, "output")) {
+            check.output = try dupeString(allocator, v);
+        } else if (std.mem.eql(u8, key, "timeout_ms")) {
+            check.timeout_ms = try decodeInt(v);
+        } else if (std.mem.eql(u8, key, "interval_ms")) {
+            check.interval_ms = try decodeInt(v);
+        } else {
+            const path = try std.fmt.allocPrint(warning_allocator, "{s}.{s}", .{ section, key });
+            defer warning_allocator.free(path);
+            try addWarning(warning_allocator, warnings, .unknown_field, path, "ready_when field ignored");
+        }
+    }
+}
+
 fn decodeStringList(allocator: schema.Allocator, out: *schema.StringList, value: Value) !void {
     const list = value.asList() orelse return error.TypeMismatch;
     for (list) |item| try schema.appendOwned(allocator, out, scalar(item));
@@ -361,6 +888,36 @@ fn scalar(value: Value) []const u8 {
     return value.asScalar() orelse "";
 }
 
+/// Best-effort 1-based line of a dotted warning path such as
+/// `procs.api.comand`, found by matching each key on a later line than the
+/// previous one. Returns 0 when a key cannot be found, e.g. a dotted name.
+fn findKeyLine(source: []const u8, path: []const u8) u32 {
+    var lines = std.mem.splitScalar(u8, source, '\n');
+    var line_number: u32 = 0;
+    var segments = std.mem.splitScalar(u8, path, '.');
+    segment: while (segments.next()) |segment| {
+        while (lines.next()) |line| {
+            line_number += 1;
+            if (isKeyLine(line, segment)) continue :segment;
+        }
+        return 0;
+    }
+    return line_number;
+}
+
+fn isKeyLine(line: []const u8, key: []const u8) bool {
+    var rest = std.mem.trimLeft(u8, line, " \t");
+    const quote: ?u8 = if (rest.len > 0 and (rest[0] == '"' or rest[0] == '\'')) rest[0] else null;
+    if (quote != null) rest = rest[1..];
+    if (!std.mem.startsWith(u8, rest, key)) return false;
+    rest = rest[key.len..];
+    if (quote) |q| {
+        if (rest.len == 0 or rest[0] != q) return false;
+        rest = rest[1..];
+    }
+    return std.mem.startsWith(u8, std.mem.trimLeft(u8, rest, " \t"), ":");
+}
+
 fn decodeInt(value: Value) !i32 {
     return std.fmt.parseInt(i32, scalar(value), 10);
 }
@@ -0,0 +1,58 @@
+//! Command-line `--set <process>.<key>=<value>` overrides.
+//! Each override adds one environment variable to a single process at launch, so one-off values need no config edit.
+
+const std = @import("std");
+const schema = @import("schema.zig");
+
+const log = std.log.scoped(.config);
+
+pub const Override = struct {
+    process: []const u8,
+    key: []const u8,
+    value: []const u8,
+};
+
+/// Splits `<process>.<key>=<value>`. The key is taken after the last `.` so
+/// process names may contain dots; the value may contain anything.
+pub fn parse(text: []const u8) !Override {
+    const eq_index = std.mem.indexOfScalar(u8, text, '=') orelse return error.InvalidSetFlag;
+    const target = text[0..eq_index];
+    const dot_index = std.mem.lastIndexOfScalar(u8, target, '.') orelse return error.InvalidSetFlag;
+    const override = Override{
+        .process = target[0..dot_index],
+        .key = target[dot_index + 1 ..],
+        .value = text[eq_index + 1 ..],
+    };
+    if (override.process.len == 0 or override.key.len == 0) return error.InvalidSetFlag;
+    return override;
+}
+
+/// Applies every override in order, so a later `--set` for the same key wins.
+/// A process name missing from the config is an error rather than ignored.
+pub fn apply(allocator: std.mem.Allocator, cfg: *schema.Config, overrides: []const []const u8) !void {
+    for (overrides) |text| {
+        const override = try parse(text);
+        const proc = cfg.procs.getPtr(override.process) orelse {
+            log.warn("--set names unknown process '{s}'", .{override.process});
+            return error.UnknownSetProcess;
+        };
+        try schema.putOwnedString(allocator, &proc.set_env, override.key, override.value);
+    }
+}
+
+test "overrides split process, key and value" {
+    const simple = try parse("worker.CONCURRENCY=4");
+    try std.testing.expectEqualStrings("worker", simple.process);
+    try std.testing.expectEqualStrings("CONCURRENCY", simple.key);
+    try std.testing.expectEqualStrings("4", simple.value);
+
+    const dotted = try parse("api.v2.URL=http://a.b/?x=1");
+    try std.testing.expectEqualStrings("api.v2", dotted.process);
+    try std.testing.expectEqualStrings("URL", dotted.key);
+    try std.testing.expectEqualStrings("http://a.b/?x=1", dotted.value);
+
+    try std.testing.expectError(error.InvalidSetFlag, parse("worker=4"));
+    try std.testing.expectError(error.InvalidSetFlag, parse("worker.CONCURRENCY"));
+    try std.testing.expectError(error.InvalidSetFlag, parse(".KEY=1"));
+    try std.testing.expectError(error.InvalidSetFlag, parse("worker.=1"));
+}
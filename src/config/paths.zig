@@ -0,0 +1,111 @@
+//! Resolution of paths written in config files.
+//! `~` expands to the home directory, relative paths anchor at the config file's directory, and the result is normalized lexically; `~user` is left as written.
+
+const std = @import("std");
+
+/// Returns a newly allocated, normalized path, or null when `raw` should be
+/// kept as written: it is already absolute and normal, names another user's
+/// home (`~user`), or uses `~` without a known home directory.
+pub fn resolve(
+    allocator: std.mem.Allocator,
+    raw: []const u8,
+    base_dir: []const u8,
+    home: ?[]const u8,
+) !?[]const u8 {
+    const joined = if (std.mem.eql(u8, raw, "~") or std.mem.startsWith(u8, raw, "~/")) blk: {
+        const home_dir = home orelse return null;
+        break :blk try std.fs.path.join(allocator, &.{ home_dir, raw[1..] });
+    } else if (std.mem.startsWith(u8, raw, "~"))
+        return null
+    else if (std.fs.path.isAbsolute(raw))
+        try allocator.dupe(u8, raw)
+    else
+        try std.fs.path.join(allocator, &.{ base_dir, raw });
+    defer allocator.free(joined);
+
+    const normalized = try normalize(allocator, joined);
+    if (std.mem.eql(u8, normalized, raw)) {
+        allocator.free(normalized);
+        return null;
+    }
+    return normalized;
+}
+
+/// Drops `.` segments and repeated slashes and folds `..` into the segment
+/// before it. This is purely textual, so symlinks are not consulted; a
+/// relative path keeps the `..` segments it cannot fold.
+pub fn normalize(allocator: std.mem.Allocator, path: []const u8) ![]u8 {
+    const absolute = std.fs.path.isAbsolute(path);
+    var segments = std.array_list.Managed([]const u8).init(allocator);
+    defer segments.deinit();
+
+    var it = std.mem.tokenizeScalar(u8, path, '/');
+    while (it.next()) |segment| {
+        if (std.mem.eql(u8, segment, ".")) continue;
+        if (std.mem.eql(u8, segment, "..")) {
+            if (segments.items.len > 0 and !std.mem.eql(u8, segments.getLast(), "..")) {
+                _ = segments.pop();
+                continue;
+            }
+            if (absolute) continue;
+        }
+        try segments.append(segment);
+    }
+
+    var out = std.array_list.Managed(u8).init(allocator);
+    errdefer out.deinit();
+    if (absolute) try out.append('/');
+    for (segments.items, 0..) |segment, index| {
+        if (index > 0) try out.append('/');
+        try out.appendSlice(segment);
+    }
+    if (out.items.len == 0) try out.append('.');
+    return out.toOwnedSlice();
+}
+
+test "process paths resolve against config dir and home" {
+    const relative = (try resolve(std.testing.allocator, "services/api", "/work/project", "/home/dev")).?;
+    defer std.testing.allocator.free(relative);
+    try std.testing.expectEqualStrings("/work/project/services/api", relative);
+
+    const home_relative = (try resolve(std.testing.allocator, "~/src", "/work/project", "/home/dev")).?;
+    defer std.testing.allocator.free(home_relative);
+    try std.testing.expectEqualStrings("/home/dev/src", home_relative);
+
+    const home = (try resolve(std.testing.allocator, "~", "/work/project", "/home/dev")).?;
+    defer std.testing.allocator.free(home);
+    try std.testing.expectEqualStrings("/home/dev", home);
+
+    try std.testing.expectEqual(@as(?[]const u8, null), try resolve(std.testing.allocator, "/srv/app", "/work/project", "/home/dev"));
+    try std.testing.expectEqual(@as(?[]const u8, null), try resolve(std.testing.allocator, "~/src", "/work/project", null));
+}
+
+test "resolved paths are normalized and other users' homes are kept" {
+    const dotted = (try resolve(std.testing.allocator, "./logs/../services//api", "/work/project", "/home/dev")).?;
+    defer std.testing.allocator.free(dotted);
+    try std.testing.expectEqualStrings("/work/project/services/api", dotted);
+
+    const sibling = (try resolve(std.testing.allocator, "../shared.yaml", "/work/project", null)).?;
+    defer std.testing.allocator.free(sibling);
+    try std.testing.expectEqualStrings("/work/shared.yaml", sibling);
+
+    const absolute = (try resolve(std.testing.allocator, "/srv/./app/", "/work/project", "/home/dev")).?;
+    defer std.testing.allocator.free(absolute);
+    try std.testing.expectEqualStrings("/srv/app", absolute);
+
+    try std.testing.expectEqual(@as(?[]const u8, null), try resolve(std.testing.allocator, "~dev/src", "/work/project", "/home/dev"));
+}
+
+test "normalize keeps leading parent segments of relative paths" {
+    const up = try normalize(std.testing.allocator, "./../a/./b/../c");
+    defer std.testing.allocator.free(up);
+    try std.testing.expectEqualStrings("../a/c", up);
+
+    const root = try normalize(std.testing.allocator, "/../..");
+    defer std.testing.allocator.free(root);
+    try std.testing.expectEqualStrings("/", root);
+
+    const here = try normalize(std.testing.allocator, "a/..");
+    defer std.testing.allocator.free(here);
+    try std.testing.expectEqualStrings(".", here);
+}
@@ -0,0 +1,229 @@
+//! YAML serialization of a resolved Project Config for `--print-config`.
+//! Every field is written, including defaults, so the output shows exactly what proctmux computed and loads back to the same config.
+
+const std = @import("std");
+const schema = @import("schema.zig");
+
+const Out = std.array_list.Managed(u8);
+
+/// Formats `cfg` as YAML in the order of the starter template. `--set` values
+/// are folded into each process `env`, since they are part of what runs.
+pub fn format(allocator: std.mem.Allocator, cfg: *const schema.Config) ![]u8 {
+    var out = Out.init(allocator);
+    errdefer out.deinit();
+
+    try writeInt(&out, 0, "version", cfg.version);
+    try writeSection(&out, "keybinding", cfg.keybinding);
+    try writeSection(&out, "layout", cfg.layout);
+    try writeSection(&out, "style", cfg.style);
+    try writeSection(&out, "general", cfg.general);
+    try writeList(&out, 0, "shell_cmd", cfg.shell_cmd);
+    try writeMap(&out, 0, "environment", cfg.environment, null);
+    try writeString(&out, 0, "log_file", cfg.log_file);
+    try writeString(&out, 0, "stdout_debug_log_file", cfg.stdout_debug_log_file);
+    try writeString(&out, 0, "control_socket", cfg.control_socket);
+    try writeString(&out, 0, "http_status_addr", cfg.http_status_addr);
+    try writeString(&out, 0, "clipboard_command", cfg.clipboard_command);
+    try writeString(&out, 0, "state_file", cfg.state_file);
+    try writeString(&out, 0, "pause", cfg.pause);
+    try writeString(&out, 0, "setup", cfg.setup);
+    try writeString(&out, 0, "teardown", cfg.teardown);
+    try writeInt(&out, 0, "start_delay_ms", cfg.start_delay_ms);
+    try writeBool(&out, 0, "confirm_quit_when_running", cfg.confirm_quit_when_running);
+    try writeBool(&out, 0, "mouse", cfg.mouse);
+    try writeBool(&out, 0, "remain_on_exit", cfg.remain_on_exit);
+    try writeString(&out, 0, "start_when_running", @tagName(cfg.start_when_running));
+    try writeInt(&out, 0, "ipc_timeout_ms", cfg.ipc_timeout_ms);
+    try writeInt(&out, 0, "refresh_interval_ms", cfg.refresh_interval_ms);
+
+    if (cfg.procs.count() == 0) {
+        try out.appendSlice("procs: {}\n");
+    } else {
+        try out.appendSlice("procs:\n");
+        var it = cfg.procs.iterator();
+        while (it.next()) |entry| try writeProcess(&out, entry.key_ptr.*, entry.value_ptr);
+    }
+    return out.toOwnedSlice();
+}
+
+fn writeProcess(out: *Out, label: []const u8, proc: *const schema.ProcessConfig) !void {
+    try writeKey(out, 2, label);
+    try out.append('\n');
+    try writeString(out, 4, "shell", proc.shell);
+    try writeList(out, 4, "cmd", proc.cmd);
+    try writeList(out, 4, "args", proc.args);
+    try writeString(out, 4, "pause", proc.pause);
+    try writeString(out, 4, "cwd", proc.cwd);
+    try writeMap(out, 4, "env", proc.env, proc.set_env);
+    if (proc.stop_sequence.len > 0) {
+        try writeString(out, 4, "stop", proc.stop_sequence);
+    } else {
+        try writeInt(out, 4, "stop", proc.stop);
+    }
+    try writeInt(out, 4, "stop_timeout_ms", proc.stop_timeout_ms);
+    try writeInt(out, 4, "max_runtime_secs", proc.max_runtime_secs);
+    try writeBool(out, 4, "autostart", proc.autostart);
+    if (proc.order) |position| try writeInt(out, 4, "order", position);
+    try writeInt(out, 4, "start_delay_ms", proc.start_delay_ms);
+    try writeBool(out, 4, "autofocus", proc.autofocus);
+    try writeString(out, 4, "description", proc.description);
+    try writeString(out, 4, "docs", proc.docs);
+    try writeString(out, 4, "hotkey", proc.hotkey);
+    try writeString(out, 4, "color", proc.color);
+    try writeList(out, 4, "meta_tags", proc.meta_tags);
+    try writeList(out, 4, "categories", proc.categories);
+    try writeList(out, 4, "depends_on", proc.depends_on);
+    try writeList(out, 4, "shell_cmd", proc.shell_cmd);
+    try writeList(out, 4, "add_path", proc.add_path);
+    try writeInt(out, 4, "terminal_rows", proc.terminal_rows);
+    try writeInt(out, 4, "terminal_cols", proc.terminal_cols);
+    try writeList(out, 4, "on_kill", proc.on_kill);
+    try writeString(out, 4, "on_start", proc.on_start);
+    try writeString(out, 4, "on_stop", proc.on_stop);
+    try writeString(out, 4, "on_success", proc.on_success);
+    try writeString(out, 4, "on_failure", proc.on_failure);
+    try writeString(out, 4, "log_file", proc.log_file);
+    try writeBool(out, 4, "log_append", proc.log_append);
+    try writeBool(out, 4, "reuse_pane", proc.reuse_pane);
+    try out.appendSlice("    ready_when:\n");
+    try writeString(out, 6, "command", proc.ready_when.command);
+    try writeString(out, 6, "output", proc.ready_when.output);
+    try writeInt(out, 6, "timeout_ms", proc.ready_when.timeout_ms);
+    try writeInt(out, 6, "interval_ms", proc.ready_when.interval_ms);
+    try writeString(out, 4, "restart", switch (proc.restart) {
+        .never => "never",
+        .always => "always",
+        .on_failure => "on-failure",
+    });
+    try writeInt(out, 4, "restart_max_retries", proc.restart_max_retries);
+    try writeInt(out, 4, "restart_backoff_ms", proc.restart_backoff_ms);
+    try writeBool(out, 4, "task", proc.task);
+    try writeBool(out, 4, "keep_on_failure", proc.keep_on_failure);
+    if (proc.remain_on_exit) |remain| try writeBool(out, 4, "remain_on_exit", remain);
+}
+
+/// Writes a nested section whose fields are all strings, ints, bools, or
+/// string lists, as the keybinding, layout, style, and general blocks are.
+fn writeSection(out: *Out, name: []const u8, section: anytype) !void {
+    try out.appendSlice(name);
+    try out.appendSlice(":\n");
+    inline for (std.meta.fields(@TypeOf(section))) |field| {
+        const value = @field(section, field.name);
+        switch (field.type) {
+            []const u8 => try writeString(out, 2, field.name, value),
+            i32 => try writeInt(out, 2, field.name, value),
+            bool => try writeBool(out, 2, field.name, value),
+            schema.StringList => try writeList(out, 2, field.name, value),
+            else => @compileError("unsupported config field type for " ++ field.name),
+        }
+    }
+}
+
+fn writeString(out: *Out, indent: usize, key: []const u8, value: []const u8) !void {
+    try writeKey(out, indent, key);
+    try out.append(' ');
+    try appendQuoted(out, value);
+    try out.append('\n');
+}
+
+fn writeInt(out: *Out, indent: usize, key: []const u8, value: i32) !void {
+    try writeKey(out, indent, key);
+    try out.writer().print(" {d}\n", .{value});
+}
+
+fn writeBool(out: *Out, indent: usize, key: []const u8, value: bool) !void {
+    try writeKey(out, indent, key);
+    try out.appendSlice(if (value) " true\n" else " false\n");
+}
+
+fn writeList(out: *Out, indent: usize, key: []const u8, list: schema.StringList) !void {
+    try writeKey(out, indent, key);
+    try out.appendSlice(" [");
+    for (list.items, 0..) |item, index| {
+        if (index > 0) try out.appendSlice(", ");
+        try appendQuoted(out, item);
+    }
+    try out.appendSlice("]\n");
+}
+
+/// Writes `map` as a block mapping; entries in `overrides` replace or extend
+/// it, so one resolved map is shown.
+fn writeMap(out: *Out, indent: usize, key: []const u8, map: schema.StringMap, overrides: ?schema.StringMap) !void {
+    const extra = if (overrides) |o| o.count() else 0;
+    try writeKey(out, indent, key);
+    if (map.count() + extra == 0) {
+        try out.appendSlice(" {}\n");
+        return;
+    }
+    try out.append('\n');
+
+    var it = map.iterator();
+    while (it.next()) |entry| {
+        const value = if (overrides) |o| o.get(entry.key_ptr.*) orelse entry.value_ptr.* else entry.value_ptr.*;
+        try writeString(out, indent + 2, entry.key_ptr.*, value);
+    }
+    if (overrides) |o| {
+        var override_it = o.iterator();
+        while (override_it.next()) |entry| {
+            if (map.contains(entry.key_ptr.*)) continue;
+            try writeString(out, indent + 2, entry.key_ptr.*, entry.value_ptr.*);
+        }
+    }
+}
+
+fn writeKey(out: *Out, indent: usize, key: []const u8) !void {
+    try out.appendNTimes(' ', indent);
+    if (isPlainKey(key)) {
+        try out.appendSlice(key);
+    } else {
+        try appendQuoted(out, key);
+    }
+    try out.append(':');
+}
+
+fn isPlainKey(key: []const u8) bool {
+    if (key.len == 0 or key[0] == '-') return false;
+    for (key) |c| {
+        if (!std.ascii.isAlphanumeric(c) and c != '_' and c != '-' and c != '.' and c != '/') return false;
+    }
+    return true;
+}
+
+/// Double-quotes `value` using only the escapes the YAML loader decodes.
+fn appendQuoted(out: *Out, value: []const u8) !void {
+    try out.append('"');
+    for (value) |c| {
+        switch (c) {
+            '"' => try out.appendSlice("\\\""),
+            '\\' => try out.appendSlice("\\\\"),
+            '\n' => try out.appendSlice("\\n"),
+            '\t' => try out.appendSlice("\\t"),
+            else => try out.append(c),
+        }
+    }
+    try out.append('"');
+}
+
+test "printed config quotes strings and keys that need it" {
+    var cfg = schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+
+    var build = schema.ProcessConfig.empty(std.testing.allocator);
+    build.shell = try std.testing.allocator.dupe(u8, "make \"all\"\tnow");
+    build.stop_sequence = try std.testing.allocator.dupe(u8, "q");
+    build.owns_scalar_strings = true;
+    build.restart = .on_failure;
+    try schema.putOwnedString(std.testing.allocator, &build.env, "MODE", "dev");
+    try schema.putOwnedString(std.testing.allocator, &build.set_env, "MODE", "ci");
+    try schema.putOwnedString(std.testing.allocator, &build.set_env, "JOBS", "4");
+    try schema.appendOwned(std.testing.allocator, &build.depends_on, "db");
+    try cfg.procs.put(try std.testing.allocator.dupe(u8, "build:prod"), build);
+
+    const text = try format(std.testing.allocator, &cfg);
+    defer std.testing.allocator.free(text);
+    try std.testing.expect(std.mem.indexOf(u8, text, "  \"build:prod\":\n    shell: \"make \\\"all\\\"\\tnow\"\n") != null);
+    try std.testing.expect(std.mem.indexOf(u8, text, "    env:\n      MODE: \"ci\"\n      JOBS: \"4\"\n    stop: \"q\"\n") != null);
+    try std.testing.expect(std.mem.indexOf(u8, text, "    depends_on: [\"db\"]\n") != null);
+    try std.testing.expect(std.mem.indexOf(u8, text, "    restart: \"on-failure\"\n") != null);
+    try std.testing.expect(std.mem.indexOf(u8, text, "environment: {}\n") != null);
+}
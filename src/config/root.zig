@@ -9,6 +9,13 @@ pub const load = @import("load.zig");
 pub const hash = @import("hash.zig");
 pub const template = @import("template.zig");
 pub const runtime = @import("runtime.zig");
+pub const depends = @import("depends.zig");
+pub const expand = @import("expand.zig");
+pub const color = @import("color.zig");
+pub const overrides = @import("overrides.zig");
+pub const paths = @import("paths.zig");
+pub const print = @import("print.zig");
+pub const debug_pane = @import("debug_pane.zig");
 
 test {
     _ = schema;
@@ -17,6 +24,13 @@ test {
     _ = hash;
     _ = template;
     _ = runtime;
+    _ = depends;
+    _ = expand;
+    _ = color;
+    _ = overrides;
+    _ = paths;
+    _ = debug_pane;
+    _ = print;
 }
 
 test "defaults match current defaults" {
@@ -218,6 +232,243 @@ test "load default in dir follows proctmux search order" {
     try std.testing.expectEqualStrings("cat:", loaded.config.layout.category_search_prefix);
 }
 
+test "load files in dir merges later files over earlier ones by key" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+
+    try tmp.dir.writeFile(.{ .sub_path = "base.yaml", .data =
+        \\layout:
+        \\  processes_list_width: 40
+        \\  sort_process_list_alpha: true
+        \\procs:
+        \\  api:
+        \\    shell: "npm start"
+        \\    autostart: true
+        \\    env:
+        \\      PORT: "3000"
+        \\  db:
+        \\    shell: "postgres"
+        \\
+    });
+    try tmp.dir.writeFile(.{ .sub_path = "local.yaml", .data =
+        \\layout:
+        \\  processes_list_width: 25
+        \\procs:
+        \\  api:
+        \\    shell: "npm run dev"
+        \\  worker:
+        \\    shell: "npm run worker"
+        \\
+    });
+
+    var loaded = try load.loadFilesInDir(std.testing.allocator, tmp.dir, &.{ "base.yaml", "local.yaml" });
+    defer loaded.deinit();
+    const cfg = &loaded.config;
+
+    try std.testing.expect(std.mem.endsWith(u8, cfg.file_path, "base.yaml"));
+    try std.testing.expectEqual(@as(i32, 25), cfg.layout.processes_list_width);
+    try std.testing.expect(cfg.layout.sort_process_list_alpha);
+
+    const api = cfg.procs.getPtr("api").?;
+    try std.testing.expectEqualStrings("npm run dev", api.shell);
+    try std.testing.expect(api.autostart);
+    try std.testing.expectEqualStrings("3000", api.env.get("PORT").?);
+
+    try std.testing.expectEqual(@as(usize, 3), cfg.procs.count());
+    try std.testing.expectEqualStrings("postgres", cfg.procs.getPtr("db").?.shell);
+    try std.testing.expectEqualStrings("worker", cfg.procs.keys()[2]);
+}
+
+test "runtime set overrides feed process env and command expansion" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    try tmp.dir.writeFile(.{ .sub_path = "proctmux.yaml", .data =
+        \\procs:
+        \\  worker:
+        \\    shell: "run-worker --concurrency $CONCURRENCY"
+        \\    env:
+        \\      CONCURRENCY: "1"
+        \\
+    });
+
+    var loaded = try runtime.loadFilesWithOverridesInDir(std.testing.allocator, tmp.dir, &.{"proctmux.yaml"}, &.{
+        "worker.CONCURRENCY=2",
+        "worker.CONCURRENCY=4",
+    });
+    defer loaded.deinit();
+    const worker = loaded.config.procs.getPtr("worker").?;
+    try std.testing.expectEqualStrings("4", worker.set_env.get("CONCURRENCY").?);
+    try std.testing.expectEqualStrings("1", worker.env.get("CONCURRENCY").?);
+    try std.testing.expectEqualStrings("run-worker --concurrency 4", worker.shell);
+
+    try std.testing.expectError(
+        error.UnknownSetProcess,
+        runtime.loadFilesWithOverridesInDir(std.testing.allocator, tmp.dir, &.{"proctmux.yaml"}, &.{"api.PORT=1"}),
+    );
+}
+
+test "printed config loads back to the same resolved config" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    try tmp.dir.writeFile(.{ .sub_path = "shared.yaml", .data =
+        \\procs:
+        \\  db:
+        \\    shell: "postgres -D ./data"
+        \\    stop: "q"
+        \\
+    });
+    try tmp.dir.writeFile(.{ .sub_path = "proctmux.yaml", .data =
+        \\include: [shared.yaml]
+        \\environment:
+        \\  REGION: "eu"
+        \\procs:
+        \\  "api server":
+        \\    shell: "echo \\"$REGION\\""
+        \\    cwd: "api"
+        \\    depends_on: [db]
+        \\    restart: on-failure
+        \\    ready_when:
+        \\      output: "listening"
+        \\
+    });
+
+    var first = try runtime.loadFilesInDir(std.testing.allocator, tmp.dir, &.{"proctmux.yaml"});
+    defer first.deinit();
+    const printed = try print.format(std.testing.allocator, &first.config);
+    defer std.testing.allocator.free(printed);
+    try tmp.dir.writeFile(.{ .sub_path = "printed.yaml", .data = printed });
+
+    var second = try runtime.loadFilesInDir(std.testing.allocator, tmp.dir, &.{"printed.yaml"});
+    defer second.deinit();
+    const reprinted = try print.format(std.testing.allocator, &second.config);
+    defer std.testing.allocator.free(reprinted);
+    try std.testing.expectEqualStrings(printed, reprinted);
+    try std.testing.expectEqual(@as(usize, 0), second.warnings.items.len);
+    try std.testing.expectEqualStrings("q", second.config.procs.get("db").?.stop_sequence);
+}
+
+test "include merges shared files under the including file and rejects cycles" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+
+    try tmp.dir.makePath("shared");
+    try tmp.dir.writeFile(.{ .sub_path = "shared/infra.yaml", .data =
+        \\include: [ports.yaml]
+        \\procs:
+        \\  db:
+        \\    shell: "postgres"
+        \\  api:
+        \\    shell: "npm start"
+        \\    autostart: true
+        \\
+    });
+    try tmp.dir.writeFile(.{ .sub_path = "shared/ports.yaml", .data =
+        \\environment:
+        \\  DB_PORT: "5432"
+        \\
+    });
+    try tmp.dir.writeFile(.{ .sub_path = "proctmux.yaml", .data =
+        \\include:
+        \\  - shared/infra.yaml
+        \\procs:
+        \\  api:
+        \\    shell: "npm run dev"
+        \\
+    });
+
+    var loaded = try load.loadFileInDir(std.testing.allocator, tmp.dir, "proctmux.yaml");
+    defer loaded.deinit();
+    const cfg = &loaded.config;
+
+    try std.testing.expect(std.mem.endsWith(u8, cfg.file_path, "proctmux.yaml"));
+    try std.testing.expect(!loaded.hasWarning("include"));
+    try std.testing.expectEqualStrings("5432", cfg.environment.get("DB_PORT").?);
+    try std.testing.expectEqualStrings("postgres", cfg.procs.getPtr("db").?.shell);
+    const api = cfg.procs.getPtr("api").?;
+    try std.testing.expectEqualStrings("npm run dev", api.shell);
+    try std.testing.expect(api.autostart);
+
+    try tmp.dir.writeFile(.{ .sub_path = "shared/ports.yaml", .data =
+        \\include: infra.yaml
+        \\
+    });
+    try std.testing.expectError(error.IncludeCycle, load.loadFileInDir(std.testing.allocator, tmp.dir, "proctmux.yaml"));
+
+    try tmp.dir.writeFile(.{ .sub_path = "shared/ports.yaml", .data =
+        \\include: missing.yaml
+        \\
+    });
+    try std.testing.expectError(error.IncludeNotFound, load.loadFileInDir(std.testing.allocator, tmp.dir, "proctmux.yaml"));
+}
+
+test "duplicate process names within one file fail to load" {
+    try std.testing.expectError(error.DuplicateMapKey, load.loadFromSlice(std.testing.allocator,
+        \\procs:
+        \\  api:
+        \\    shell: "one"
+        \\  api:
+        \\    shell: "two"
+        \\
+    , "dupes.yaml"));
+}
+
+test "duplicate key diagnostic names the repeated key and its line" {
+    const yaml_mod = @import("yaml");
+    var yml: yaml_mod.Yaml = .{ .source =
+        \\procs:
+        \\  api:
+        \\    shell: "one"
+        \\  api:
+        \\    shell: "two"
+        \\
+    };
+    defer yml.deinit(std.testing.allocator);
+
+    try std.testing.expectError(error.DuplicateMapKey, yml.load(std.testing.allocator));
+    const duplicate = load.duplicateKey(yml) orelse return error.TestExpectedDuplicateKey;
+    try std.testing.expectEqualStrings("api", duplicate.key);
+    try std.testing.expectEqual(@as(u32, 4), duplicate.line);
+    try std.testing.expectEqual(@as(u32, 3), duplicate.column);
+}
+
+test "processes without a command fail to load" {
+    try std.testing.expectError(error.MissingProcessCommand, load.loadFromSlice(std.testing.allocator,
+        \\procs:
+        \\  api:
+        \\    shell: "npm start"
+        \\  typo:
+        \\    shel: "npm run worker"
+        \\
+    , "missing.yaml"));
+    try std.testing.expectError(error.MissingProcessCommand, load.loadFromSlice(std.testing.allocator,
+        \\procs:
+        \\  blank:
+        \\    shell: "  "
+        \\    cmd: []
+        \\
+    , "blank.yaml"));
+}
+
+test "keybinding overrides merge with defaults and reject unknown actions" {
+    var loaded = try load.loadFromSlice(std.testing.allocator,
+        \\keybinding:
+        \\  up: ["ctrl+p"]
+        \\  down: ["ctrl+n"]
+        \\
+    , "keys.yaml");
+    defer loaded.deinit();
+    try std.testing.expectEqualStrings("ctrl+p", loaded.config.keybinding.up.items[0]);
+    try std.testing.expectEqual(@as(usize, 1), loaded.config.keybinding.up.items.len);
+    try std.testing.expectEqualStrings("ctrl+n", loaded.config.keybinding.down.items[0]);
+    try std.testing.expectEqualStrings("q", loaded.config.keybinding.quit.items[0]);
+
+    try std.testing.expectError(error.UnknownKeybindingAction, load.loadFromSlice(std.testing.allocator,
+        \\keybinding:
+        \\  zoom: ["z"]
+        \\
+    , "typo.yaml"));
+}
+
 test "runtime config loads explicit file and applies Makefile discovery" {
     var tmp = std.testing.tmpDir(.{});
     defer tmp.cleanup();
@@ -262,6 +513,34 @@ test "runtime config loads default file and applies Makefile discovery" {
     try std.testing.expect(loaded.config.procs.contains("make:test"));
 }
 
+test "runtime config merges global environment under process env" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+
+    try tmp.dir.writeFile(.{ .sub_path = "proctmux.yaml", .data = 
+        \\environment:
+        \\  RAILS_ENV: "development"
+        \\  SHARED: "global"
+        \\procs:
+        \\  web:
+        \\    shell: "echo web"
+        \\    env:
+        \\      SHARED: "local"
+        \\  worker:
+        \\    shell: "echo worker"
+        \\
+    });
+
+    var loaded = try runtime.loadInDir(std.testing.allocator, tmp.dir, "");
+    defer loaded.deinit();
+
+    const web = loaded.config.procs.get("web").?;
+    try std.testing.expectEqualStrings("development", web.env.get("RAILS_ENV").?);
+    try std.testing.expectEqualStrings("local", web.env.get("SHARED").?);
+    const worker = loaded.config.procs.get("worker").?;
+    try std.testing.expectEqualStrings("global", worker.env.get("SHARED").?);
+}
+
 test "dead and unknown fields warn and do not populate active config" {
     var loaded = try load.loadFile(std.testing.allocator, "testdata/phase2/config/dead-fields.yaml");
     defer loaded.deinit();
@@ -289,6 +568,258 @@ test "malformed yaml returns parse error" {
     try std.testing.expectError(error.ParseFailure, load.loadFile(std.testing.allocator, "testdata/phase2/config/malformed.yaml"));
 }
 
+test "malformed yaml parse diagnostic points at a 1-based source line" {
+    const yaml_mod = @import("yaml");
+    const source = try std.fs.cwd().readFileAlloc(std.testing.allocator, "testdata/phase2/config/malformed.yaml", 1024 * 1024);
+    defer std.testing.allocator.free(source);
+
+    var yml: yaml_mod.Yaml = .{ .source = source };
+    defer yml.deinit(std.testing.allocator);
+
+    try std.testing.expectError(error.ParseFailure, yml.load(std.testing.allocator));
+    const diagnostic = load.parseDiagnostic(yml) orelse return error.TestExpectedParseDiagnostic;
+    try std.testing.expect(diagnostic.line >= 1);
+    try std.testing.expect(diagnostic.column >= 1);
+    try std.testing.expect(diagnostic.message.len > 0);
+}
+
+test "depends_on orders dependencies before dependents" {
+    var loaded = try load.loadFromSlice(
+        std.testing.allocator,
+        \\procs:
+        \\  app:
+        \\    shell: "sleep 1"
+        \\    depends_on: ["migrate", "cache"]
+        \\  migrate:
+        \\    shell: "sleep 1"
+        \\    depends_on: ["db"]
+        \\  db:
+        \\    shell: "sleep 1"
+        \\  cache:
+        \\    shell: "sleep 1"
+        \\    depends_on: ["db"]
+        \\
+    ,
+        "inline-depends.yaml",
+    );
+    defer loaded.deinit();
+
+    const order = try depends.startOrder(std.testing.allocator, &loaded.config, "app");
+    defer std.testing.allocator.free(order);
+
+    try std.testing.expectEqual(@as(usize, 4), order.len);
+    try std.testing.expectEqualStrings("db", order[0]);
+    try std.testing.expectEqualStrings("migrate", order[1]);
+    try std.testing.expectEqualStrings("cache", order[2]);
+    try std.testing.expectEqualStrings("app", order[3]);
+}
+
+test "depends_on full start order lists every process after its dependencies" {
+    var loaded = try load.loadFromSlice(
+        std.testing.allocator,
+        \\procs:
+        \\  web:
+        \\    shell: "sleep 1"
+        \\    depends_on: ["api", "missing"]
+        \\  api:
+        \\    shell: "sleep 1"
+        \\    depends_on: ["db"]
+        \\  worker:
+        \\    shell: "sleep 1"
+        \\  db:
+        \\    shell: "sleep 1"
+        \\
+    ,
+        "inline-all-depends.yaml",
+    );
+    defer loaded.deinit();
+
+    const order = try depends.allStartOrder(std.testing.allocator, &loaded.config);
+    defer std.testing.allocator.free(order);
+
+    try std.testing.expectEqual(@as(usize, 4), order.len);
+    try std.testing.expectEqualStrings("db", order[0]);
+    try std.testing.expectEqualStrings("api", order[1]);
+    try std.testing.expectEqualStrings("web", order[2]);
+    try std.testing.expectEqualStrings("worker", order[3]);
+}
+
+test "order sorts launches and depends_on still starts dependencies first" {
+    var loaded = try load.loadFromSlice(
+        std.testing.allocator,
+        \\procs:
+        \\  docs:
+        \\    shell: "sleep 1"
+        \\  web:
+        \\    shell: "sleep 1"
+        \\    order: 1
+        \\    depends_on: ["api"]
+        \\  worker:
+        \\    shell: "sleep 1"
+        \\    order: 2
+        \\  api:
+        \\    shell: "sleep 1"
+        \\    order: 3
+        \\  cache:
+        \\    shell: "sleep 1"
+        \\    order: 2
+        \\  lint:
+        \\    shell: "sleep 1"
+        \\
+    ,
+        "inline-order.yaml",
+    );
+    defer loaded.deinit();
+
+    const launches = try depends.launchOrder(std.testing.allocator, &loaded.config);
+    defer std.testing.allocator.free(launches);
+    const expected_launches = [_][]const u8{ "web", "worker", "cache", "api", "docs", "lint" };
+    try std.testing.expectEqual(expected_launches.len, launches.len);
+    for (expected_launches, launches) |want, got| try std.testing.expectEqualStrings(want, got);
+
+    const starts = try depends.allStartOrder(std.testing.allocator, &loaded.config);
+    defer std.testing.allocator.free(starts);
+    const expected_starts = [_][]const u8{ "api", "web", "worker", "cache", "docs", "lint" };
+    try std.testing.expectEqual(expected_starts.len, starts.len);
+    for (expected_starts, starts) |want, got| try std.testing.expectEqualStrings(want, got);
+}
+
+test "depends_on cycles fail config load" {
+    try std.testing.expectError(error.DependencyCycle, load.loadFromSlice(
+        std.testing.allocator,
+        \\procs:
+        \\  api:
+        \\    shell: "sleep 1"
+        \\    depends_on: ["worker"]
+        \\  worker:
+        \\    shell: "sleep 1"
+        \\    depends_on: ["api"]
+        \\
+    ,
+        "inline-cycle.yaml",
+    ));
+}
+
+test "process colors accept names and hex and reject unknown names" {
+    var loaded = try load.loadFromSlice(
+        std.testing.allocator,
+        \\procs:
+        \\  api:
+        \\    shell: "sleep 1"
+        \\    color: cyan
+        \\  web:
+        \\    shell: "sleep 1"
+        \\    color: "#1e90ff"
+        \\  worker:
+        \\    shell: "sleep 1"
+        \\
+    ,
+        "inline-color.yaml",
+    );
+    defer loaded.deinit();
+    try std.testing.expectEqualStrings("cyan", loaded.config.procs.get("api").?.color);
+    try std.testing.expectEqualStrings("#1e90ff", loaded.config.procs.get("web").?.color);
+    try std.testing.expectEqualStrings("", loaded.config.procs.get("worker").?.color);
+
+    try std.testing.expectError(error.UnknownColor, load.loadFromSlice(
+        std.testing.allocator,
+        \\procs:
+        \\  api:
+        \\    shell: "sleep 1"
+        \\    color: purple
+        \\
+    ,
+        "inline-color-unknown.yaml",
+    ));
+}
+
+test "process hotkeys must be unique single keys" {
+    var loaded = try load.loadFromSlice(
+        std.testing.allocator,
+        \\procs:
+        \\  api:
+        \\    shell: "sleep 1"
+        \\    hotkey: "1"
+        \\
+    ,
+        "inline-hotkey.yaml",
+    );
+    defer loaded.deinit();
+    try std.testing.expectEqualStrings("1", loaded.config.procs.get("api").?.hotkey);
+
+    try std.testing.expectError(error.HotkeyConflict, load.loadFromSlice(
+        std.testing.allocator,
+        \\procs:
+        \\  api:
+        \\    shell: "sleep 1"
+        \\    hotkey: "1"
+        \\  web:
+        \\    shell: "sleep 1"
+        \\    hotkey: "1"
+        \\
+    ,
+        "inline-hotkey-duplicate.yaml",
+    ));
+    try std.testing.expectError(error.HotkeyConflict, load.loadFromSlice(
+        std.testing.allocator,
+        \\procs:
+        \\  api:
+        \\    shell: "sleep 1"
+        \\    hotkey: "q"
+        \\
+    ,
+        "inline-hotkey-binding.yaml",
+    ));
+    try std.testing.expectError(error.InvalidHotkey, load.loadFromSlice(
+        std.testing.allocator,
+        \\procs:
+        \\  api:
+        \\    shell: "sleep 1"
+        \\    hotkey: "ab"
+        \\
+    ,
+        "inline-hotkey-long.yaml",
+    ));
+}
+
+test "depends_on cycle path names every process in the loop" {
+    var cfg = schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+
+    for ([_][2][]const u8{ .{ "a", "b" }, .{ "b", "a" } }) |edge| {
+        var proc = schema.ProcessConfig.empty(std.testing.allocator);
+        errdefer proc.deinit(std.testing.allocator);
+        try schema.appendOwned(std.testing.allocator, &proc.depends_on, edge[1]);
+        const label = try std.testing.allocator.dupe(u8, edge[0]);
+        errdefer std.testing.allocator.free(label);
+        try cfg.procs.put(label, proc);
+    }
+
+    const cycle = (try depends.findCycle(std.testing.allocator, &cfg)).?;
+    defer std.testing.allocator.free(cycle);
+
+    try std.testing.expectEqual(@as(usize, 3), cycle.len);
+    try std.testing.expectEqualStrings("a", cycle[0]);
+    try std.testing.expectEqualStrings("b", cycle[1]);
+    try std.testing.expectEqualStrings("a", cycle[2]);
+}
+
+test "depends_on unknown process fails at start order time" {
+    var loaded = try load.loadFromSlice(
+        std.testing.allocator,
+        \\procs:
+        \\  api:
+        \\    shell: "sleep 1"
+        \\    depends_on: ["missing"]
+        \\
+    ,
+        "inline-unknown-dependency.yaml",
+    );
+    defer loaded.deinit();
+
+    try std.testing.expectError(error.UnknownDependency, depends.startOrder(std.testing.allocator, &loaded.config, "api"));
+}
+
 test "active config hash is stable hex" {
     var loaded = try load.loadFile(std.testing.allocator, "testdata/phase2/config/full-active.yaml");
     defer loaded.deinit();
@@ -341,6 +872,95 @@ test "dead fields do not affect active config hash" {
     try std.testing.expect(!dead.hasWarning("procs.docs-demo.meta_tags"));
 }
 
+test "unknown keys carry their line and other versions still load" {
+    const source =
+        \\version: 2
+        \\layout:
+        \\  sort_process_list_alpha: true
+        \\  hide_everything: true
+        \\procs:
+        \\  api:
+        \\    shell: "serve"
+        \\    comand: "serve --port 1"
+        \\
+    ;
+    var loaded = try load.loadFromSlice(std.testing.allocator, source, "proctmux.yaml");
+    defer loaded.deinit();
+
+    try std.testing.expectEqual(@as(i32, 2), loaded.config.version);
+    try std.testing.expectEqualStrings("serve", loaded.config.procs.get("api").?.shell);
+    try std.testing.expectEqual(@as(usize, 2), loaded.warnings.items.len);
+    for (loaded.warnings.items) |warning| {
+        try std.testing.expectEqual(schema.WarningKind.unknown_field, warning.kind);
+        if (std.mem.eql(u8, warning.path, "layout.hide_everything")) {
+            try std.testing.expectEqual(@as(u32, 4), warning.line);
+        } else {
+            try std.testing.expectEqualStrings("procs.api.comand", warning.path);
+            try std.testing.expectEqual(@as(u32, 8), warning.line);
+        }
+    }
+}
+
+test "unknown ready_when keys warn under their process" {
+    const source =
+        \\procs:
+        \\  api:
+        \\    shell: "serve"
+        \\    ready_when:
+        \\      output: "listening"
+        \\      timout_ms: 5000
+        \\
+    ;
+    var loaded = try load.loadFromSlice(std.testing.allocator, source, "proctmux.yaml");
+    defer loaded.deinit();
+
+    try std.testing.expectEqualStrings("listening", loaded.config.procs.get("api").?.ready_when.output);
+    try std.testing.expectEqual(@as(usize, 1), loaded.warnings.items.len);
+    const warning = loaded.warnings.items[0];
+    try std.testing.expectEqual(schema.WarningKind.unknown_field, warning.kind);
+    try std.testing.expectEqualStrings("procs.api.ready_when.timout_ms", warning.path);
+    try std.testing.expectEqual(@as(u32, 6), warning.line);
+}
+
+test "default_process fills fields a process leaves unset" {
+    const source =
+        \\default_process:
+        \\  cwd: "services"
+        \\  shell: "make run"
+        \\  restart: "on-failure"
+        \\  env:
+        \\    MODE: "dev"
+        \\    LOG: "info"
+        \\  colour: "red"
+        \\procs:
+        \\  api:
+        \\    env:
+        \\      LOG: "debug"
+        \\  worker:
+        \\    cmd: ["./worker"]
+        \\    cwd: "jobs"
+        \\
+    ;
+    var loaded = try load.loadFromSlice(std.testing.allocator, source, "proctmux.yaml");
+    defer loaded.deinit();
+
+    const api = loaded.config.procs.get("api").?;
+    try std.testing.expectEqualStrings("services", api.cwd);
+    try std.testing.expectEqualStrings("make run", api.shell);
+    try std.testing.expectEqual(schema.RestartPolicy.on_failure, api.restart);
+    try std.testing.expectEqualStrings("dev", api.env.get("MODE").?);
+    try std.testing.expectEqualStrings("debug", api.env.get("LOG").?);
+
+    const worker = loaded.config.procs.get("worker").?;
+    try std.testing.expectEqualStrings("jobs", worker.cwd);
+    try std.testing.expectEqualStrings("", worker.shell);
+    try std.testing.expectEqualStrings("./worker", worker.cmd.items[0]);
+
+    try std.testing.expectEqual(@as(usize, 1), loaded.warnings.items.len);
+    try std.testing.expectEqualStrings("default_process.colour", loaded.warnings.items[0].path);
+    try std.testing.expectEqual(@as(u32, 8), loaded.warnings.items[0].line);
+}
+
 test "starter template parses docs and meta tags fields" {
     const content = template.content();
     try std.testing.expect(std.mem.indexOf(u8, content, "procs:") != null);
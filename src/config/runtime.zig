@@ -3,24 +3,416 @@
 
 const std = @import("std");
 const discover = @import("../discover/root.zig");
+const debug_pane = @import("debug_pane.zig");
+const expand = @import("expand.zig");
+const hash = @import("hash.zig");
 const load = @import("load.zig");
+const overrides = @import("overrides.zig");
+const paths = @import("paths.zig");
+const schema = @import("schema.zig");
+
+const log = std.log.scoped(.config);
 
 pub const LoadedRuntimeConfig = load.LoadedConfig;
 
+/// Environment variable consulted when no `-f` path is given.
+pub const config_env_var = "PROCTMUX_CONFIG";
+
 /// Loads Project Config and applies Discovery before any Runtime Mode starts.
-/// This keeps primary/client/unified modes aligned on effective config.
+/// This keeps primary/client/unified modes aligned on effective config. An
+/// empty `config_file` searches `dir`; `PROCTMUX_CONFIG` is not consulted.
 pub fn loadInDir(
     allocator: std.mem.Allocator,
     dir: std.fs.Dir,
     config_file: []const u8,
 ) !LoadedRuntimeConfig {
-    var loaded = if (config_file.len > 0)
-        try load.loadFileInDir(allocator, dir, config_file)
+    const config_files = [_][]const u8{config_file};
+    return loadFilesWithEnvInDir(allocator, dir, if (config_file.len > 0) config_files[0..] else config_files[0..0], &.{}, null);
+}
+
+/// Multi-file variant of `loadInDir` for repeated `-f` flags; files merge in
+/// order with later ones winning. With no files, `PROCTMUX_CONFIG` applies.
+pub fn loadFilesInDir(
+    allocator: std.mem.Allocator,
+    dir: std.fs.Dir,
+    config_files: []const []const u8,
+) !LoadedRuntimeConfig {
+    return loadFilesWithOverridesInDir(allocator, dir, config_files, &.{});
+}
+
+/// `loadFilesInDir` plus `--set` overrides, applied before commands are
+/// expanded so `$NAME` in a command sees the overridden value.
+pub fn loadFilesWithOverridesInDir(
+    allocator: std.mem.Allocator,
+    dir: std.fs.Dir,
+    config_files: []const []const u8,
+    set_overrides: []const []const u8,
+) !LoadedRuntimeConfig {
+    if (config_files.len > 0) return loadSelectedInDir(allocator, dir, config_files, set_overrides);
+
+    const env_config_file = std.process.getEnvVarOwned(allocator, config_env_var) catch null;
+    defer if (env_config_file) |path| allocator.free(path);
+    return loadFilesWithEnvInDir(allocator, dir, config_files, set_overrides, env_config_file);
+}
+
+/// `loadFilesWithOverridesInDir` with the `PROCTMUX_CONFIG` value passed in
+/// rather than read from the process environment.
+pub fn loadFilesWithEnvInDir(
+    allocator: std.mem.Allocator,
+    dir: std.fs.Dir,
+    config_files: []const []const u8,
+    set_overrides: []const []const u8,
+    env_config_file: ?[]const u8,
+) !LoadedRuntimeConfig {
+    if (config_files.len > 0) return loadSelectedInDir(allocator, dir, config_files, set_overrides);
+
+    const selected = [_][]const u8{selectConfigFile("", env_config_file)};
+    return loadSelectedInDir(allocator, dir, if (selected[0].len > 0) selected[0..] else selected[0..0], set_overrides);
+}
+
+/// Picks the explicit `-f` path first, then `PROCTMUX_CONFIG`. An empty result
+/// means the default file search in the working directory.
+pub fn selectConfigFile(config_file: []const u8, env_config_file: ?[]const u8) []const u8 {
+    if (config_file.len > 0) return config_file;
+    if (env_config_file) |path| return std.mem.trim(u8, path, " \t\r\n");
+    return "";
+}
+
+fn loadSelectedInDir(
+    allocator: std.mem.Allocator,
+    dir: std.fs.Dir,
+    config_files: []const []const u8,
+    set_overrides: []const []const u8,
+) !LoadedRuntimeConfig {
+    var loaded = if (config_files.len > 0)
+        load.loadFilesInDir(allocator, dir, config_files) catch |err| switch (err) {
+            error.FileNotFound => {
+                if (config_files.len == 1) logMissingPath(allocator, dir, config_files[0]);
+                return error.ConfigPathNotFound;
+            },
+            else => return err,
+        }
     else
-        try load.loadDefaultInDir(allocator, dir);
+        load.loadDefaultInDir(allocator, dir) catch |err| switch (err) {
+            error.ConfigFileNotFound => {
+                logMissingDefault(allocator, dir);
+                return err;
+            },
+            else => return err,
+        };
     errdefer loaded.deinit();
 
+    log.debug("loaded config from {s}", .{loaded.config.file_path});
+    logUnknownFields(&loaded);
+    loaded.config.socket_key = try socketKey(allocator, dir, &loaded.config, config_files);
+
     const discovery_cwd = std.fs.path.dirname(loaded.config.file_path) orelse ".";
     try discover.apply_mod.apply(loaded.config.allocator, &loaded.config, discovery_cwd);
+
+    try applyGlobalEnvironment(loaded.config.allocator, &loaded.config);
+    try overrides.apply(loaded.config.allocator, &loaded.config, set_overrides);
+
+    var base_env = try std.process.getEnvMap(allocator);
+    defer base_env.deinit();
+    try expandProcessCommands(allocator, &loaded.config, &base_env);
+
+    const home = std.process.getEnvVarOwned(allocator, "HOME") catch null;
+    defer if (home) |path| allocator.free(path);
+    try resolveProcessPaths(loaded.config.allocator, &loaded.config, discovery_cwd, home);
+    if (loaded.config.control_socket.len > 0) {
+        if (try paths.resolve(loaded.config.allocator, loaded.config.control_socket, discovery_cwd, home)) |path| {
+            loaded.config.control_socket = path;
+        }
+    }
+    if (loaded.config.state_file.len > 0) {
+        if (try paths.resolve(loaded.config.allocator, loaded.config.state_file, discovery_cwd, home)) |path| {
+            loaded.config.state_file = path;
+        }
+    }
+    try debug_pane.apply(loaded.config.allocator, &loaded.config);
     return loaded;
 }
+
+/// The first file is already resolved as `file_path`; later `-f` files are
+/// resolved against `dir`, where loading found them.
+fn socketKey(
+    allocator: std.mem.Allocator,
+    dir: std.fs.Dir,
+    cfg: *const schema.Config,
+    config_files: []const []const u8,
+) ![]const u8 {
+    var file_paths = std.array_list.Managed([]const u8).init(allocator);
+    defer file_paths.deinit();
+    try file_paths.append(cfg.file_path);
+    if (config_files.len > 1) {
+        for (config_files[1..]) |path| try file_paths.append(try dir.realpathAlloc(cfg.allocator, path));
+    }
+    return hash.pathsHash(cfg.allocator, file_paths.items);
+}
+
+/// Names a missing `-f` or `PROCTMUX_CONFIG` file by absolute path, since a
+/// relative one is easy to resolve against the wrong directory.
+fn logMissingPath(allocator: std.mem.Allocator, dir: std.fs.Dir, path: []const u8) void {
+    const dir_path = dir.realpathAlloc(allocator, ".") catch {
+        log.warn("config not found at {s}", .{path});
+        return;
+    };
+    defer allocator.free(dir_path);
+    const full_path = std.fs.path.resolve(allocator, &.{ dir_path, path }) catch {
+        log.warn("config not found at {s}", .{path});
+        return;
+    };
+    defer allocator.free(full_path);
+    log.warn("config not found at {s}", .{full_path});
+}
+
+fn logMissingDefault(allocator: std.mem.Allocator, dir: std.fs.Dir) void {
+    const dir_path = dir.realpathAlloc(allocator, ".") catch {
+        log.warn("no proctmux.yaml, proctmux.yml, procmux.yaml or procmux.yml in the working directory", .{});
+        return;
+    };
+    defer allocator.free(dir_path);
+    log.warn("no proctmux.yaml, proctmux.yml, procmux.yaml or procmux.yml in {s}", .{dir_path});
+}
+
+/// Reports keys the loader did not recognize, usually typos such as `comand`.
+/// Dead fields are accepted on purpose and stay quiet.
+fn logUnknownFields(loaded: *const LoadedRuntimeConfig) void {
+    for (loaded.warnings.items) |warning| {
+        if (warning.kind != .unknown_field) continue;
+        if (warning.line > 0) {
+            log.warn("{s}:{d}: unknown key '{s}' ({s})", .{ loaded.config.file_path, warning.line, warning.path, warning.message });
+        } else {
+            log.warn("{s}: unknown key '{s}' ({s})", .{ loaded.config.file_path, warning.path, warning.message });
+        }
+    }
+}
+
+/// Copies top-level `environment` entries into every process `env`. Keys a
+/// process already sets are left alone so per-process values win.
+pub fn applyGlobalEnvironment(allocator: std.mem.Allocator, cfg: *schema.Config) !void {
+    if (cfg.environment.count() == 0) return;
+
+    var it = cfg.procs.iterator();
+    while (it.next()) |entry| {
+        const proc = entry.value_ptr;
+        var env_it = cfg.environment.iterator();
+        while (env_it.next()) |env_entry| {
+            if (proc.env.contains(env_entry.key_ptr.*)) continue;
+            try schema.putOwnedString(allocator, &proc.env, env_entry.key_ptr.*, env_entry.value_ptr.*);
+        }
+    }
+}
+
+/// Expands `$NAME`/`${NAME}` in each process `shell`, `cmd`, and `pause`
+/// for names the process declares in `env`, `environment` or `--set`. Other
+/// names, such as `$PWD`, `$PATH` or shell-local variables, and `\$` are
+/// left for the shell; in `cmd`, `\$` becomes a plain `$`.
+pub fn expandProcessCommands(
+    scratch_allocator: std.mem.Allocator,
+    cfg: *schema.Config,
+    base_env: *const std.process.EnvMap,
+) !void {
+    const allocator = cfg.allocator;
+    var it = cfg.procs.iterator();
+    while (it.next()) |entry| {
+        const proc = entry.value_ptr;
+        var env_map = try processEnvMap(scratch_allocator, proc, base_env);
+        defer env_map.deinit();
+
+        const shell_options: expand.Options = .{ .keep_unknown = true, .keep_escape = true };
+        try expandOwnedString(allocator, proc, &proc.shell, &env_map, shell_options);
+        try expandOwnedString(allocator, proc, &proc.pause, &env_map, shell_options);
+        try expandList(&proc.cmd, &env_map);
+        try expandList(&proc.args, &env_map);
+    }
+}
+
+fn expandList(list: *schema.StringList, env_map: *const std.process.EnvMap) !void {
+    for (list.items) |*part| {
+        if (std.mem.indexOfScalar(u8, part.*, '$') == null) continue;
+        const expanded = try expand.expand(list.allocator, part.*, env_map, .{ .keep_unknown = true });
+        list.allocator.free(part.*);
+        part.* = expanded;
+    }
+}
+
+/// The names the process declares, with the values the child will see;
+/// `env` values may themselves reference `base_env`. Launcher variables are
+/// left out because the child's shell resolves them against its own `cwd`
+/// and `add_path`.
+fn processEnvMap(
+    allocator: std.mem.Allocator,
+    proc: *const schema.ProcessConfig,
+    base_env: *const std.process.EnvMap,
+) !std.process.EnvMap {
+    var env_map = std.process.EnvMap.init(allocator);
+    errdefer env_map.deinit();
+
+    var it = proc.env.iterator();
+    while (it.next()) |entry| {
+        const value = try expand.expand(allocator, entry.value_ptr.*, base_env, .{});
+        defer allocator.free(value);
+        try env_map.put(entry.key_ptr.*, value);
+    }
+    var set_it = proc.set_env.iterator();
+    while (set_it.next()) |entry| try env_map.put(entry.key_ptr.*, entry.value_ptr.*);
+    return env_map;
+}
+
+fn expandOwnedString(
+    allocator: std.mem.Allocator,
+    proc: *schema.ProcessConfig,
+    value: *[]const u8,
+    env_map: *const std.process.EnvMap,
+    options: expand.Options,
+) !void {
+    if (std.mem.indexOfScalar(u8, value.*, '$') == null) return;
+    const expanded = try expand.expand(allocator, value.*, env_map, options);
+    if (proc.owns_scalar_strings) allocator.free(value.*);
+    value.* = expanded;
+}
+
+/// Rewrites each process `cwd` and `log_file` through `paths.resolve`.
+pub fn resolveProcessPaths(
+    allocator: std.mem.Allocator,
+    cfg: *schema.Config,
+    config_dir: []const u8,
+    home: ?[]const u8,
+) !void {
+    var it = cfg.procs.iterator();
+    while (it.next()) |entry| {
+        const proc = entry.value_ptr;
+        try resolveOwnedPath(allocator, proc, &proc.cwd, config_dir, home);
+        try resolveOwnedPath(allocator, proc, &proc.log_file, config_dir, home);
+    }
+}
+
+fn resolveOwnedPath(
+    allocator: std.mem.Allocator,
+    proc: *schema.ProcessConfig,
+    path: *[]const u8,
+    config_dir: []const u8,
+    home: ?[]const u8,
+) !void {
+    if (path.len == 0) return;
+    const resolved = (try paths.resolve(allocator, path.*, config_dir, home)) orelse return;
+    if (proc.owns_scalar_strings) allocator.free(path.*);
+    path.* = resolved;
+}
+
+test "explicit config path wins over PROCTMUX_CONFIG" {
+    try std.testing.expectEqualStrings("cli.yaml", selectConfigFile("cli.yaml", "env.yaml"));
+    try std.testing.expectEqualStrings("env.yaml", selectConfigFile("", "env.yaml"));
+    try std.testing.expectEqualStrings("", selectConfigFile("", null));
+}
+
+test "process commands expand the names the process declares" {
+    var cfg = schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+
+    var proc = schema.ProcessConfig.empty(std.testing.allocator);
+    proc.owns_scalar_strings = true;
+    proc.shell = try std.testing.allocator.dupe(u8, "$HOME/bin/server --port ${PORT} \\$PORT $1");
+    try schema.putOwnedString(std.testing.allocator, &proc.env, "PORT", "80$SUFFIX");
+    try cfg.procs.put(try std.testing.allocator.dupe(u8, "api"), proc);
+
+    var tool = schema.ProcessConfig.empty(std.testing.allocator);
+    try schema.putOwnedString(std.testing.allocator, &tool.env, "TOOLS", "$HOME/bin");
+    try schema.appendOwned(std.testing.allocator, &tool.cmd, "$TOOLS/tool");
+    try schema.appendOwned(std.testing.allocator, &tool.cmd, "\\$TOOLS");
+    try cfg.procs.put(try std.testing.allocator.dupe(u8, "tool"), tool);
+
+    var base_env = std.process.EnvMap.init(std.testing.allocator);
+    defer base_env.deinit();
+    try base_env.put("HOME", "/home/dev");
+    try base_env.put("SUFFIX", "80");
+
+    try expandProcessCommands(std.testing.allocator, &cfg, &base_env);
+
+    try std.testing.expectEqualStrings("$HOME/bin/server --port 8080 \\$PORT $1", cfg.procs.get("api").?.shell);
+    const tool_cmd = cfg.procs.get("tool").?.cmd.items;
+    try std.testing.expectEqualStrings("/home/dev/bin/tool", tool_cmd[0]);
+    try std.testing.expectEqualStrings("$TOOLS", tool_cmd[1]);
+}
+
+test "process commands leave launcher variables for the shell" {
+    var cfg = schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+
+    var proc = schema.ProcessConfig.empty(std.testing.allocator);
+    proc.owns_scalar_strings = true;
+    proc.shell = try std.testing.allocator.dupe(u8, "echo $PWD; PORT=3000; serve --port $PORT");
+    try cfg.procs.put(try std.testing.allocator.dupe(u8, "api"), proc);
+
+    var base_env = std.process.EnvMap.init(std.testing.allocator);
+    defer base_env.deinit();
+    try base_env.put("PWD", "/home/dev/launcher");
+    try base_env.put("PORT", "8080");
+
+    try expandProcessCommands(std.testing.allocator, &cfg, &base_env);
+
+    try std.testing.expectEqualStrings("echo $PWD; PORT=3000; serve --port $PORT", cfg.procs.get("api").?.shell);
+}
+
+test "PROCTMUX_CONFIG value selects the file when no path is given" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    try tmp.dir.writeFile(.{ .sub_path = "proctmux.yaml", .data = "procs:\n  default:\n    shell: \"true\"\n" });
+    try tmp.dir.writeFile(.{ .sub_path = "env.yaml", .data = "procs:\n  from-env:\n    shell: \"true\"\n" });
+
+    var from_env = try loadFilesWithEnvInDir(std.testing.allocator, tmp.dir, &.{}, &.{}, " env.yaml\n");
+    defer from_env.deinit();
+    try std.testing.expect(from_env.config.procs.contains("from-env"));
+
+    var searched = try loadFilesWithEnvInDir(std.testing.allocator, tmp.dir, &.{}, &.{}, null);
+    defer searched.deinit();
+    try std.testing.expect(searched.config.procs.contains("default"));
+
+    try std.testing.expectError(
+        error.ConfigPathNotFound,
+        loadFilesWithEnvInDir(std.testing.allocator, tmp.dir, &.{}, &.{}, "missing.yaml"),
+    );
+}
+
+test "socket key follows the config file paths, not their contents" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    try tmp.dir.writeFile(.{ .sub_path = "proctmux.yaml", .data = "procs:\n  api:\n    shell: \"echo $PATH\"\n" });
+    try tmp.dir.writeFile(.{ .sub_path = "local.yaml", .data = "procs:\n  api:\n    cwd: \"api\"\n" });
+
+    var before = try loadFilesWithEnvInDir(std.testing.allocator, tmp.dir, &.{}, &.{}, null);
+    defer before.deinit();
+    try tmp.dir.writeFile(.{ .sub_path = "proctmux.yaml", .data = "procs:\n  api:\n    shell: \"echo edited\"\n" });
+    var edited = try loadFilesWithEnvInDir(std.testing.allocator, tmp.dir, &.{}, &.{}, null);
+    defer edited.deinit();
+    try std.testing.expectEqualStrings(before.config.socket_key, edited.config.socket_key);
+
+    var merged = try loadFilesWithEnvInDir(std.testing.allocator, tmp.dir, &.{ "proctmux.yaml", "local.yaml" }, &.{}, null);
+    defer merged.deinit();
+    try std.testing.expect(!std.mem.eql(u8, before.config.socket_key, merged.config.socket_key));
+}
+
+test "--set overrides keep the socket of the same config" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    try tmp.dir.writeFile(.{ .sub_path = "proctmux.yaml", .data = "procs:\n  worker:\n    shell: \"run --jobs $CONCURRENCY\"\n" });
+
+    var plain = try loadFilesWithEnvInDir(std.testing.allocator, tmp.dir, &.{}, &.{}, null);
+    defer plain.deinit();
+    var overridden = try loadFilesWithEnvInDir(std.testing.allocator, tmp.dir, &.{}, &.{"worker.CONCURRENCY=4"}, null);
+    defer overridden.deinit();
+
+    try std.testing.expectEqualStrings("run --jobs 4", overridden.config.procs.get("worker").?.shell);
+    try std.testing.expectEqualStrings(plain.config.socket_key, overridden.config.socket_key);
+}
+
+test "missing selected config path reports ConfigPathNotFound" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+
+    try std.testing.expectError(
+        error.ConfigPathNotFound,
+        loadSelectedInDir(std.testing.allocator, tmp.dir, &.{"shared/proctmux.yaml"}, &.{}),
+    );
+}
@@ -14,8 +14,14 @@ pub const Warning = struct {
     kind: WarningKind,
     path: []const u8,
     message: []const u8,
+    /// 1-based line of the key in the config file, or 0 when unknown.
+    line: u32 = 0,
 };
 
+/// Config `version` this build reads. Files may omit it; other values still
+/// load with a warning.
+pub const config_version: i32 = 1;
+
 pub const StringList = std.array_list.Managed([]const u8);
 pub const StringMap = std.StringArrayHashMap([]const u8);
 pub const ProcessMap = std.StringArrayHashMap(ProcessConfig);
@@ -27,13 +33,27 @@ pub const KeybindingConfig = struct {
     start: StringList,
     stop: StringList,
     restart: StringList,
+    start_all: StringList,
+    stop_all: StringList,
     filter: StringList,
     submit_filter: StringList,
     toggle_running: StringList,
+    next_running: StringList,
+    next_stopped: StringList,
     toggle_help: StringList,
     toggle_focus: StringList,
     focus_client: StringList,
     focus_server: StringList,
+    toggle_zoom: StringList,
+    grow_output: StringList,
+    shrink_output: StringList,
+    copy_mode: StringList,
+    send_input: StringList,
+    reload_config: StringList,
+    edit_config: StringList,
+    clear_output: StringList,
+    copy_output: StringList,
+    command_palette: StringList,
     docs: StringList,
 
     pub fn empty(allocator: Allocator) KeybindingConfig {
@@ -44,13 +64,27 @@ pub const KeybindingConfig = struct {
             .start = StringList.init(allocator),
             .stop = StringList.init(allocator),
             .restart = StringList.init(allocator),
+            .start_all = StringList.init(allocator),
+            .stop_all = StringList.init(allocator),
             .filter = StringList.init(allocator),
             .submit_filter = StringList.init(allocator),
             .toggle_running = StringList.init(allocator),
+            .next_running = StringList.init(allocator),
+            .next_stopped = StringList.init(allocator),
             .toggle_help = StringList.init(allocator),
             .toggle_focus = StringList.init(allocator),
             .focus_client = StringList.init(allocator),
             .focus_server = StringList.init(allocator),
+            .toggle_zoom = StringList.init(allocator),
+            .grow_output = StringList.init(allocator),
+            .shrink_output = StringList.init(allocator),
+            .copy_mode = StringList.init(allocator),
+            .send_input = StringList.init(allocator),
+            .reload_config = StringList.init(allocator),
+            .edit_config = StringList.init(allocator),
+            .clear_output = StringList.init(allocator),
+            .copy_output = StringList.init(allocator),
+            .command_palette = StringList.init(allocator),
             .docs = StringList.init(allocator),
         };
     }
@@ -62,13 +96,27 @@ pub const KeybindingConfig = struct {
         deinitStringList(&self.start);
         deinitStringList(&self.stop);
         deinitStringList(&self.restart);
+        deinitStringList(&self.start_all);
+        deinitStringList(&self.stop_all);
         deinitStringList(&self.filter);
         deinitStringList(&self.submit_filter);
         deinitStringList(&self.toggle_running);
+        deinitStringList(&self.next_running);
+        deinitStringList(&self.next_stopped);
         deinitStringList(&self.toggle_help);
         deinitStringList(&self.toggle_focus);
         deinitStringList(&self.focus_client);
         deinitStringList(&self.focus_server);
+        deinitStringList(&self.toggle_zoom);
+        deinitStringList(&self.grow_output);
+        deinitStringList(&self.shrink_output);
+        deinitStringList(&self.copy_mode);
+        deinitStringList(&self.send_input);
+        deinitStringList(&self.reload_config);
+        deinitStringList(&self.edit_config);
+        deinitStringList(&self.clear_output);
+        deinitStringList(&self.copy_output);
+        deinitStringList(&self.command_palette);
         deinitStringList(&self.docs);
     }
 };
@@ -76,14 +124,24 @@ pub const KeybindingConfig = struct {
 pub const LayoutConfig = struct {
     category_search_prefix: []const u8 = "",
     processes_list_width: i32 = 0,
+    process_list_percent: i32 = 0,
     hide_process_description_panel: bool = false,
     hide_process_list_when_unfocused: bool = false,
     sort_process_list_alpha: bool = false,
     sort_process_list_running_first: bool = false,
     placeholder_banner: []const u8 = "",
     enable_debug_process_info: bool = false,
+    show_status_bar: bool = false,
+    /// Comma-separated fields plain filter text matches; see `filter_fields`.
+    filter_fields: []const u8 = "",
+    /// Show the newest output line beside each running process in the list.
+    show_last_line: bool = false,
 };
 
+/// Process fields `layout.filter_fields` can name. Name matches always rank
+/// first; the others match on a case-insensitive substring.
+pub const filter_fields = [_][]const u8{ "name", "description", "categories" };
+
 pub const StyleConfig = struct {
     selected_process_color: []const u8 = "",
     selected_process_bg_color: []const u8 = "",
@@ -99,33 +157,124 @@ pub const GeneralConfig = struct {
     procs_from_package_json: bool = false,
 };
 
+/// Category that processes without `categories` match in category filters.
+pub const default_category = "uncategorized";
+
+/// Optional `ready_when` checks. Every configured check must pass before a
+/// dependent process starts; zero timings fall back to controller defaults.
+pub const ReadyCheck = struct {
+    command: []const u8 = "",
+    output: []const u8 = "",
+    timeout_ms: i32 = 0,
+    interval_ms: i32 = 0,
+
+    pub fn enabled(self: ReadyCheck) bool {
+        return self.command.len > 0 or self.output.len > 0;
+    }
+};
+
+/// When the primary re-runs a process that exited on its own. User stops
+/// never trigger a restart.
+pub const RestartPolicy = enum {
+    never,
+    always,
+    on_failure,
+
+    /// Parses the YAML spelling (`never`, `always`, `on-failure`).
+    pub fn parse(value: []const u8) ?RestartPolicy {
+        if (std.mem.eql(u8, value, "never")) return .never;
+        if (std.mem.eql(u8, value, "always")) return .always;
+        if (std.mem.eql(u8, value, "on-failure") or std.mem.eql(u8, value, "on_failure")) return .on_failure;
+        return null;
+    }
+};
+
+/// What a start request does when its process is already running. Nothing is
+/// spawned either way.
+pub const StartWhenRunning = enum {
+    /// Reply that the process is already running.
+    notice,
+    /// Also make it the current process, so its output is shown.
+    focus,
+
+    pub fn parse(value: []const u8) ?StartWhenRunning {
+        return std.meta.stringToEnum(StartWhenRunning, value);
+    }
+};
+
 /// Owned config for one managed process. String ownership is explicit because
 /// entries may originate from YAML, discovery, defaults, or tests.
 pub const ProcessConfig = struct {
     shell: []const u8 = "",
     cmd: StringList,
+    /// Appended to `shell` (shell-quoted) or `cmd` (as argv entries).
+    args: StringList,
+    pause: []const u8 = "",
     cwd: []const u8 = "",
     env: StringMap,
+    /// Values from `--set <process>.<key>=<value>`. They apply over `env` but
+    /// stay out of the config hash, so clients run without the flags still
+    /// find the server.
+    set_env: StringMap,
     stop: i32 = 0,
     stop_timeout_ms: i32 = 0,
+    stop_sequence: []const u8 = "",
+    /// Stop the process once it has run this long; 0 lets it run indefinitely.
+    max_runtime_secs: i32 = 0,
     autostart: bool = false,
+    /// Position among autostart and start-all launches, lowest first; null
+    /// launches after every numbered process, in config order.
+    order: ?i32 = null,
+    /// Wait before autostarting this process; overrides the top-level stagger.
+    start_delay_ms: i32 = 0,
     autofocus: bool = false,
     description: []const u8 = "",
     docs: []const u8 = "",
+    /// Single key that jumps the selection straight to this process.
+    hotkey: []const u8 = "",
+    /// List color for the process name; empty uses the terminal default.
+    color: []const u8 = "",
     meta_tags: StringList,
     categories: StringList,
+    depends_on: StringList,
+    shell_cmd: StringList,
     add_path: StringList,
     terminal_rows: i32 = 0,
     terminal_cols: i32 = 0,
     on_kill: StringList,
+    on_start: []const u8 = "",
+    on_stop: []const u8 = "",
+    /// Host commands run when the process exits on its own with status 0 or
+    /// non-zero; the status is passed as `PROCTMUX_EXIT_CODE`.
+    on_success: []const u8 = "",
+    on_failure: []const u8 = "",
+    log_file: []const u8 = "",
+    log_append: bool = true,
+    /// Keep earlier runs' output on screen when the process starts again.
+    reuse_pane: bool = false,
+    ready_when: ReadyCheck = .{},
+    restart: RestartPolicy = .never,
+    restart_max_retries: i32 = 0,
+    restart_backoff_ms: i32 = 0,
+    /// Run once and leave nothing behind: the exited instance and its output
+    /// are dismissed, keeping only the exit status for the list.
+    task: bool = false,
+    /// Keep a failed task's output around instead of dismissing it.
+    keep_on_failure: bool = false,
+    /// Overrides the top-level `remain_on_exit`; null inherits it.
+    remain_on_exit: ?bool = null,
     owns_scalar_strings: bool = false,
 
     pub fn empty(allocator: Allocator) ProcessConfig {
         return .{
             .cmd = StringList.init(allocator),
+            .args = StringList.init(allocator),
             .env = StringMap.init(allocator),
+            .set_env = StringMap.init(allocator),
             .meta_tags = StringList.init(allocator),
             .categories = StringList.init(allocator),
+            .depends_on = StringList.init(allocator),
+            .shell_cmd = StringList.init(allocator),
             .add_path = StringList.init(allocator),
             .on_kill = StringList.init(allocator),
         };
@@ -133,23 +282,33 @@ pub const ProcessConfig = struct {
 
     pub fn deinit(self: *ProcessConfig, allocator: Allocator) void {
         deinitStringList(&self.cmd);
+        deinitStringList(&self.args);
         deinitStringList(&self.meta_tags);
         deinitStringList(&self.categories);
+        deinitStringList(&self.depends_on);
+        deinitStringList(&self.shell_cmd);
         deinitStringList(&self.add_path);
         deinitStringList(&self.on_kill);
 
-        var it = self.env.iterator();
-        while (it.next()) |entry| {
-            allocator.free(entry.key_ptr.*);
-            allocator.free(entry.value_ptr.*);
-        }
-        self.env.deinit();
+        deinitStringMap(allocator, &self.env);
+        deinitStringMap(allocator, &self.set_env);
 
         if (self.owns_scalar_strings) {
             if (self.shell.len > 0) allocator.free(self.shell);
+            if (self.pause.len > 0) allocator.free(self.pause);
             if (self.cwd.len > 0) allocator.free(self.cwd);
+            if (self.stop_sequence.len > 0) allocator.free(self.stop_sequence);
+            if (self.on_start.len > 0) allocator.free(self.on_start);
+            if (self.on_stop.len > 0) allocator.free(self.on_stop);
+            if (self.on_success.len > 0) allocator.free(self.on_success);
+            if (self.on_failure.len > 0) allocator.free(self.on_failure);
+            if (self.log_file.len > 0) allocator.free(self.log_file);
+            if (self.ready_when.command.len > 0) allocator.free(self.ready_when.command);
+            if (self.ready_when.output.len > 0) allocator.free(self.ready_when.output);
             if (self.description.len > 0) allocator.free(self.description);
             if (self.docs.len > 0) allocator.free(self.docs);
+            if (self.hotkey.len > 0) allocator.free(self.hotkey);
+            if (self.color.len > 0) allocator.free(self.color);
         }
     }
 };
@@ -160,14 +319,50 @@ pub const Config = struct {
     allocator: Allocator,
     file_path: []const u8 = "",
     owns_file_path: bool = false,
+    /// Socket identity from the resolved config file paths, set by runtime
+    /// loading so edits keep the socket. Empty hashes the config instead.
+    socket_key: []const u8 = "",
+    version: i32 = 0,
     keybinding: KeybindingConfig,
     layout: LayoutConfig = .{},
     style: StyleConfig = .{},
     general: GeneralConfig = .{},
     shell_cmd: StringList,
+    environment: StringMap,
     log_file: []const u8 = "",
     stdout_debug_log_file: []const u8 = "",
     owns_log_paths: bool = false,
+    control_socket: []const u8 = "",
+    owns_control_socket: bool = false,
+    /// `host:port` for the read-only HTTP `/status` endpoint; empty disables it.
+    http_status_addr: []const u8 = "",
+    owns_http_status_addr: bool = false,
+    /// Shell command that reads copied output on stdin; empty auto-detects.
+    clipboard_command: []const u8 = "",
+    owns_clipboard_command: bool = false,
+    /// Where the last selection and filter are kept; empty uses the XDG state dir.
+    state_file: []const u8 = "",
+    owns_state_file: bool = false,
+    pause: []const u8 = "",
+    owns_pause: bool = false,
+    /// Host commands run once before any process starts and once at shutdown.
+    setup: []const u8 = "",
+    owns_setup: bool = false,
+    teardown: []const u8 = "",
+    owns_teardown: bool = false,
+    /// Wait between autostarted processes that set no `start_delay_ms`.
+    start_delay_ms: i32 = 0,
+    confirm_quit_when_running: bool = true,
+    /// Report mouse clicks to the TUI so a click selects a process or a pane.
+    mouse: bool = false,
+    /// Keep an exited process's output in view until it is started again.
+    remain_on_exit: bool = true,
+    /// Whether starting a running process also switches to it.
+    start_when_running: StartWhenRunning = .notice,
+    /// How long clients wait on the Primary Server; 0 uses the IPC default.
+    ipc_timeout_ms: i32 = 0,
+    /// How often the Primary Server re-reads process state; 0 uses the default.
+    refresh_interval_ms: i32 = 0,
     procs: ProcessMap,
 
     pub fn empty(allocator: Allocator) Config {
@@ -175,6 +370,7 @@ pub const Config = struct {
             .allocator = allocator,
             .keybinding = KeybindingConfig.empty(allocator),
             .shell_cmd = StringList.init(allocator),
+            .environment = StringMap.init(allocator),
             .procs = ProcessMap.init(allocator),
         };
     }
@@ -182,6 +378,12 @@ pub const Config = struct {
     pub fn deinit(self: *Config) void {
         self.keybinding.deinit();
         deinitStringList(&self.shell_cmd);
+        var env_it = self.environment.iterator();
+        while (env_it.next()) |entry| {
+            self.allocator.free(entry.key_ptr.*);
+            self.allocator.free(entry.value_ptr.*);
+        }
+        self.environment.deinit();
         var it = self.procs.iterator();
         while (it.next()) |entry| {
             self.allocator.free(entry.key_ptr.*);
@@ -193,6 +395,13 @@ pub const Config = struct {
             if (self.log_file.len > 0) self.allocator.free(self.log_file);
             if (self.stdout_debug_log_file.len > 0) self.allocator.free(self.stdout_debug_log_file);
         }
+        if (self.owns_control_socket and self.control_socket.len > 0) self.allocator.free(self.control_socket);
+        if (self.owns_http_status_addr and self.http_status_addr.len > 0) self.allocator.free(self.http_status_addr);
+        if (self.owns_clipboard_command and self.clipboard_command.len > 0) self.allocator.free(self.clipboard_command);
+        if (self.owns_state_file and self.state_file.len > 0) self.allocator.free(self.state_file);
+        if (self.owns_pause and self.pause.len > 0) self.allocator.free(self.pause);
+        if (self.owns_setup and self.setup.len > 0) self.allocator.free(self.setup);
+        if (self.owns_teardown and self.teardown.len > 0) self.allocator.free(self.teardown);
     }
 };
 
@@ -202,6 +411,15 @@ pub fn deinitStringList(list: *StringList) void {
     list.deinit();
 }
 
+fn deinitStringMap(allocator: Allocator, map: *StringMap) void {
+    var it = map.iterator();
+    while (it.next()) |entry| {
+        allocator.free(entry.key_ptr.*);
+        allocator.free(entry.value_ptr.*);
+    }
+    map.deinit();
+}
+
 pub fn appendOwned(allocator: Allocator, list: *StringList, value: []const u8) !void {
     try list.append(try allocator.dupe(u8, value));
 }
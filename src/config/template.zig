@@ -6,6 +6,8 @@ pub fn content() []const u8 {
     \\# Proctmux Configuration File
     \\# Generated by 'proctmux config-init'
     \\
+    \\version: 1
+    \\
     \\procs:
     \\  example-process:
     \\    shell: "echo 'Hello from proctmux!' && sleep 30"
@@ -33,12 +35,16 @@ pub fn content() []const u8 {
     \\
     \\layout:
     \\  processes_list_width: 30
+    \\  process_list_percent: 0
     \\  hide_process_description_panel: false
     \\  hide_process_list_when_unfocused: false
     \\  sort_process_list_alpha: false
     \\  sort_process_list_running_first: false
     \\  category_search_prefix: "cat:"
+    \\  filter_fields: "name,description,categories"
     \\  enable_debug_process_info: false
+    \\  show_status_bar: false
+    \\  show_last_line: false
     \\
     \\style:
     \\  pointer_char: "▶"
@@ -56,18 +62,49 @@ pub fn content() []const u8 {
     \\  start: ["s", "enter"]
     \\  stop: ["x"]
     \\  restart: ["r"]
+    \\  start_all: ["S"]
+    \\  stop_all: ["X"]
     \\  filter: ["/"]
     \\  submit_filter: ["enter"]
     \\  toggle_running: ["R"]
+    \\  next_running: ["n"]
+    \\  next_stopped: ["N"]
     \\  toggle_help: ["?"]
     \\  toggle_focus: ["ctrl+w"]
     \\  focus_client: ["ctrl+left"]
     \\  focus_server: ["ctrl+right"]
+    \\  toggle_zoom: ["f5"]
+    \\  grow_output: ["f8"]
+    \\  shrink_output: ["f7"]
+    \\  copy_mode: ["f6"]
+    \\  send_input: ["i"]
+    \\  reload_config: ["ctrl+r"]
+    \\  edit_config: ["e"]
+    \\  clear_output: ["ctrl+l"]
+    \\  copy_output: ["y"]
+    \\  command_palette: [":"]
     \\  docs: ["d"]
     \\
+    \\environment:
+    \\  EXAMPLE_SHARED_VAR: "shared_value"
+    \\
     \\shell_cmd: ["sh", "-c"]
     \\log_file: ""
     \\stdout_debug_log_file: ""
+    \\control_socket: ""
+    \\http_status_addr: ""
+    \\clipboard_command: ""
+    \\state_file: ""
+    \\pause: ""
+    \\setup: ""
+    \\teardown: ""
+    \\start_delay_ms: 0
+    \\confirm_quit_when_running: true
+    \\mouse: false
+    \\remain_on_exit: true
+    \\start_when_running: notice
+    \\ipc_timeout_ms: 5000
+    \\refresh_interval_ms: 100
     \\
     ;
 }
@@ -48,19 +48,48 @@ fn cloneProcessConfig(allocator: std.mem.Allocator, source: config.schema.Proces
     out.owns_scalar_strings = true;
 
     if (source.shell.len > 0) out.shell = try allocator.dupe(u8, source.shell);
+    if (source.pause.len > 0) out.pause = try allocator.dupe(u8, source.pause);
     if (source.cwd.len > 0) out.cwd = try allocator.dupe(u8, source.cwd);
+    if (source.on_start.len > 0) out.on_start = try allocator.dupe(u8, source.on_start);
+    if (source.on_stop.len > 0) out.on_stop = try allocator.dupe(u8, source.on_stop);
+    if (source.on_success.len > 0) out.on_success = try allocator.dupe(u8, source.on_success);
+    if (source.on_failure.len > 0) out.on_failure = try allocator.dupe(u8, source.on_failure);
     if (source.description.len > 0) out.description = try allocator.dupe(u8, source.description);
     if (source.docs.len > 0) out.docs = try allocator.dupe(u8, source.docs);
+    if (source.hotkey.len > 0) out.hotkey = try allocator.dupe(u8, source.hotkey);
+    if (source.color.len > 0) out.color = try allocator.dupe(u8, source.color);
     out.stop = source.stop;
     out.stop_timeout_ms = source.stop_timeout_ms;
+    if (source.stop_sequence.len > 0) out.stop_sequence = try allocator.dupe(u8, source.stop_sequence);
+    out.max_runtime_secs = source.max_runtime_secs;
     out.autostart = source.autostart;
+    out.order = source.order;
+    out.start_delay_ms = source.start_delay_ms;
     out.autofocus = source.autofocus;
     out.terminal_rows = source.terminal_rows;
     out.terminal_cols = source.terminal_cols;
+    if (source.log_file.len > 0) out.log_file = try allocator.dupe(u8, source.log_file);
+    out.log_append = source.log_append;
+    out.reuse_pane = source.reuse_pane;
+    out.restart = source.restart;
+    out.restart_max_retries = source.restart_max_retries;
+    out.restart_backoff_ms = source.restart_backoff_ms;
+    out.task = source.task;
+    out.keep_on_failure = source.keep_on_failure;
+    out.remain_on_exit = source.remain_on_exit;
+    out.ready_when = .{
+        .timeout_ms = source.ready_when.timeout_ms,
+        .interval_ms = source.ready_when.interval_ms,
+    };
+    if (source.ready_when.command.len > 0) out.ready_when.command = try allocator.dupe(u8, source.ready_when.command);
+    if (source.ready_when.output.len > 0) out.ready_when.output = try allocator.dupe(u8, source.ready_when.output);
 
     for (source.cmd.items) |item| try config.schema.appendOwned(allocator, &out.cmd, item);
+    for (source.args.items) |item| try config.schema.appendOwned(allocator, &out.args, item);
     for (source.meta_tags.items) |item| try config.schema.appendOwned(allocator, &out.meta_tags, item);
     for (source.categories.items) |item| try config.schema.appendOwned(allocator, &out.categories, item);
+    for (source.depends_on.items) |item| try config.schema.appendOwned(allocator, &out.depends_on, item);
+    for (source.shell_cmd.items) |item| try config.schema.appendOwned(allocator, &out.shell_cmd, item);
     for (source.add_path.items) |item| try config.schema.appendOwned(allocator, &out.add_path, item);
     for (source.on_kill.items) |item| try config.schema.appendOwned(allocator, &out.on_kill, item);
 
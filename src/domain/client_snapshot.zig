@@ -16,13 +16,27 @@ pub const UiKeybindingConfig = struct {
     start: StringList = &.{},
     stop: StringList = &.{},
     restart: StringList = &.{},
+    start_all: StringList = &.{},
+    stop_all: StringList = &.{},
     filter: StringList = &.{},
     submit_filter: StringList = &.{},
     toggle_running: StringList = &.{},
+    next_running: StringList = &.{},
+    next_stopped: StringList = &.{},
     toggle_help: StringList = &.{},
     toggle_focus: StringList = &.{},
     focus_client: StringList = &.{},
     focus_server: StringList = &.{},
+    toggle_zoom: StringList = &.{},
+    grow_output: StringList = &.{},
+    shrink_output: StringList = &.{},
+    copy_mode: StringList = &.{},
+    send_input: StringList = &.{},
+    reload_config: StringList = &.{},
+    edit_config: StringList = &.{},
+    clear_output: StringList = &.{},
+    copy_output: StringList = &.{},
+    command_palette: StringList = &.{},
     docs: StringList = &.{},
 };
 
@@ -34,6 +48,9 @@ pub const UiLayoutConfig = struct {
     sort_process_list_running_first: bool = false,
     placeholder_banner: []const u8 = "",
     enable_debug_process_info: bool = false,
+    show_status_bar: bool = false,
+    filter_fields: []const u8 = "",
+    show_last_line: bool = false,
 };
 
 pub const UiStyleConfig = struct {
@@ -44,6 +61,7 @@ pub const UiStyleConfig = struct {
 };
 
 pub const UiConfig = struct {
+    confirm_quit_when_running: bool = true,
     keybinding: UiKeybindingConfig = .{},
     layout: UiLayoutConfig = .{},
     style: UiStyleConfig = .{},
@@ -56,9 +74,21 @@ pub const ProcessSummary = struct {
     label: []const u8,
     status: process.ProcessStatus = .halted,
     pid: i32 = -1,
+    /// Set once a process has exited on its own (e.g. shown as "exited 1").
+    exit_status: ?u32 = null,
+    /// Stopped for exceeding `max_runtime_secs` rather than exiting by itself.
+    timed_out: bool = false,
+    /// The config changed since this process started; a restart applies it.
+    config_changed: bool = false,
+    /// Configured as a one-shot `task` rather than a long-lived service.
+    task: bool = false,
     description: []const u8 = "",
     docs: []const u8 = "",
+    hotkey: []const u8 = "",
+    color: []const u8 = "",
     categories: StringList = &.{},
+    /// Newest output line of a running process, when `show_last_line` is on.
+    last_line: []const u8 = "",
 };
 
 /// Complete replacement state for Client Sessions.
@@ -96,12 +126,23 @@ pub fn fromAppState(
     app_state: *const state.AppState,
     controller: process.ProcessController,
 ) !BuiltClientSnapshot {
-    var processes = try allocator.alloc(ProcessSummary, app_state.processes.items.len);
+    const count = app_state.processes.items.len;
+    var processes = try allocator.alloc(ProcessSummary, count);
     errdefer allocator.free(processes);
 
-    for (app_state.processes.items, 0..) |proc, index| {
-        const view = process.toView(proc, controller);
+    const ids = try allocator.alloc(process.ProcessId, count);
+    defer allocator.free(ids);
+    const live = try allocator.alloc(process.LiveState, count);
+    defer allocator.free(live);
+    for (app_state.processes.items, ids) |proc, *id| id.* = proc.id;
+    controller.getStates(ids, live);
+
+    for (app_state.processes.items, live, 0..) |proc, live_state, index| {
+        const view = process.viewWithState(proc, live_state);
         processes[index] = summaryFromView(view);
+        if (app_state.config.layout.show_last_line and view.status == .running) {
+            processes[index].last_line = controller.getLastLine(proc.id);
+        }
     }
 
     return .{ .value = .{
@@ -118,8 +159,14 @@ pub fn summaryFromView(view: process.ProcessView) ProcessSummary {
         .label = view.label,
         .status = view.status,
         .pid = view.pid,
+        .exit_status = view.exit_status,
+        .timed_out = view.timed_out,
+        .config_changed = view.config_changed,
+        .task = view.config.task,
         .description = view.config.description,
         .docs = view.config.docs,
+        .hotkey = view.config.hotkey,
+        .color = view.config.color,
         .categories = view.config.categories.items,
     };
 }
@@ -152,22 +199,63 @@ pub fn filteredProcesses(
         return owned;
     }
 
+    const fields = FilterFields.parse(snapshot.ui.layout.filter_fields);
     var matches = std.array_list.Managed(fuzzy.Match).init(allocator);
     defer matches.deinit();
+    var other_matches = std.array_list.Managed(usize).init(allocator);
+    defer other_matches.deinit();
     for (snapshot.processes, 0..) |summary, index| {
         if (show_only_running and summary.status != .running) continue;
-        if (fuzzy.score(trimmed, summary.label)) |score| {
-            try matches.append(.{ .index = index, .score = score });
+        if (fields.name) {
+            if (fuzzy.score(trimmed, summary.label)) |score| {
+                try matches.append(.{ .index = index, .score = score });
+                continue;
+            }
         }
+        if (fields.matchesOther(trimmed, summary)) try other_matches.append(index);
     }
     fuzzy.sortMatches(matches.items);
 
     var result = std.array_list.Managed(ProcessSummary).init(allocator);
     errdefer result.deinit();
     for (matches.items) |match| try result.append(snapshot.processes[match.index]);
+    for (other_matches.items) |index| try result.append(snapshot.processes[index]);
     return result.toOwnedSlice();
 }
 
+/// Which process fields plain filter text is matched against, parsed from
+/// `layout.filter_fields`; empty text enables every field.
+pub const FilterFields = struct {
+    name: bool = true,
+    description: bool = true,
+    categories: bool = true,
+
+    pub fn parse(text: []const u8) FilterFields {
+        if (std.mem.trim(u8, text, " \t,").len == 0) return .{};
+        var fields = FilterFields{ .name = false, .description = false, .categories = false };
+        var parts = std.mem.splitScalar(u8, text, ',');
+        while (parts.next()) |part| {
+            const field = std.mem.trim(u8, part, " \t");
+            inline for (std.meta.fields(FilterFields)) |info| {
+                if (std.mem.eql(u8, field, info.name)) @field(fields, info.name) = true;
+            }
+        }
+        return fields;
+    }
+
+    /// Case-insensitive substring match on the enabled fields other than the
+    /// name, which are too long for fuzzy matching to stay useful.
+    fn matchesOther(self: FilterFields, needle: []const u8, summary: ProcessSummary) bool {
+        if (self.description and indexOfIgnoreCase(summary.description, needle) != null) return true;
+        if (self.categories) {
+            for (summary.categories) |category| {
+                if (indexOfIgnoreCase(category, needle) != null) return true;
+            }
+        }
+        return false;
+    }
+};
+
 fn selectRunningProcesses(
     allocator: std.mem.Allocator,
     processes: []const ProcessSummary,
@@ -208,11 +296,12 @@ fn lessProcess(ui: *const UiConfig, a: ProcessSummary, b: ProcessSummary) bool {
 }
 
 fn matchesAllCategories(raw: []const u8, categories: []const []const u8) bool {
+    const effective: []const []const u8 = if (categories.len == 0) &.{config.schema.default_category} else categories;
     var parts = std.mem.splitScalar(u8, raw, ',');
     while (parts.next()) |part| {
         const wanted = std.mem.trim(u8, part, " \t\r\n");
         var found = false;
-        for (categories) |category| {
+        for (effective) |category| {
             if (fuzzyCategoryMatch(category, wanted)) {
                 found = true;
                 break;
@@ -246,6 +335,7 @@ fn indexOfIgnoreCase(haystack: []const u8, needle: []const u8) ?usize {
 
 pub fn fromConfig(cfg: *const config.schema.Config) UiConfig {
     return .{
+        .confirm_quit_when_running = cfg.confirm_quit_when_running,
         .keybinding = .{
             .quit = cfg.keybinding.quit.items,
             .up = cfg.keybinding.up.items,
@@ -253,13 +343,27 @@ pub fn fromConfig(cfg: *const config.schema.Config) UiConfig {
             .start = cfg.keybinding.start.items,
             .stop = cfg.keybinding.stop.items,
             .restart = cfg.keybinding.restart.items,
+            .start_all = cfg.keybinding.start_all.items,
+            .stop_all = cfg.keybinding.stop_all.items,
             .filter = cfg.keybinding.filter.items,
             .submit_filter = cfg.keybinding.submit_filter.items,
             .toggle_running = cfg.keybinding.toggle_running.items,
+            .next_running = cfg.keybinding.next_running.items,
+            .next_stopped = cfg.keybinding.next_stopped.items,
             .toggle_help = cfg.keybinding.toggle_help.items,
             .toggle_focus = cfg.keybinding.toggle_focus.items,
             .focus_client = cfg.keybinding.focus_client.items,
             .focus_server = cfg.keybinding.focus_server.items,
+            .toggle_zoom = cfg.keybinding.toggle_zoom.items,
+            .grow_output = cfg.keybinding.grow_output.items,
+            .shrink_output = cfg.keybinding.shrink_output.items,
+            .copy_mode = cfg.keybinding.copy_mode.items,
+            .send_input = cfg.keybinding.send_input.items,
+            .reload_config = cfg.keybinding.reload_config.items,
+            .edit_config = cfg.keybinding.edit_config.items,
+            .clear_output = cfg.keybinding.clear_output.items,
+            .copy_output = cfg.keybinding.copy_output.items,
+            .command_palette = cfg.keybinding.command_palette.items,
             .docs = cfg.keybinding.docs.items,
         },
         .layout = .{
@@ -270,6 +374,9 @@ pub fn fromConfig(cfg: *const config.schema.Config) UiConfig {
             .sort_process_list_running_first = cfg.layout.sort_process_list_running_first,
             .placeholder_banner = cfg.layout.placeholder_banner,
             .enable_debug_process_info = cfg.layout.enable_debug_process_info,
+            .show_status_bar = cfg.layout.show_status_bar,
+            .filter_fields = cfg.layout.filter_fields,
+            .show_last_line = cfg.layout.show_last_line,
         },
         .style = .{
             .pointer_char = cfg.style.pointer_char,
@@ -280,6 +387,61 @@ pub fn fromConfig(cfg: *const config.schema.Config) UiConfig {
     };
 }
 
+const ProcessListEntry = struct {
+    name: []const u8,
+    id: u32,
+    status: []const u8,
+    running: bool,
+    pid: ?i32,
+    description: ?[]const u8,
+};
+
+/// Formats the snapshot's process summaries as one JSON array line. Field
+/// names and order are part of the documented `signal-list --json` output,
+/// which the HTTP `/status` endpoint serves as well.
+pub fn formatProcessListJson(
+    allocator: std.mem.Allocator,
+    snapshot: *const ClientSnapshot,
+) ![]u8 {
+    const entries = try allocator.alloc(ProcessListEntry, snapshot.processes.len);
+    defer allocator.free(entries);
+
+    for (snapshot.processes, entries) |item, *entry| {
+        const running = item.status == .running;
+        entry.* = .{
+            .name = item.label,
+            .id = item.id,
+            .status = if (running) "running" else "stopped",
+            .running = running,
+            .pid = if (running and item.pid > 0) item.pid else null,
+            .description = if (item.description.len > 0) item.description else null,
+        };
+    }
+
+    var out = std.array_list.Managed(u8).init(allocator);
+    errdefer out.deinit();
+    try out.writer().print("{f}\n", .{std.json.fmt(entries, .{})});
+    return out.toOwnedSlice();
+}
+
+test "process list json emits stable fields" {
+    const snapshot = ClientSnapshot{
+        .processes = &.{
+            .{ .id = 1, .label = "api", .status = .running, .pid = 4242, .description = "API server" },
+            .{ .id = 2, .label = "worker", .status = .halted },
+        },
+    };
+
+    const out = try formatProcessListJson(std.testing.allocator, &snapshot);
+    defer std.testing.allocator.free(out);
+
+    try std.testing.expectEqualStrings(
+        "[{\"name\":\"api\",\"id\":1,\"status\":\"running\",\"running\":true,\"pid\":4242,\"description\":\"API server\"}," ++
+            "{\"name\":\"worker\",\"id\":2,\"status\":\"stopped\",\"running\":false,\"pid\":null,\"description\":null}]\n",
+        out,
+    );
+}
+
 test "client snapshot includes only client-visible process data" {
     const test_config = @import("../test_support/config.zig");
     const test_ipc = @import("../test_support/ipc.zig");
@@ -310,3 +472,28 @@ test "client snapshot includes only client-visible process data" {
     try std.testing.expectEqualStrings("API server", snapshot.view().processes[0].description);
     try std.testing.expectEqualStrings("backend", snapshot.view().processes[0].categories[0]);
 }
+
+test "filter matches descriptions and categories and ranks name matches first" {
+    const processes = [_]ProcessSummary{
+        .{ .id = 1, .label = "web", .description = "Vite dev server" },
+        .{ .id = 2, .label = "worker", .categories = &.{ "backend", "jobs" } },
+        .{ .id = 3, .label = "jobs-ui", .description = "Dashboard for queued jobs" },
+    };
+    var snapshot = ClientSnapshot{ .processes = &processes };
+
+    const vite = try filteredProcesses(std.testing.allocator, &snapshot, "vite", false);
+    defer std.testing.allocator.free(vite);
+    try std.testing.expectEqual(@as(usize, 1), vite.len);
+    try std.testing.expectEqualStrings("web", vite[0].label);
+
+    const jobs = try filteredProcesses(std.testing.allocator, &snapshot, "jobs", false);
+    defer std.testing.allocator.free(jobs);
+    try std.testing.expectEqual(@as(usize, 2), jobs.len);
+    try std.testing.expectEqualStrings("jobs-ui", jobs[0].label);
+    try std.testing.expectEqualStrings("worker", jobs[1].label);
+
+    snapshot.ui.layout.filter_fields = "name, categories";
+    const names_only = try filteredProcesses(std.testing.allocator, &snapshot, "vite", false);
+    defer std.testing.allocator.free(names_only);
+    try std.testing.expectEqual(@as(usize, 0), names_only.len);
+}
@@ -64,11 +64,12 @@ fn selectRunning(
 }
 
 fn matchesAllCategories(raw: []const u8, categories: []const []const u8) bool {
+    const effective: []const []const u8 = if (categories.len == 0) &.{config.schema.default_category} else categories;
     var parts = std.mem.splitScalar(u8, raw, ',');
     while (parts.next()) |part| {
         const wanted = std.mem.trim(u8, part, " \t\r\n");
         var found = false;
-        for (categories) |category| {
+        for (effective) |category| {
             if (fuzzyCategoryMatch(category, wanted)) {
                 found = true;
                 break;
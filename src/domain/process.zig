@@ -49,6 +49,8 @@ pub const Process = struct {
     id: ProcessId,
     label: []const u8,
     config: *config.schema.ProcessConfig,
+    /// Set by config reload while the running instance predates its new definition.
+    config_changed: bool = false,
 };
 
 pub const ProcessView = struct {
@@ -56,15 +58,35 @@ pub const ProcessView = struct {
     label: []const u8,
     status: ProcessStatus = .halted,
     pid: i32 = -1,
+    /// Exit status of a process that ended on its own, or 128 + signal.
+    exit_status: ?u32 = null,
+    /// Stopped by proctmux for running past `max_runtime_secs`.
+    timed_out: bool = false,
+    config_changed: bool = false,
     config: *config.schema.ProcessConfig,
 };
 
+/// Live facts about one process, read together so a snapshot sees one
+/// consistent state per process.
+pub const LiveState = struct {
+    status: ProcessStatus = .halted,
+    pid: i32 = -1,
+    exit_status: ?u32 = null,
+    timed_out: bool = false,
+};
+
 /// Narrow status adapter used by domain code that needs live process facts
 /// without depending on the concrete runtime controller.
 pub const ProcessController = struct {
     context: *anyopaque,
     get_process_status: *const fn (context: *anyopaque, id: ProcessId) ProcessStatus,
     get_pid: *const fn (context: *anyopaque, id: ProcessId) i32,
+    get_exit_status: *const fn (context: *anyopaque, id: ProcessId) ?u32,
+    get_timed_out: *const fn (context: *anyopaque, id: ProcessId) bool,
+    /// Most recent output line; adapters without output leave it unset.
+    get_last_line: ?*const fn (context: *anyopaque, id: ProcessId) []const u8 = null,
+    /// Reads every id in one call; adapters without it are asked field by field.
+    get_states: ?*const fn (context: *anyopaque, ids: []const ProcessId, out: []LiveState) void = null,
 
     pub fn getProcessStatus(self: ProcessController, id: ProcessId) ProcessStatus {
         return self.get_process_status(self.context, id);
@@ -73,17 +95,50 @@ pub const ProcessController = struct {
     pub fn getPID(self: ProcessController, id: ProcessId) i32 {
         return self.get_pid(self.context, id);
     }
+
+    pub fn getExitStatus(self: ProcessController, id: ProcessId) ?u32 {
+        return self.get_exit_status(self.context, id);
+    }
+
+    pub fn getTimedOut(self: ProcessController, id: ProcessId) bool {
+        return self.get_timed_out(self.context, id);
+    }
+
+    pub fn getLastLine(self: ProcessController, id: ProcessId) []const u8 {
+        const get = self.get_last_line orelse return "";
+        return get(self.context, id);
+    }
+
+    /// Fills `out[i]` with the live state of `ids[i]`.
+    pub fn getStates(self: ProcessController, ids: []const ProcessId, out: []LiveState) void {
+        std.debug.assert(ids.len == out.len);
+        if (self.get_states) |get| return get(self.context, ids, out);
+        for (ids, out) |id, *live| live.* = .{
+            .status = self.getProcessStatus(id),
+            .pid = self.getPID(id),
+            .exit_status = self.getExitStatus(id),
+            .timed_out = self.getTimedOut(id),
+        };
+    }
 };
 
 /// Combines static process config with optional live controller-derived status.
 pub fn toView(proc: Process, controller: ?ProcessController) ProcessView {
-    const status = if (controller) |ctl| ctl.getProcessStatus(proc.id) else ProcessStatus.halted;
-    const pid = if (controller) |ctl| ctl.getPID(proc.id) else -1;
+    var live = [_]LiveState{.{}};
+    if (controller) |ctl| ctl.getStates(&.{proc.id}, &live);
+    return viewWithState(proc, live[0]);
+}
+
+/// Combines static process config with state already read from a controller.
+pub fn viewWithState(proc: Process, live: LiveState) ProcessView {
     return .{
         .id = proc.id,
         .label = proc.label,
-        .status = status,
-        .pid = pid,
+        .status = live.status,
+        .pid = live.pid,
+        .exit_status = live.exit_status,
+        .timed_out = live.timed_out,
+        .config_changed = proc.config_changed,
         .config = proc.config,
     };
 }
@@ -115,6 +115,29 @@ test "category filter uses AND matching and running-only toggle" {
     try std.testing.expectEqualStrings("backend", running[0].label);
 }
 
+test "category filter puts processes without categories in the default bucket" {
+    var api_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer api_cfg.deinit(std.testing.allocator);
+    try config.schema.appendOwned(std.testing.allocator, &api_cfg.categories, "backend");
+
+    var plain_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer plain_cfg.deinit(std.testing.allocator);
+
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+
+    var views = [_]process.ProcessView{
+        .{ .id = process.ProcessId.fromInt(1), .label = "api", .status = .halted, .config = &api_cfg },
+        .{ .id = process.ProcessId.fromInt(2), .label = "scratch", .status = .halted, .config = &plain_cfg },
+    };
+
+    const result = try filter.filterProcesses(std.testing.allocator, &cfg, views[0..], "cat:uncategorized", false);
+    defer std.testing.allocator.free(result);
+    try std.testing.expectEqual(@as(usize, 1), result.len);
+    try std.testing.expectEqualStrings("scratch", result[0].label);
+}
+
 test "sort running first then alpha" {
     var cfg = config.schema.Config.empty(std.testing.allocator);
     defer cfg.deinit();
@@ -171,6 +194,8 @@ const FakeController = struct {
             .context = self,
             .get_process_status = getProcessStatus,
             .get_pid = getPID,
+            .get_exit_status = getExitStatus,
+            .get_timed_out = getTimedOut,
         };
     }
 
@@ -183,4 +208,12 @@ const FakeController = struct {
         const self: *FakeController = @ptrCast(@alignCast(context));
         return self.pid;
     }
+
+    fn getExitStatus(_: *anyopaque, _: process.ProcessId) ?u32 {
+        return null;
+    }
+
+    fn getTimedOut(_: *anyopaque, _: process.ProcessId) bool {
+        return false;
+    }
 };
@@ -2,11 +2,18 @@
 //! The client buffers interleaved Snapshot and Response messages so TUI sessions can match command responses without losing the latest server snapshot.
 
 const std = @import("std");
+const config = @import("../config/root.zig");
 const line_io = @import("line.zig");
 const protocol = @import("protocol.zig");
 
 const max_response_line = 1024 * 1024;
-const default_response_timeout_ms = 5000;
+pub const default_response_timeout_ms = 5000;
+
+/// The configured `ipc_timeout_ms`, or the default when it is unset.
+pub fn responseTimeoutMs(cfg: *const config.schema.Config) i32 {
+    if (cfg.ipc_timeout_ms > 0) return cfg.ipc_timeout_ms;
+    return default_response_timeout_ms;
+}
 
 /// Persistent client connection used by interactive TUI sessions. It preserves
 /// snapshots seen while waiting for command responses so UI state is never lost
@@ -17,6 +24,8 @@ pub const Client = struct {
     next_request_id: u64 = 1,
     closed: bool = false,
     pending_snapshot: ?protocol.SnapshotUpdate = null,
+    /// Bounds every blocking read, so a wedged server surfaces as
+    /// `error.CommandTimeout` instead of freezing the UI.
     response_timeout_ms: i32 = default_response_timeout_ms,
     read_buffer: std.array_list.Managed(u8),
 
@@ -40,6 +49,20 @@ pub const Client = struct {
         self.stream.close();
     }
 
+    /// False once the primary server has closed its end of the socket, so
+    /// callers can shut down cleanly instead of failing on the next read.
+    pub fn isAlive(self: *Client) bool {
+        if (self.closed) return false;
+        var poll_fds = [_]std.posix.pollfd{.{
+            .fd = self.stream.handle,
+            .events = std.posix.POLL.IN,
+            .revents = 0,
+        }};
+        _ = std.posix.poll(&poll_fds, 0) catch return false;
+        const gone = std.posix.POLL.HUP | std.posix.POLL.ERR | std.posix.POLL.NVAL;
+        return (poll_fds[0].revents & gone) == 0;
+    }
+
     /// Sends a Process Command and returns the request id the caller should use
     /// to match the eventual response.
     pub fn sendCommand(self: *Client, action: protocol.Command, label: []const u8) !u64 {
@@ -54,6 +77,19 @@ pub const Client = struct {
         return request_id;
     }
 
+    /// Sends `input` to the terminal of the process named `label`; the response
+    /// is matched like any other command.
+    pub fn sendInput(self: *Client, label: []const u8, input: []const u8) !u64 {
+        const request_id = self.next_request_id;
+        self.next_request_id += 1;
+
+        const request = try protocol.inputRequestLine(self.allocator, request_id, label, input);
+        defer self.allocator.free(request);
+        try self.stream.writeAll(request);
+
+        return request_id;
+    }
+
     pub fn readSnapshot(self: *Client) !protocol.SnapshotUpdate {
         if (self.pending_snapshot) |*snapshot| {
             const pending = snapshot.*;
@@ -62,7 +98,7 @@ pub const Client = struct {
         }
 
         while (true) {
-            const line = try self.readLineBlocking();
+            const line = try self.readLineWithTimeout(self.response_timeout_ms);
             defer self.allocator.free(line);
 
             var message = try protocol.decodeLine(self.allocator, line);
@@ -133,13 +169,6 @@ pub const Client = struct {
         return null;
     }
 
-    fn readLineBlocking(self: *Client) ![]const u8 {
-        while (true) {
-            if (try self.takeBufferedLine()) |line| return line;
-            try self.readOneByte();
-        }
-    }
-
     fn readLineWithTimeout(self: *Client, timeout_ms: i32) ![]const u8 {
         while (true) {
             if (try self.takeBufferedLine()) |line| return line;
@@ -227,9 +256,11 @@ pub const Client = struct {
 pub fn readInitialSnapshotFromPath(
     allocator: std.mem.Allocator,
     socket_path: []const u8,
+    response_timeout_ms: i32,
 ) !protocol.SnapshotUpdate {
     var client = try Client.connect(allocator, socket_path);
     defer client.deinit();
+    client.response_timeout_ms = response_timeout_ms;
     return client.readSnapshot();
 }
 
@@ -0,0 +1,257 @@
+//! Plain-text control socket for `control_socket`.
+//! Each line is a word command such as `start api` or `list`; replies are `ok`/`error <message>` status lines so scripts can drive proctmux with `nc -U`.
+
+const std = @import("std");
+const interfaces = @import("interfaces.zig");
+const protocol = @import("protocol.zig");
+const server = @import("server.zig");
+
+const log = std.log.scoped(.ipc_control);
+
+const max_line = 4096;
+const poll_ms = 200;
+const idle_close_ms = 1000;
+
+pub const usage = "commands: start <name>, stop <name>, restart <name>, switch <name>, restart-running, stop-running, start-all, stop-all, clear-output <name>, copy-output <name>, reload, list";
+
+pub const ControlCommand = union(enum) {
+    command: struct {
+        action: protocol.Command,
+        target: ?[]const u8 = null,
+    },
+    list,
+};
+
+/// Parses one control line. Returned targets borrow from `line`.
+pub fn parseLine(line: []const u8) !ControlCommand {
+    var words = std.mem.tokenizeAny(u8, line, " \t\r\n");
+    const verb = words.next() orelse return error.EmptyCommand;
+    const target = words.next();
+    if (words.next() != null) return error.TooManyArguments;
+
+    if (std.mem.eql(u8, verb, "list")) {
+        if (target != null) return error.TooManyArguments;
+        return .list;
+    }
+
+    const action = actionFromVerb(verb) orelse return error.UnknownCommand;
+    if (protocol.commandRequiresTarget(action)) {
+        if (target == null) return error.MissingProcessName;
+    } else if (target != null) {
+        return error.TooManyArguments;
+    }
+    return .{ .command = .{ .action = action, .target = target } };
+}
+
+fn actionFromVerb(verb: []const u8) ?protocol.Command {
+    if (std.mem.eql(u8, verb, "restart-running")) return .restart_running;
+    if (std.mem.eql(u8, verb, "stop-running")) return .stop_running;
+    if (std.mem.eql(u8, verb, "start-all")) return .start_all;
+    if (std.mem.eql(u8, verb, "stop-all")) return .stop_all;
+    if (std.mem.eql(u8, verb, "clear-output")) return .clear_output;
+    if (std.mem.eql(u8, verb, "copy-output")) return .copy_output;
+    const action = protocol.commandFromName(verb) catch return null;
+    // Input text does not fit the one-word target grammar.
+    if (action == .send_input) return null;
+    return action;
+}
+
+/// Serves the control socket until `stopped` is raised. Each connection runs
+/// on its own thread, so a slow client never blocks the others; callers unblock
+/// `accept` by connecting after setting `stopped`, the same way the snapshot
+/// server is shut down.
+pub fn serveAtPath(
+    allocator: std.mem.Allocator,
+    socket_path: []const u8,
+    handler: interfaces.CommandHandler,
+    snapshot_provider: interfaces.SnapshotProvider,
+    stopped: *std.atomic.Value(bool),
+) !void {
+    var listener = try server.listenAtSocketPath(socket_path);
+    defer listener.deinit();
+    defer std.fs.deleteFileAbsolute(socket_path) catch {};
+
+    var shared = Shared{
+        .allocator = allocator,
+        .handler = handler,
+        .snapshot_provider = snapshot_provider,
+        .stopped = stopped,
+    };
+    // Connection threads poll `stopped`, so they wind down within `poll_ms`.
+    defer {
+        while (shared.active.load(.seq_cst) > 0) std.Thread.sleep(poll_ms * std.time.ns_per_ms);
+    }
+
+    const authorizer = server.defaultPeerAuthorizer();
+    while (!stopped.load(.seq_cst)) {
+        const conn = listener.accept() catch |err| {
+            if (stopped.load(.seq_cst)) break;
+            return err;
+        };
+        if (stopped.load(.seq_cst)) {
+            conn.stream.close();
+            break;
+        }
+        authorizer.authorizeStream(conn.stream) catch {
+            conn.stream.close();
+            continue;
+        };
+
+        _ = shared.active.fetchAdd(1, .seq_cst);
+        const thread = std.Thread.spawn(.{}, connectionThread, .{ &shared, conn.stream }) catch |err| {
+            _ = shared.active.fetchSub(1, .seq_cst);
+            conn.stream.close();
+            log.warn("control connection dropped: {s}", .{@errorName(err)});
+            continue;
+        };
+        thread.detach();
+    }
+}
+
+const Shared = struct {
+    allocator: std.mem.Allocator,
+    handler: interfaces.CommandHandler,
+    snapshot_provider: interfaces.SnapshotProvider,
+    stopped: *std.atomic.Value(bool),
+    request_id: std.atomic.Value(u64) = std.atomic.Value(u64).init(0),
+    active: std.atomic.Value(usize) = std.atomic.Value(usize).init(0),
+};
+
+fn connectionThread(shared: *Shared, stream: std.net.Stream) void {
+    defer _ = shared.active.fetchSub(1, .seq_cst);
+    defer stream.close();
+    serveConnection(shared, stream) catch |err| {
+        log.debug("control connection ended: {s}", .{@errorName(err)});
+    };
+}
+
+/// Answers lines until the client closes, or until every line has been
+/// answered and the connection sits idle for `idle_close_ms`. The idle close
+/// lets `printf ... | nc -U` exit without waiting on the server.
+fn serveConnection(shared: *Shared, stream: std.net.Stream) !void {
+    var pending = std.array_list.Managed(u8).init(shared.allocator);
+    defer pending.deinit();
+
+    var answered = false;
+    var idle_ms: u32 = 0;
+    while (!shared.stopped.load(.seq_cst)) {
+        var poll_fds = [_]std.posix.pollfd{.{
+            .fd = stream.handle,
+            .events = std.posix.POLL.IN,
+            .revents = 0,
+        }};
+        if (try std.posix.poll(&poll_fds, poll_ms) == 0) {
+            idle_ms += poll_ms;
+            if (answered and pending.items.len == 0 and idle_ms >= idle_close_ms) return;
+            continue;
+        }
+        idle_ms = 0;
+
+        var byte: [1]u8 = undefined;
+        if (try stream.read(&byte) == 0) return;
+        if (byte[0] != '\n') {
+            if (pending.items.len >= max_line) {
+                try stream.writeAll("error line too long\n");
+                return error.LineTooLong;
+            }
+            try pending.append(byte[0]);
+            continue;
+        }
+
+        defer pending.clearRetainingCapacity();
+        if (std.mem.trim(u8, pending.items, " \t\r").len == 0) continue;
+        const request_id = shared.request_id.fetchAdd(1, .seq_cst) + 1;
+        const reply = try replyForLine(shared.allocator, pending.items, shared.handler, shared.snapshot_provider, request_id);
+        defer shared.allocator.free(reply);
+        try stream.writeAll(reply);
+        answered = true;
+    }
+}
+
+/// Runs one control line and returns the full reply text, including the final
+/// `ok` or `error` status line.
+pub fn replyForLine(
+    allocator: std.mem.Allocator,
+    line: []const u8,
+    handler: interfaces.CommandHandler,
+    snapshot_provider: interfaces.SnapshotProvider,
+    request_id: u64,
+) ![]const u8 {
+    const command = parseLine(line) catch |err| {
+        return std.fmt.allocPrint(allocator, "error {s}; {s}\n", .{ parseErrorMessage(err), usage });
+    };
+
+    switch (command) {
+        .list => return listReply(allocator, snapshot_provider) catch |err| {
+            return std.fmt.allocPrint(allocator, "error {s}\n", .{@errorName(err)});
+        },
+        .command => |request| {
+            var response = handler.handleCommand(allocator, .{
+                .request_id = request_id,
+                .action = request.action,
+                .target = request.target,
+            }) catch |err| {
+                return std.fmt.allocPrint(allocator, "error {s}\n", .{@errorName(err)});
+            };
+            defer response.deinit(allocator);
+            if (response.success and response.message.len > 0) return std.fmt.allocPrint(allocator, "ok {s}\n", .{response.message});
+            if (response.success) return allocator.dupe(u8, "ok\n");
+            return std.fmt.allocPrint(allocator, "error {s}\n", .{response.error_message});
+        },
+    }
+}
+
+fn listReply(allocator: std.mem.Allocator, snapshot_provider: interfaces.SnapshotProvider) ![]const u8 {
+    const snapshot_line = try snapshot_provider.snapshotLine(allocator);
+    defer allocator.free(snapshot_line);
+    var update = try protocol.parseSnapshotLine(allocator, snapshot_line);
+    defer update.deinit();
+
+    var out = std.array_list.Managed(u8).init(allocator);
+    errdefer out.deinit();
+    for (update.snapshot().processes) |process| {
+        try out.writer().print("{s}\t{s}\n", .{
+            process.label,
+            if (process.status == .running) "running" else "stopped",
+        });
+    }
+    try out.appendSlice("ok\n");
+    return out.toOwnedSlice();
+}
+
+fn parseErrorMessage(err: anyerror) []const u8 {
+    return switch (err) {
+        error.EmptyCommand => "empty command",
+        error.UnknownCommand => "unknown command",
+        error.MissingProcessName => "missing process name",
+        error.TooManyArguments => "too many arguments",
+        else => @errorName(err),
+    };
+}
+
+test "control lines parse into process commands" {
+    const start = try parseLine("start api\n");
+    try std.testing.expectEqual(protocol.Command.start, start.command.action);
+    try std.testing.expectEqualStrings("api", start.command.target.?);
+
+    const stop_all = try parseLine("stop-running");
+    try std.testing.expectEqual(protocol.Command.stop_running, stop_all.command.action);
+    try std.testing.expect(stop_all.command.target == null);
+
+    const start_all = try parseLine("start-all");
+    try std.testing.expectEqual(protocol.Command.start_all, start_all.command.action);
+
+    const reload = try parseLine("reload");
+    try std.testing.expectEqual(protocol.Command.reload, reload.command.action);
+
+    try std.testing.expect((try parseLine("  list \r\n")) == .list);
+}
+
+test "control lines reject malformed commands" {
+    try std.testing.expectError(error.EmptyCommand, parseLine(" \n"));
+    try std.testing.expectError(error.UnknownCommand, parseLine("launch api"));
+    try std.testing.expectError(error.MissingProcessName, parseLine("restart"));
+    try std.testing.expectError(error.TooManyArguments, parseLine("start api worker"));
+    try std.testing.expectError(error.TooManyArguments, parseLine("list api"));
+    try std.testing.expectError(error.TooManyArguments, parseLine("stop-running api"));
+}
@@ -0,0 +1,147 @@
+//! Read-only HTTP status endpoint for `http_status_addr`.
+//! A minimal HTTP/1.1 responder serves `GET /status` as the `signal-list --json` array so dashboards can poll proctmux without speaking the socket protocol.
+
+const std = @import("std");
+const domain = @import("../domain/root.zig");
+const interfaces = @import("interfaces.zig");
+const protocol = @import("protocol.zig");
+const test_ipc = @import("../test_support/ipc.zig");
+
+const log = std.log.scoped(.ipc_http_status);
+
+const max_request_head = 8192;
+const read_timeout_ms = 1000;
+
+/// Parses `host:port` with a literal IP host; IPv6 hosts are bracketed, as
+/// in `[::1]:8080`.
+pub fn parseAddress(text: []const u8) !std.net.Address {
+    const colon = std.mem.lastIndexOfScalar(u8, text, ':') orelse return error.InvalidStatusAddress;
+    var host = text[0..colon];
+    if (host.len >= 2 and host[0] == '[' and host[host.len - 1] == ']') host = host[1 .. host.len - 1];
+    const port = std.fmt.parseUnsigned(u16, text[colon + 1 ..], 10) catch return error.InvalidStatusAddress;
+    return std.net.Address.parseIp(host, port) catch return error.InvalidStatusAddress;
+}
+
+pub fn listen(address_text: []const u8) !std.net.Server {
+    const address = try parseAddress(address_text);
+    return address.listen(.{ .reuse_address = true });
+}
+
+/// Answers requests one connection at a time until `stopped` is raised.
+/// Callers unblock `accept` with `unblock` after setting `stopped`.
+pub fn serve(
+    allocator: std.mem.Allocator,
+    listener: *std.net.Server,
+    snapshot_provider: interfaces.SnapshotProvider,
+    stopped: *std.atomic.Value(bool),
+) void {
+    while (!stopped.load(.seq_cst)) {
+        const conn = listener.accept() catch |err| {
+            if (!stopped.load(.seq_cst)) log.warn("status endpoint stopped: {s}", .{@errorName(err)});
+            return;
+        };
+        defer conn.stream.close();
+        if (stopped.load(.seq_cst)) return;
+
+        serveConnection(allocator, conn.stream, snapshot_provider) catch |err| {
+            log.debug("status connection ended: {s}", .{@errorName(err)});
+        };
+    }
+}
+
+/// Connects once so a blocked `accept` returns and `serve` sees `stopped`.
+pub fn unblock(listener: *const std.net.Server) void {
+    var stream = std.net.tcpConnectToAddress(listener.listen_address) catch return;
+    stream.close();
+}
+
+fn serveConnection(
+    allocator: std.mem.Allocator,
+    stream: std.net.Stream,
+    snapshot_provider: interfaces.SnapshotProvider,
+) !void {
+    var head: [max_request_head]u8 = undefined;
+    var len: usize = 0;
+    while (std.mem.indexOf(u8, head[0..len], "\r\n\r\n") == null) {
+        if (len == head.len) return error.RequestTooLarge;
+        var poll_fds = [_]std.posix.pollfd{.{
+            .fd = stream.handle,
+            .events = std.posix.POLL.IN,
+            .revents = 0,
+        }};
+        if (try std.posix.poll(&poll_fds, read_timeout_ms) == 0) return error.RequestTimeout;
+        const read = try stream.read(head[len..]);
+        if (read == 0) return error.ConnectionClosed;
+        len += read;
+    }
+
+    const reply = try responseFor(allocator, head[0..len], snapshot_provider);
+    defer allocator.free(reply);
+    try stream.writeAll(reply);
+}
+
+/// Builds the full HTTP response for one request head. Only `GET /status`
+/// succeeds; a query string is ignored.
+pub fn responseFor(
+    allocator: std.mem.Allocator,
+    request_head: []const u8,
+    snapshot_provider: interfaces.SnapshotProvider,
+) ![]u8 {
+    const line_end = std.mem.indexOf(u8, request_head, "\r\n") orelse request_head.len;
+    var parts = std.mem.tokenizeScalar(u8, request_head[0..line_end], ' ');
+    const method = parts.next() orelse "";
+    const target = parts.next() orelse "";
+    const path = target[0 .. std.mem.indexOfScalar(u8, target, '?') orelse target.len];
+
+    if (!std.mem.eql(u8, path, "/status")) return response(allocator, "404 Not Found", "text/plain", "not found\n");
+    if (!std.mem.eql(u8, method, "GET")) return response(allocator, "405 Method Not Allowed", "text/plain", "use GET\n");
+
+    const body = statusJson(allocator, snapshot_provider) catch |err| {
+        log.warn("status endpoint could not read the snapshot: {s}", .{@errorName(err)});
+        return response(allocator, "500 Internal Server Error", "text/plain", "snapshot unavailable\n");
+    };
+    defer allocator.free(body);
+    return response(allocator, "200 OK", "application/json", body);
+}
+
+fn statusJson(allocator: std.mem.Allocator, snapshot_provider: interfaces.SnapshotProvider) ![]u8 {
+    const snapshot_line = try snapshot_provider.snapshotLine(allocator);
+    defer allocator.free(snapshot_line);
+    var update = try protocol.parseSnapshotLine(allocator, snapshot_line);
+    defer update.deinit();
+    return domain.client_snapshot.formatProcessListJson(allocator, update.snapshot());
+}
+
+fn response(allocator: std.mem.Allocator, status: []const u8, content_type: []const u8, body: []const u8) ![]u8 {
+    return std.fmt.allocPrint(
+        allocator,
+        "HTTP/1.1 {s}\r\nContent-Type: {s}\r\nContent-Length: {d}\r\nConnection: close\r\n\r\n{s}",
+        .{ status, content_type, body.len, body },
+    );
+}
+
+test "status endpoint serves the process list json and rejects other requests" {
+    var provider = test_ipc.FakeSnapshotProvider{ .line = test_ipc.apiWorkerSnapshotLine };
+
+    const ok = try responseFor(std.testing.allocator, "GET /status?verbose=1 HTTP/1.1\r\nHost: x\r\n\r\n", provider.provider());
+    defer std.testing.allocator.free(ok);
+    try std.testing.expect(std.mem.startsWith(u8, ok, "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n"));
+    try std.testing.expect(std.mem.endsWith(u8, ok, "\r\n\r\n[{\"name\":\"api\",\"id\":1,\"status\":\"running\",\"running\":true,\"pid\":123,\"description\":null}," ++
+        "{\"name\":\"worker\",\"id\":2,\"status\":\"stopped\",\"running\":false,\"pid\":null,\"description\":null}]\n"));
+
+    const missing = try responseFor(std.testing.allocator, "GET / HTTP/1.1\r\n\r\n", provider.provider());
+    defer std.testing.allocator.free(missing);
+    try std.testing.expect(std.mem.startsWith(u8, missing, "HTTP/1.1 404 Not Found\r\n"));
+
+    const post = try responseFor(std.testing.allocator, "POST /status HTTP/1.1\r\n\r\n", provider.provider());
+    defer std.testing.allocator.free(post);
+    try std.testing.expect(std.mem.startsWith(u8, post, "HTTP/1.1 405 Method Not Allowed\r\n"));
+}
+
+test "status addresses need a literal ip and port" {
+    try std.testing.expectEqual(@as(u16, 8080), (try parseAddress("127.0.0.1:8080")).getPort());
+    try std.testing.expectEqual(@as(u16, 9000), (try parseAddress("[::1]:9000")).getPort());
+    try std.testing.expectError(error.InvalidStatusAddress, parseAddress("localhost:8080"));
+    try std.testing.expectError(error.InvalidStatusAddress, parseAddress("127.0.0.1"));
+    try std.testing.expectError(error.InvalidStatusAddress, parseAddress("127.0.0.1:http"));
+}
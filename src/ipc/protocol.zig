@@ -4,7 +4,7 @@
 const std = @import("std");
 const domain = @import("../domain/root.zig");
 
-pub const current_protocol_version: u32 = 1;
+pub const current_protocol_version: u32 = 2;
 
 pub const CommandNameError = error{UnknownCommand};
 pub const DecodeError = error{
@@ -23,6 +23,12 @@ pub const Command = enum {
     switch_process,
     restart_running,
     stop_running,
+    send_input,
+    reload,
+    start_all,
+    stop_all,
+    clear_output,
+    copy_output,
 };
 
 /// Wire command request after decoding. `target` is optional because bulk
@@ -31,6 +37,8 @@ pub const CommandRequest = struct {
     request_id: u64,
     action: Command,
     target: ?[]const u8 = null,
+    /// Bytes written to the target's terminal by `send_input`.
+    input: ?[]const u8 = null,
 
     pub fn targetLabel(self: CommandRequest) []const u8 {
         return self.target orelse "";
@@ -45,9 +53,12 @@ pub const Response = struct {
     request_id: u64,
     success: bool,
     error_message: []const u8,
+    /// Optional note for a successful command, shown to the user as is.
+    message: []const u8 = "",
 
     pub fn deinit(self: *const Response, allocator: std.mem.Allocator) void {
         allocator.free(self.error_message);
+        allocator.free(self.message);
     }
 };
 
@@ -117,6 +128,7 @@ const CommandMessage = struct {
     request_id: u64,
     action: []const u8,
     target: ?[]const u8 = null,
+    input: ?[]const u8 = null,
 };
 
 const ResponseMessage = struct {
@@ -125,6 +137,7 @@ const ResponseMessage = struct {
     request_id: u64,
     success: bool,
     @"error": []const u8 = "",
+    message: []const u8 = "",
 };
 
 pub fn commandName(command: Command) []const u8 {
@@ -135,6 +148,12 @@ pub fn commandName(command: Command) []const u8 {
         .switch_process => "switch",
         .restart_running => "restart_running",
         .stop_running => "stop_running",
+        .send_input => "send_input",
+        .reload => "reload",
+        .start_all => "start_all",
+        .stop_all => "stop_all",
+        .clear_output => "clear_output",
+        .copy_output => "copy_output",
     };
 }
 
@@ -145,20 +164,26 @@ pub fn commandFromName(name: []const u8) CommandNameError!Command {
     if (std.mem.eql(u8, name, "switch")) return .switch_process;
     if (std.mem.eql(u8, name, "restart_running")) return .restart_running;
     if (std.mem.eql(u8, name, "stop_running")) return .stop_running;
+    if (std.mem.eql(u8, name, "send_input")) return .send_input;
+    if (std.mem.eql(u8, name, "reload")) return .reload;
+    if (std.mem.eql(u8, name, "start_all")) return .start_all;
+    if (std.mem.eql(u8, name, "stop_all")) return .stop_all;
+    if (std.mem.eql(u8, name, "clear_output")) return .clear_output;
+    if (std.mem.eql(u8, name, "copy_output")) return .copy_output;
     return error.UnknownCommand;
 }
 
 pub fn commandRequiresTarget(command: Command) bool {
     return switch (command) {
-        .start, .stop, .restart, .switch_process => true,
-        .restart_running, .stop_running => false,
+        .start, .stop, .restart, .switch_process, .send_input, .clear_output, .copy_output => true,
+        .restart_running, .stop_running, .reload, .start_all, .stop_all => false,
     };
 }
 
 pub fn commandRequiresSelectedProcess(command: Command) bool {
     return switch (command) {
-        .start, .stop, .restart => true,
-        .switch_process, .restart_running, .stop_running => false,
+        .start, .stop, .restart, .send_input, .clear_output, .copy_output => true,
+        .switch_process, .restart_running, .stop_running, .reload, .start_all, .stop_all => false,
     };
 }
 
@@ -166,13 +191,13 @@ pub fn commandRequiresSelectedProcess(command: Command) bool {
 /// the TUI reflects start/stop/restart results without waiting for polling.
 pub fn commandNeedsImmediateSnapshotSync(command: Command) bool {
     return switch (command) {
-        .start, .stop, .restart, .restart_running => true,
-        .switch_process, .stop_running => false,
+        .start, .stop, .restart, .restart_running, .reload, .start_all, .stop_all => true,
+        .switch_process, .stop_running, .send_input, .clear_output, .copy_output => false,
     };
 }
 
 pub fn commandShouldRenderImmediately(command: Command) bool {
-    return command == .switch_process;
+    return command == .switch_process or command == .clear_output or command == .copy_output;
 }
 
 /// Decodes one complete JSON line. The protocol is strict about unknown fields
@@ -223,6 +248,21 @@ pub fn commandRequestLine(
     });
 }
 
+/// Encodes a `send_input` command carrying `input` for the `target` process.
+pub fn inputRequestLine(
+    allocator: std.mem.Allocator,
+    request_id: u64,
+    target: []const u8,
+    input: []const u8,
+) EncodeError![]const u8 {
+    return jsonLine(allocator, CommandMessage{
+        .request_id = request_id,
+        .action = commandName(.send_input),
+        .target = target,
+        .input = input,
+    });
+}
+
 pub fn parseCommandRequestLine(allocator: std.mem.Allocator, line: []const u8) DecodeError!CommandRequest {
     try validateHeader(allocator, line, .command);
     var parsed = try std.json.parseFromSlice(CommandMessage, allocator, line, .{
@@ -235,11 +275,14 @@ pub fn parseCommandRequestLine(allocator: std.mem.Allocator, line: []const u8) D
 
     const target = if (parsed.value.target) |value| try allocator.dupe(u8, value) else null;
     errdefer if (target) |value| allocator.free(value);
+    const input = if (parsed.value.input) |value| try allocator.dupe(u8, value) else null;
+    errdefer if (input) |value| allocator.free(value);
 
     return .{
         .request_id = parsed.value.request_id,
         .action = try commandFromName(parsed.value.action),
         .target = target,
+        .input = input,
     };
 }
 
@@ -248,6 +291,7 @@ pub fn responseLine(allocator: std.mem.Allocator, response: Response) EncodeErro
         .request_id = response.request_id,
         .success = response.success,
         .@"error" = response.error_message,
+        .message = response.message,
     });
 }
 
@@ -261,15 +305,19 @@ pub fn parseResponseLine(allocator: std.mem.Allocator, line: []const u8) DecodeE
     if (!std.mem.eql(u8, parsed.value.type, "response")) return error.InvalidMessageType;
     if (parsed.value.protocol_version != current_protocol_version) return error.UnsupportedProtocolVersion;
 
+    const error_message = try allocator.dupe(u8, parsed.value.@"error");
+    errdefer allocator.free(error_message);
     return .{
         .request_id = parsed.value.request_id,
         .success = parsed.value.success,
-        .error_message = try allocator.dupe(u8, parsed.value.@"error"),
+        .error_message = error_message,
+        .message = try allocator.dupe(u8, parsed.value.message),
     };
 }
 
 pub fn deinitCommandRequest(allocator: std.mem.Allocator, request: CommandRequest) void {
     if (request.target) |target| allocator.free(target);
+    if (request.input) |input| allocator.free(input);
 }
 
 fn jsonLine(allocator: std.mem.Allocator, value: anytype) EncodeError![]const u8 {
@@ -317,7 +365,7 @@ test "protocol encodes and decodes snapshot messages" {
     const line = try snapshotLine(std.testing.allocator, &snapshot);
     defer std.testing.allocator.free(line);
 
-    try std.testing.expect(std.mem.startsWith(u8, line, "{\"type\":\"snapshot\",\"protocol_version\":1"));
+    try std.testing.expect(std.mem.startsWith(u8, line, "{\"type\":\"snapshot\",\"protocol_version\":2"));
     var parsed = try parseSnapshotLine(std.testing.allocator, line);
     defer parsed.deinit();
 
@@ -370,7 +418,7 @@ test "protocol encodes and decodes command requests" {
     defer std.testing.allocator.free(line);
 
     try std.testing.expectEqualStrings(
-        "{\"type\":\"command\",\"protocol_version\":1,\"request_id\":42,\"action\":\"start\",\"target\":\"api\"}\n",
+        "{\"type\":\"command\",\"protocol_version\":2,\"request_id\":42,\"action\":\"start\",\"target\":\"api\"}\n",
         line,
     );
 
@@ -381,12 +429,23 @@ test "protocol encodes and decodes command requests" {
     try std.testing.expectEqualStrings("api", parsed.target.?);
 }
 
+test "protocol carries send_input text with its target" {
+    const line = try inputRequestLine(std.testing.allocator, 5, "repl", "y; echo \"ok\"\r");
+    defer std.testing.allocator.free(line);
+
+    const parsed = try parseCommandRequestLine(std.testing.allocator, line);
+    defer deinitCommandRequest(std.testing.allocator, parsed);
+    try std.testing.expectEqual(Command.send_input, parsed.action);
+    try std.testing.expectEqualStrings("repl", parsed.target.?);
+    try std.testing.expectEqualStrings("y; echo \"ok\"\r", parsed.input.?);
+}
+
 test "protocol encodes targetless commands without null target" {
     const line = try commandRequestLine(std.testing.allocator, 7, .stop_running, null);
     defer std.testing.allocator.free(line);
 
     try std.testing.expectEqualStrings(
-        "{\"type\":\"command\",\"protocol_version\":1,\"request_id\":7,\"action\":\"stop_running\"}\n",
+        "{\"type\":\"command\",\"protocol_version\":2,\"request_id\":7,\"action\":\"stop_running\"}\n",
         line,
     );
 }
@@ -405,6 +464,18 @@ test "protocol encodes and decodes responses" {
     try std.testing.expectEqual(@as(u64, 99), parsed.request_id);
     try std.testing.expect(!parsed.success);
     try std.testing.expectEqualStrings("process not found: api", parsed.error_message);
+
+    const notice_line = try responseLine(std.testing.allocator, .{
+        .request_id = 100,
+        .success = true,
+        .error_message = "",
+        .message = "copied api output to the clipboard",
+    });
+    defer std.testing.allocator.free(notice_line);
+    var notice = try parseResponseLine(std.testing.allocator, notice_line);
+    defer notice.deinit(std.testing.allocator);
+    try std.testing.expect(notice.success);
+    try std.testing.expectEqualStrings("copied api output to the clipboard", notice.message);
 }
 
 test "protocol decodes any message through one interface" {
@@ -431,22 +502,36 @@ test "protocol rejects unsupported protocol versions unknown actions and unknown
             \\{"type":"command","protocol_version":999,"request_id":1,"action":"start","target":"api"}
         ),
     );
+    // Version 2 added `input` and `message`; a version 1 line is refused on
+    // its version before strict field parsing sees it.
+    try std.testing.expectError(
+        error.UnsupportedProtocolVersion,
+        parseCommandRequestLine(std.testing.allocator,
+            \\{"type":"command","protocol_version":1,"request_id":1,"action":"send_input","target":"api","input":"ls\n"}
+        ),
+    );
+    try std.testing.expectError(
+        error.UnsupportedProtocolVersion,
+        parseResponseLine(std.testing.allocator,
+            \\{"type":"response","protocol_version":1,"request_id":1,"success":true,"error":"","message":"copied"}
+        ),
+    );
     try std.testing.expectError(
         error.UnknownCommand,
         parseCommandRequestLine(std.testing.allocator,
-            \\{"type":"command","protocol_version":1,"request_id":1,"action":"dance","target":"api"}
+            \\{"type":"command","protocol_version":2,"request_id":1,"action":"dance","target":"api"}
         ),
     );
     try std.testing.expectError(
         error.InvalidMessageType,
         decodeLine(std.testing.allocator,
-            \\{"type":"event","protocol_version":1}
+            \\{"type":"event","protocol_version":2}
         ),
     );
     try std.testing.expectError(
         error.UnexpectedEndOfInput,
         decodeLine(std.testing.allocator,
-            \\{"type":"command","protocol_version":1,
+            \\{"type":"command","protocol_version":2,
         ),
     );
 }
@@ -1,5 +1,5 @@
 //! IPC namespace.
-//! Runtime modules import this root to access protocol, socket, client, server, and testable IPC interfaces through one stable seam.
+//! Runtime modules import this root to access protocol, socket, client, server, control socket, and testable IPC interfaces through one stable seam.
 
 pub const protocol = @import("protocol.zig");
 pub const interfaces = @import("interfaces.zig");
@@ -8,6 +8,8 @@ pub const socket = @import("socket.zig");
 pub const client = @import("client.zig");
 pub const server = @import("server.zig");
 pub const snapshot_broadcaster = @import("snapshot_broadcaster.zig");
+pub const control = @import("control.zig");
+pub const http_status = @import("http_status.zig");
 
 test {
     _ = protocol;
@@ -17,5 +19,7 @@ test {
     _ = client;
     _ = server;
     _ = snapshot_broadcaster;
+    _ = control;
+    _ = http_status;
     _ = @import("tests.zig");
 }
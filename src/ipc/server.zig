@@ -163,7 +163,9 @@ fn serveSnapshotListener(
     }
 }
 
-fn listenAtSocketPath(socket_path: []const u8) !std.net.Server {
+/// Binds a fresh listener at `socket_path`, replacing any stale file, and
+/// restricts the socket to the current user.
+pub fn listenAtSocketPath(socket_path: []const u8) !std.net.Server {
     std.fs.deleteFileAbsolute(socket_path) catch |err| switch (err) {
         error.FileNotFound => {},
         else => return err,
@@ -357,7 +357,7 @@ test "snapshot client closes peer that disconnects before initial snapshot write
 }
 
 test "snapshot monitor broadcasts its first sampled snapshot" {
-    const snapshot_line = "{\"type\":\"snapshot\",\"protocol_version\":1,\"current_process_id\":0,\"exiting\":false,\"ui\":{},\"processes\":[]}\n";
+    const snapshot_line = "{\"type\":\"snapshot\",\"protocol_version\":2,\"current_process_id\":0,\"exiting\":false,\"ui\":{},\"processes\":[]}\n";
     var provider = StaticSnapshotProvider{ .line = snapshot_line };
     var stopped = std.atomic.Value(bool).init(false);
     var broadcaster = Broadcaster.init(
@@ -392,7 +392,7 @@ test "snapshot monitor broadcasts its first sampled snapshot" {
 }
 
 test "snapshot monitor does not echo snapshot already published except requester" {
-    const snapshot_line = "{\"type\":\"snapshot\",\"protocol_version\":1,\"current_process_id\":1,\"exiting\":false,\"ui\":{},\"processes\":[]}\n";
+    const snapshot_line = "{\"type\":\"snapshot\",\"protocol_version\":2,\"current_process_id\":1,\"exiting\":false,\"ui\":{},\"processes\":[]}\n";
     var provider = StaticSnapshotProvider{ .line = snapshot_line };
     var stopped = std.atomic.Value(bool).init(false);
     var broadcaster = Broadcaster.init(
@@ -449,7 +449,7 @@ test "snapshot monitor does not echo snapshot already published except requester
 }
 
 test "successful process command publishes snapshot and finished client is reaped" {
-    const snapshot_line = "{\"type\":\"snapshot\",\"protocol_version\":1,\"current_process_id\":1,\"exiting\":false,\"ui\":{},\"processes\":[]}\n";
+    const snapshot_line = "{\"type\":\"snapshot\",\"protocol_version\":2,\"current_process_id\":1,\"exiting\":false,\"ui\":{},\"processes\":[]}\n";
     var handler = SuccessCommandHandler{};
     var provider = StaticSnapshotProvider{ .line = snapshot_line };
     var stopped = std.atomic.Value(bool).init(false);
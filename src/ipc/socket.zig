@@ -1,10 +1,13 @@
 //! Project socket path lifecycle.
-//! The socket hash is derived from Project Config so clients find the right Primary Server without a global registry or user-supplied port.
+//! The socket hash is derived from the config file paths so clients find the right Primary Server without a global registry or user-supplied port.
 
 const std = @import("std");
 const config = @import("../config/root.zig");
 
+/// Uses the config file `socket_key` runtime loading recorded, or hashes
+/// `cfg` as it is for configs built in code.
 pub fn pathForConfig(allocator: std.mem.Allocator, cfg: *const config.schema.Config) ![]const u8 {
+    if (cfg.socket_key.len > 0) return std.fmt.allocPrint(allocator, "/tmp/proctmux-{s}.socket", .{cfg.socket_key});
     const hash = try config.hash.toHash(allocator, cfg);
     defer allocator.free(hash);
 
@@ -7,6 +7,8 @@ const config = @import("../config/root.zig");
 const protocol = @import("protocol.zig");
 const client = @import("client.zig");
 const server = @import("server.zig");
+const control = @import("control.zig");
+const line_io = @import("line.zig");
 const test_config = @import("../test_support/config.zig");
 const test_ipc = @import("../test_support/ipc.zig");
 
@@ -45,7 +47,7 @@ test "snapshotLine emits a minimal snapshot without process execution config" {
     const line = try protocol.snapshotLine(std.testing.allocator, initial_snapshot.view());
     defer std.testing.allocator.free(line);
 
-    try std.testing.expect(std.mem.startsWith(u8, line, "{\"type\":\"snapshot\",\"protocol_version\":1"));
+    try std.testing.expect(std.mem.startsWith(u8, line, "{\"type\":\"snapshot\",\"protocol_version\":2"));
     try std.testing.expect(std.mem.indexOf(u8, line, "\"env\"") == null);
     try std.testing.expect(std.mem.indexOf(u8, line, "\"shell\"") == null);
     try std.testing.expect(std.mem.indexOf(u8, line, "\"cmd\"") == null);
@@ -118,3 +120,174 @@ test "snapshot client reads initial snapshot" {
     try std.testing.expectEqual(@as(u32, 2), snapshot.current_process_id);
     try std.testing.expectEqualStrings("api", snapshot.processes[0].label);
 }
+
+test "client reads time out when the server never answers" {
+    const path = "/tmp/proctmux-zig-clean-ipc-timeout-test.socket";
+    std.fs.deleteFileAbsolute(path) catch {};
+    defer std.fs.deleteFileAbsolute(path) catch {};
+
+    const address = try std.net.Address.initUnix(path);
+    var listener = try address.listen(.{});
+    defer listener.deinit();
+
+    var ipc_client = try client.Client.connect(std.testing.allocator, path);
+    defer ipc_client.deinit();
+    ipc_client.response_timeout_ms = 50;
+
+    try std.testing.expectError(error.CommandTimeout, ipc_client.readSnapshot());
+    _ = try ipc_client.sendCommand(.start, "api");
+    try std.testing.expectError(error.CommandTimeout, ipc_client.readResponseFor(1));
+}
+
+test "client notices when the server closes its socket" {
+    const path = "/tmp/proctmux-zig-clean-ipc-alive-test.socket";
+    std.fs.deleteFileAbsolute(path) catch {};
+    defer std.fs.deleteFileAbsolute(path) catch {};
+
+    const address = try std.net.Address.initUnix(path);
+    var listener = try address.listen(.{});
+    defer listener.deinit();
+
+    var ipc_client = try client.Client.connect(std.testing.allocator, path);
+    defer ipc_client.deinit();
+    const conn = try listener.accept();
+    try std.testing.expect(ipc_client.isAlive());
+
+    conn.stream.close();
+    try std.testing.expect(!ipc_client.isAlive());
+}
+
+test "control socket answers text commands with status lines" {
+    const path = "/tmp/proctmux-zig-clean-ipc-control-test.socket";
+    std.fs.deleteFileAbsolute(path) catch {};
+    defer std.fs.deleteFileAbsolute(path) catch {};
+
+    var handler = test_ipc.FakeCommandHandler{};
+    var provider = test_ipc.FakeSnapshotProvider{ .line = test_ipc.apiWorkerSnapshotLine };
+    var stopped = std.atomic.Value(bool).init(false);
+    const thread = try std.Thread.spawn(.{}, control.serveAtPath, .{
+        std.testing.allocator,
+        path,
+        handler.handler(),
+        provider.provider(),
+        &stopped,
+    });
+    var joined = false;
+    defer if (!joined) {
+        stopped.store(true, .seq_cst);
+        test_ipc.unblockServer(path);
+        thread.join();
+    };
+    test_ipc.waitForSocketFile(path);
+
+    var stream = try std.net.connectUnixSocket(path);
+    errdefer stream.close();
+    try stream.writeAll("restart worker\n");
+    const ok = try line_io.readTimeout(std.testing.allocator, stream, 1024, 1000);
+    defer std.testing.allocator.free(ok);
+    try std.testing.expectEqualStrings("ok\n", ok);
+    try std.testing.expectEqual(protocol.Command.restart, handler.action);
+    try std.testing.expectEqualStrings("worker", handler.label());
+
+    try stream.writeAll("list\n");
+    const api = try line_io.readTimeout(std.testing.allocator, stream, 1024, 1000);
+    defer std.testing.allocator.free(api);
+    const worker = try line_io.readTimeout(std.testing.allocator, stream, 1024, 1000);
+    defer std.testing.allocator.free(worker);
+    const listed = try line_io.readTimeout(std.testing.allocator, stream, 1024, 1000);
+    defer std.testing.allocator.free(listed);
+    try std.testing.expectEqualStrings("api\trunning\n", api);
+    try std.testing.expectEqualStrings("worker\tstopped\n", worker);
+    try std.testing.expectEqualStrings("ok\n", listed);
+
+    try stream.writeAll("launch worker\n");
+    const rejected = try line_io.readTimeout(std.testing.allocator, stream, 1024, 1000);
+    defer std.testing.allocator.free(rejected);
+    try std.testing.expect(std.mem.startsWith(u8, rejected, "error unknown command"));
+    try std.testing.expectEqual(@as(usize, 1), handler.call_count);
+    stream.close();
+
+    stopped.store(true, .seq_cst);
+    test_ipc.unblockServer(path);
+    thread.join();
+    joined = true;
+    try std.testing.expectError(error.FileNotFound, std.fs.accessAbsolute(path, .{}));
+}
+
+test "control socket serves clients concurrently and closes idle answered ones" {
+    const path = "/tmp/proctmux-zig-clean-ipc-control-concurrent-test.socket";
+    std.fs.deleteFileAbsolute(path) catch {};
+    defer std.fs.deleteFileAbsolute(path) catch {};
+
+    var handler = test_ipc.FakeCommandHandler{};
+    var provider = test_ipc.FakeSnapshotProvider{ .line = test_ipc.apiWorkerSnapshotLine };
+    var stopped = std.atomic.Value(bool).init(false);
+    const thread = try std.Thread.spawn(.{}, control.serveAtPath, .{
+        std.testing.allocator,
+        path,
+        handler.handler(),
+        provider.provider(),
+        &stopped,
+    });
+    defer {
+        stopped.store(true, .seq_cst);
+        test_ipc.unblockServer(path);
+        thread.join();
+    }
+    test_ipc.waitForSocketFile(path);
+
+    // The first client never writes; it must not hold up the second.
+    var silent = try std.net.connectUnixSocket(path);
+    defer silent.close();
+    var stream = try std.net.connectUnixSocket(path);
+    defer stream.close();
+
+    try stream.writeAll("stop worker\n");
+    const ok = try line_io.readTimeout(std.testing.allocator, stream, 1024, 1000);
+    defer std.testing.allocator.free(ok);
+    try std.testing.expectEqualStrings("ok\n", ok);
+    try std.testing.expectEqual(protocol.Command.stop, handler.action);
+    try std.testing.expectEqualStrings("worker", handler.label());
+
+    try std.testing.expectError(
+        error.EndOfStream,
+        line_io.readTimeout(std.testing.allocator, stream, 1024, 3000),
+    );
+}
+
+test "control socket answers an overlong line before closing" {
+    const path = "/tmp/proctmux-zig-clean-ipc-control-long-line-test.socket";
+    std.fs.deleteFileAbsolute(path) catch {};
+    defer std.fs.deleteFileAbsolute(path) catch {};
+
+    var handler = test_ipc.FakeCommandHandler{};
+    var provider = test_ipc.FakeSnapshotProvider{ .line = test_ipc.apiWorkerSnapshotLine };
+    var stopped = std.atomic.Value(bool).init(false);
+    const thread = try std.Thread.spawn(.{}, control.serveAtPath, .{
+        std.testing.allocator,
+        path,
+        handler.handler(),
+        provider.provider(),
+        &stopped,
+    });
+    defer {
+        stopped.store(true, .seq_cst);
+        test_ipc.unblockServer(path);
+        thread.join();
+    }
+    test_ipc.waitForSocketFile(path);
+
+    var stream = try std.net.connectUnixSocket(path);
+    defer stream.close();
+    // One byte past the 4096-byte limit, so the server has read all of it.
+    const long_line = [_]u8{'x'} ** 4097;
+    try stream.writeAll(&long_line);
+    const rejected = try line_io.readTimeout(std.testing.allocator, stream, 1024, 1000);
+    defer std.testing.allocator.free(rejected);
+    try std.testing.expectEqualStrings("error line too long\n", rejected);
+    try std.testing.expectEqual(@as(usize, 0), handler.call_count);
+    try std.testing.expectError(
+        error.EndOfStream,
+        line_io.readTimeout(std.testing.allocator, stream, 1024, 1000),
+    );
+}
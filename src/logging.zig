@@ -0,0 +1,90 @@
+//! Process-wide log sink for the binary.
+//! Messages go to stderr with a UTC timestamp; `--verbose` lowers the runtime threshold to debug without rebuilding, and `--debug-pane` sends them to a file instead.
+
+const std = @import("std");
+
+var threshold = std.atomic.Value(u8).init(@intFromEnum(std.log.Level.info));
+var file_mutex: std.Thread.Mutex = .{};
+var log_file: ?std.fs.File = null;
+
+/// Sets the most detailed level that is still written.
+pub fn setLevel(level: std.log.Level) void {
+    threshold.store(@intFromEnum(level), .monotonic);
+}
+
+/// Writes every message to `path` instead of stderr until `closeFile`. The
+/// TUI owns the terminal then, so stderr would draw over it. Writes append, so
+/// a unified child primary can share the parent's file; `truncate` empties it
+/// first for the process that owns it.
+pub fn openFile(path: []const u8, truncate: bool) !void {
+    const fd = try std.posix.open(path, .{ .ACCMODE = .WRONLY, .CREAT = true, .TRUNC = truncate, .APPEND = true, .CLOEXEC = true }, 0o600);
+    const file = std.fs.File{ .handle = fd };
+    file_mutex.lock();
+    defer file_mutex.unlock();
+    if (log_file) |previous| previous.close();
+    log_file = file;
+}
+
+pub fn closeFile() void {
+    file_mutex.lock();
+    defer file_mutex.unlock();
+    if (log_file) |file| file.close();
+    log_file = null;
+}
+
+/// `std.Options.logFn` implementation. Filtering happens at runtime, so the
+/// binary must be built with `log_level = .debug` for `--verbose` to show all.
+pub fn logFn(
+    comptime level: std.log.Level,
+    comptime scope: @Type(.enum_literal),
+    comptime format: []const u8,
+    args: anytype,
+) void {
+    if (@intFromEnum(level) > threshold.load(.monotonic)) return;
+
+    const prefix = comptime level.asText() ++ if (scope == .default) ": " else "(" ++ @tagName(scope) ++ "): ";
+    var timestamp: [24]u8 = undefined;
+    const line_args = .{formatTimestamp(&timestamp, std.time.milliTimestamp())} ++ args;
+    if (writeFile("{s} " ++ prefix ++ format ++ "\n", line_args)) return;
+
+    var buffer: [64]u8 = undefined;
+    const stderr = std.debug.lockStderrWriter(&buffer);
+    defer std.debug.unlockStderrWriter();
+    stderr.print("{s} " ++ prefix ++ format ++ "\n", line_args) catch return;
+}
+
+/// Returns false when no log file is open, so the caller writes to stderr.
+fn writeFile(comptime format: []const u8, args: anytype) bool {
+    file_mutex.lock();
+    defer file_mutex.unlock();
+    const file = log_file orelse return false;
+    var buffer: [256]u8 = undefined;
+    var file_writer = file.writerStreaming(&buffer);
+    file_writer.interface.print(format, args) catch return true;
+    file_writer.interface.flush() catch {};
+    return true;
+}
+
+/// Formats epoch milliseconds as `YYYY-MM-DDTHH:MM:SS.mmmZ`.
+pub fn formatTimestamp(buf: *[24]u8, epoch_ms: i64) []const u8 {
+    const ms: u64 = @intCast(@max(epoch_ms, 0));
+    const epoch = std.time.epoch.EpochSeconds{ .secs = ms / std.time.ms_per_s };
+    const year_day = epoch.getEpochDay().calculateYearDay();
+    const month_day = year_day.calculateMonthDay();
+    const day_seconds = epoch.getDaySeconds();
+    return std.fmt.bufPrint(buf, "{d:0>4}-{d:0>2}-{d:0>2}T{d:0>2}:{d:0>2}:{d:0>2}.{d:0>3}Z", .{
+        year_day.year,
+        month_day.month.numeric(),
+        month_day.day_index + 1,
+        day_seconds.getHoursIntoDay(),
+        day_seconds.getMinutesIntoHour(),
+        day_seconds.getSecondsIntoMinute(),
+        ms % std.time.ms_per_s,
+    }) catch unreachable;
+}
+
+test "log timestamps are UTC with milliseconds" {
+    var buf: [24]u8 = undefined;
+    try std.testing.expectEqualStrings("1970-01-01T00:00:00.000Z", formatTimestamp(&buf, 0));
+    try std.testing.expectEqualStrings("2024-02-29T13:45:07.089Z", formatTimestamp(&buf, 1709214307089));
+}
@@ -3,9 +3,12 @@
 
 const std = @import("std");
 const app = @import("app/root.zig");
+const logging = @import("logging.zig");
 
+// Debug calls are compiled in and filtered at runtime so `--verbose` works.
 pub const std_options: std.Options = .{
-    .log_level = .info,
+    .log_level = .debug,
+    .logFn = logging.logFn,
 };
 
 pub fn main() !void {
@@ -27,15 +30,16 @@ pub fn main() !void {
         .fd = stdout.handle,
     };
 
-    app.run(allocator, args, output) catch |err| {
+    const status = app.run(allocator, args, output) catch |err| {
         var stderr = std.fs.File.stderr();
         if (app.shouldPrintGenericError(err)) {
             try stderr.writeAll("Error: ");
-            try stderr.writeAll(@errorName(err));
+            try stderr.writeAll(app.genericErrorMessage(err));
             try stderr.writeAll("\n");
         }
         std.process.exit(app.exitCodeForError(err));
     };
+    if (status != 0) std.process.exit(status);
 }
 
 fn writeFile(context: *anyopaque, bytes: []const u8) anyerror!void {
@@ -11,11 +11,11 @@ const io = @import("io.zig");
 pub fn run(
     allocator: std.mem.Allocator,
     dir: std.fs.Dir,
-    config_file: []const u8,
+    config_files: []const []const u8,
     input: io.Input,
     output: io.Output,
 ) !void {
-    var loaded = try config.runtime.loadInDir(allocator, dir, config_file);
+    var loaded = try config.runtime.loadFilesInDir(allocator, dir, config_files);
     defer loaded.deinit();
 
     const socket_path = ipc.socket.getPathForConfig(allocator, &loaded.config) catch
@@ -24,6 +24,7 @@ pub fn run(
 
     var ipc_client = try ipc.client.Client.connect(allocator, socket_path);
     defer ipc_client.deinit();
+    ipc_client.response_timeout_ms = ipc.client.responseTimeoutMs(&loaded.config);
 
     var session = try tui.client_session.ClientSession.init(
         allocator,
@@ -31,27 +32,37 @@ pub fn run(
     );
     defer session.deinit();
 
+    var ui_state = try tui.ui_state.Session.init(allocator, dir, &loaded.config);
+    defer ui_state.deinit();
+    ui_state.restore(&session);
+    defer ui_state.remember(&session);
+
     try output.writeAll(terminal.repaint.hide_cursor);
     defer output.writeAll(terminal.repaint.show_cursor) catch {};
+    if (loaded.config.mouse) try output.writeAll(terminal.repaint.enable_mouse);
+    defer if (loaded.config.mouse) output.writeAll(terminal.repaint.disable_mouse) catch {};
+    const mouse_slot = if (loaded.config.mouse) try terminal.shutdown.register(terminal.mode.mouseCleanup()) else null;
+    defer if (mouse_slot) |slot| terminal.shutdown.unregister(slot);
 
     try render(&session, output);
 
     if (input.fd) |input_fd| {
-        try pollLoop(&session, &ipc_client, input, input_fd, output);
+        try pollLoop(&session, &ipc_client, input, input_fd, output, &loaded.config);
         return;
     }
 
-    try inputLoop(&session, input, output);
+    try inputLoop(&session, input, output, &loaded.config);
 }
 
 fn inputLoop(
     session: *tui.client_session.ClientSession,
     input: io.Input,
     output: io.Output,
+    app_config: *const config.schema.Config,
 ) !void {
     var buffer: [64]u8 = undefined;
     while (true) {
-        if (try handleInput(session, input, output, &buffer)) return;
+        if (try handleInput(session, input, output, app_config, &buffer)) return;
     }
 }
 
@@ -61,9 +72,11 @@ fn pollLoop(
     input: io.Input,
     input_fd: std.posix.fd_t,
     output: io.Output,
+    app_config: *const config.schema.Config,
 ) !void {
     var buffer: [64]u8 = undefined;
     while (true) {
+        if (!ipc_client.isAlive()) return error.PrimaryServerGone;
         if (try readAvailableSnapshotUpdate(session, ipc_client)) {
             try render(session, output);
             continue;
@@ -90,7 +103,8 @@ fn pollLoop(
         }
 
         if ((poll_fds[0].revents & std.posix.POLL.IN) != 0) {
-            if (try handleInput(session, input, output, &buffer)) return;
+            if (!ipc_client.isAlive()) return error.PrimaryServerGone;
+            if (try handleInput(session, input, output, app_config, &buffer)) return;
         }
     }
 }
@@ -99,7 +113,11 @@ fn readAvailableSnapshotUpdate(
     session: *tui.client_session.ClientSession,
     ipc_client: *ipc.client.Client,
 ) !bool {
-    const update = (try ipc_client.readLatestSnapshotIfAvailable()) orelse return false;
+    const maybe_update = ipc_client.readLatestSnapshotIfAvailable() catch |err| switch (err) {
+        error.EndOfStream => return error.PrimaryServerGone,
+        else => return err,
+    };
+    const update = maybe_update orelse return false;
     try session.applySnapshotUpdate(update);
     return true;
 }
@@ -108,6 +126,7 @@ fn handleInput(
     session: *tui.client_session.ClientSession,
     input: io.Input,
     output: io.Output,
+    app_config: *const config.schema.Config,
     buffer: *[64]u8,
 ) !bool {
     const n = try input.readBytes(buffer);
@@ -116,9 +135,19 @@ fn handleInput(
     var should_render = false;
     var index: usize = 0;
     while (index < n) {
+        if (tui.key_input.mouseForInput(buffer[0..n], &index)) |mouse| {
+            if (mouse.isLeftPress() and try session.clickListRow(mouse.row)) should_render = true;
+            continue;
+        }
         var key_buf: [1]u8 = undefined;
         if (tui.key_input.keyForInput(buffer[0..n], &index, &key_buf)) |key| {
             const interaction = try session.handleKeyInteraction(key, .{});
+            if (interaction.edit_config) {
+                try tui.editor.editAndReload(session, app_config.file_path, input.fd, output, app_config.mouse);
+                try render(session, output);
+                should_render = false;
+                continue;
+            }
             if (interaction.stop) {
                 try render(session, output);
                 return true;
@@ -15,12 +15,13 @@ const log = std.log.scoped(.primary_mode);
 pub fn runUntilStopped(
     allocator: std.mem.Allocator,
     dir: std.fs.Dir,
-    config_file: []const u8,
+    config_files: []const []const u8,
+    set_overrides: []const []const u8,
     input: io.Input,
     output: io.Output,
     stopped: *std.atomic.Value(bool),
 ) !void {
-    var loaded = try config.runtime.loadInDir(allocator, dir, config_file);
+    var loaded = try config.runtime.loadFilesWithOverridesInDir(allocator, dir, config_files, set_overrides);
     defer loaded.deinit();
 
     const socket_path = try ipc.socket.createPathForConfig(allocator, &loaded.config);
@@ -29,6 +30,9 @@ pub fn runUntilStopped(
 
     var primary_server = try primary_mod.Server.init(allocator, &loaded.config);
     defer primary_server.deinit();
+    primary_server.config_dir = dir;
+    primary_server.config_files = config_files;
+    primary_server.set_overrides = set_overrides;
 
     var output_run = PrimaryOutputRun{
         .allocator = allocator,
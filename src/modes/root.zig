@@ -4,11 +4,13 @@
 pub const client = @import("client.zig");
 pub const io = @import("io.zig");
 pub const primary = @import("primary.zig");
+pub const run = @import("run.zig");
 pub const signal = @import("signal.zig");
 
 test {
     _ = client;
     _ = io;
     _ = primary;
+    _ = run;
     _ = signal;
 }
@@ -0,0 +1,123 @@
+//! Headless Run Mode for CI.
+//! This mode starts the requested processes without a TUI or IPC socket, streams the awaited process's output, and returns its exit status once it ends.
+
+const std = @import("std");
+const commands = @import("../commands/root.zig");
+const config = @import("../config/root.zig");
+const domain = @import("../domain/root.zig");
+const primary_mod = @import("../primary/root.zig");
+const terminal = @import("../terminal/root.zig");
+const io = @import("io.zig");
+
+const log = std.log.scoped(.run_mode);
+
+/// Status returned when the run is stopped before the awaited process exits,
+/// or when it was stopped by something other than its own exit.
+pub const interrupted_status: u8 = 130;
+
+/// Status returned when the awaited process was stopped for running past its
+/// `max_runtime_secs`, matching timeout(1).
+pub const timed_out_status: u8 = 124;
+
+/// Starts every named process, then waits for `wait_for` to exit. All
+/// processes are stopped before returning that process's exit status.
+pub fn run(
+    allocator: std.mem.Allocator,
+    dir: std.fs.Dir,
+    config_files: []const []const u8,
+    set_overrides: []const []const u8,
+    args: []const []const u8,
+    output: io.Output,
+    stopped: *std.atomic.Value(bool),
+) !u8 {
+    const plan = commands.run.parse(args) catch |err| {
+        try output.writeAll(switch (err) {
+            error.MissingName => "run needs at least one process name\n",
+            error.MissingWaitFor => "run needs --wait-for <name> when more than one process is given\n",
+            error.UnknownRunFlag => "run only accepts --wait-for <name>, --shutdown-grace <secs> and process names\n",
+            error.InvalidShutdownGrace => "run needs --shutdown-grace <secs> as a whole number of seconds\n",
+            else => "",
+        });
+        return err;
+    };
+
+    var loaded = try config.runtime.loadFilesWithOverridesInDir(allocator, dir, config_files, set_overrides);
+    defer loaded.deinit();
+    for (plan.names()) |name| try requireProcess(&loaded.config, name);
+    try requireProcess(&loaded.config, plan.wait_for);
+
+    var primary_server = try primary_mod.Server.init(allocator, &loaded.config);
+    defer primary_server.deinit();
+    defer primary_server.cleanup();
+    defer if (plan.shutdown_grace_secs) |grace_secs| stopWithinGrace(allocator, &primary_server, grace_secs);
+    const cleanup_slot = try terminal.shutdown.register(primary_server.shutdownCleanup());
+    defer terminal.shutdown.unregister(cleanup_slot);
+    try primary_server.prepare();
+
+    for (plan.names()) |name| _ = try primary_server.startWithDependencies(name);
+    const wait_id = if (plan.startsWaitFor())
+        try primary_server.startWithDependencies(plan.wait_for)
+    else
+        primary_server.state.getProcessByLabel(plan.wait_for).?.id;
+
+    const poll_interval_ms = primary_mod.refreshIntervalMs(&loaded.config);
+    var output_cursor: u64 = 0;
+    while (primary_server.controller.isRunning(wait_id)) {
+        if (stopped.load(.seq_cst)) {
+            log.info("run stopped before '{s}' exited", .{plan.wait_for});
+            return interrupted_status;
+        }
+        try writeOutputDelta(allocator, &primary_server, wait_id, output, &output_cursor);
+        primary_server.enforceRuntimeLimits();
+        primary_server.runExitHooks();
+        std.Thread.sleep(@as(u64, poll_interval_ms) * std.time.ns_per_ms);
+    }
+    try writeOutputDelta(allocator, &primary_server, wait_id, output, &output_cursor);
+    primary_server.runExitHooks();
+
+    if (primary_server.controller.timedOut(wait_id)) {
+        log.warn("process '{s}' timed out", .{plan.wait_for});
+        return timed_out_status;
+    }
+    const status = primary_server.controller.exitStatus(wait_id) orelse interrupted_status;
+    log.info("process '{s}' exited with status {d}", .{ plan.wait_for, status });
+    return @intCast(@min(status, 255));
+}
+
+/// Stops every process together and names the ones that outlived the grace
+/// period and were killed. The regular cleanup that follows finds nothing left.
+fn stopWithinGrace(allocator: std.mem.Allocator, primary_server: *primary_mod.Server, grace_secs: u32) void {
+    const killed = primary_server.controller.stopAllWithin(allocator, @as(u64, grace_secs) * std.time.ms_per_s) catch |err| {
+        log.warn("shutdown grace failed: {s}; stopping processes one by one", .{@errorName(err)});
+        return;
+    };
+    defer allocator.free(killed);
+    for (killed) |id| {
+        const process = primary_server.state.getProcessByID(id) orelse continue;
+        log.warn("process '{s}' did not exit within the {d}s shutdown grace and was killed", .{ process.label, grace_secs });
+    }
+}
+
+fn requireProcess(cfg: *const config.schema.Config, name: []const u8) !void {
+    if (cfg.procs.contains(name)) return;
+    log.warn("no process named '{s}' in the config", .{name});
+    return error.ProcessNotFound;
+}
+
+/// Writes output the awaited process produced since the last call. The byte
+/// cursor keeps streaming once scrollback is full and trims old output.
+fn writeOutputDelta(
+    allocator: std.mem.Allocator,
+    primary_server: *primary_mod.Server,
+    id: domain.process.ProcessId,
+    output: io.Output,
+    cursor: *u64,
+) !void {
+    const bytes = primary_server.controller.readOutputSince(allocator, id, cursor) catch |err| switch (err) {
+        error.ProcessNotFound => return,
+        else => return err,
+    };
+    defer allocator.free(bytes);
+
+    if (bytes.len > 0) try output.writeAll(bytes);
+}
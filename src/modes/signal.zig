@@ -9,12 +9,12 @@ const io = @import("io.zig");
 pub fn run(
     allocator: std.mem.Allocator,
     dir: std.fs.Dir,
-    config_file: []const u8,
+    config_files: []const []const u8,
     subcommand: []const u8,
     args: []const []const u8,
     output: io.Output,
 ) !void {
-    var loaded = try config.runtime.loadInDir(allocator, dir, config_file);
+    var loaded = try config.runtime.loadFilesInDir(allocator, dir, config_files);
     defer loaded.deinit();
 
     try commands.signal.runWithConfig(
@@ -2,12 +2,20 @@
 //! This module converts IPC Process Commands into process lifecycle and selection changes while keeping response construction local to command semantics.
 
 const std = @import("std");
+const config = @import("../config/root.zig");
 const domain = @import("../domain/root.zig");
 const ipc = @import("../ipc/root.zig");
 const proc_mod = @import("../proc/root.zig");
 
 const log = std.log.scoped(.primary_command_runner);
 
+/// Reloads Project Config for the `reload` command. The Primary Server owns
+/// config lifetimes, so it supplies this instead of the runner loading files.
+pub const Reloader = struct {
+    context: *anyopaque,
+    reload: *const fn (context: *anyopaque) anyerror!void,
+};
+
 /// Executes Process Commands against Primary-owned state. The runner is kept
 /// concrete instead of callback-heavy so command semantics stay local to the
 /// Primary Server domain.
@@ -15,6 +23,7 @@ pub const Runner = struct {
     state: *domain.state.AppState,
     controller: *proc_mod.controller.Controller,
     current_process_id: *std.atomic.Value(u32),
+    reloader: ?Reloader = null,
 
     /// Handles one decoded IPC command and returns the response that should be
     /// written to the requesting client.
@@ -27,7 +36,117 @@ pub const Runner = struct {
             .start, .stop, .restart, .switch_process => self.handleNamedRequest(allocator, request),
             .stop_running => self.stopRunningResponse(allocator, request.request_id),
             .restart_running => self.restartRunningResponse(allocator, request.request_id),
+            .send_input => self.sendInputResponse(allocator, request),
+            .reload => self.reloadResponse(allocator, request.request_id),
+            .start_all => batchResponse(allocator, request.request_id, "start", try self.startAll(allocator)),
+            .stop_all => batchResponse(allocator, request.request_id, "stop", try self.stopAll(allocator)),
+            .clear_output => self.clearOutputResponse(allocator, request),
+            .copy_output => self.copyOutputResponse(allocator, request),
+        };
+    }
+
+    /// Drops the target's retained output. A process that is not running keeps
+    /// its last output, so the command succeeds without clearing anything.
+    fn clearOutputResponse(
+        self: Runner,
+        allocator: std.mem.Allocator,
+        request: ipc.protocol.CommandRequest,
+    ) !ipc.protocol.Response {
+        const target = request.targetLabel();
+        if (target.len == 0) return errorResponse(allocator, request.request_id, "missing process name");
+
+        const target_process = self.state.getProcessByLabel(target) orelse {
+            const message = try std.fmt.allocPrint(allocator, "process not found: {s}", .{target});
+            defer allocator.free(message);
+            return errorResponse(allocator, request.request_id, message);
+        };
+        if (!self.controller.isRunning(target_process.id)) {
+            log.debug("not clearing output of stopped process '{s}'", .{target_process.label});
+            return successResponse(allocator, request.request_id);
+        }
+        self.controller.clearScrollback(target_process.id) catch |err| switch (err) {
+            error.ProcessNotFound => {},
+            else => return errorResponse(allocator, request.request_id, @errorName(err)),
+        };
+        return successResponse(allocator, request.request_id);
+    }
+
+    /// Copies the target's retained output, without escape sequences, to the
+    /// clipboard, or to a temp file when no clipboard tool is available.
+    fn copyOutputResponse(
+        self: Runner,
+        allocator: std.mem.Allocator,
+        request: ipc.protocol.CommandRequest,
+    ) !ipc.protocol.Response {
+        const target = request.targetLabel();
+        if (target.len == 0) return errorResponse(allocator, request.request_id, "missing process name");
+
+        const target_process = self.state.getProcessByLabel(target) orelse {
+            const message = try std.fmt.allocPrint(allocator, "process not found: {s}", .{target});
+            defer allocator.free(message);
+            return errorResponse(allocator, request.request_id, message);
+        };
+        const bytes = self.controller.getScrollback(allocator, target_process.id) catch |err| switch (err) {
+            error.ProcessNotFound => try allocator.dupe(u8, ""),
+            else => return err,
+        };
+        defer allocator.free(bytes);
+        const text = try proc_mod.clipboard.plainText(allocator, bytes);
+        defer allocator.free(text);
+        if (std.mem.trim(u8, text, " \t\r\n").len == 0) {
+            const message = try std.fmt.allocPrint(allocator, "{s} has no output to copy", .{target_process.label});
+            defer allocator.free(message);
+            return errorResponse(allocator, request.request_id, message);
+        }
+
+        const outcome = proc_mod.clipboard.copy(allocator, text, target_process.label, self.controller.global_config) catch |err| {
+            const message = try std.fmt.allocPrint(allocator, "copying output failed: {s}", .{@errorName(err)});
+            defer allocator.free(message);
+            return errorResponse(allocator, request.request_id, message);
+        };
+        defer outcome.deinit(allocator);
+        const notice = switch (outcome) {
+            .clipboard => try std.fmt.allocPrint(allocator, "copied {s} output to the clipboard", .{target_process.label}),
+            .file => |path| try std.fmt.allocPrint(allocator, "no clipboard tool found; wrote {s} output to {s}", .{ target_process.label, path }),
+        };
+        defer allocator.free(notice);
+        return noticeResponse(allocator, request.request_id, notice);
+    }
+
+    fn reloadResponse(self: Runner, allocator: std.mem.Allocator, request_id: u64) !ipc.protocol.Response {
+        const reloader = self.reloader orelse return errorResponse(allocator, request_id, "config reload is not available");
+        reloader.reload(reloader.context) catch |err| {
+            const message = try std.fmt.allocPrint(allocator, "config reload failed ({s}); keeping the current config", .{@errorName(err)});
+            defer allocator.free(message);
+            return errorResponse(allocator, request_id, message);
+        };
+        return successResponse(allocator, request_id);
+    }
+
+    /// Writes the request's input to the target's terminal as if it were typed.
+    fn sendInputResponse(
+        self: Runner,
+        allocator: std.mem.Allocator,
+        request: ipc.protocol.CommandRequest,
+    ) !ipc.protocol.Response {
+        const target = request.targetLabel();
+        if (target.len == 0) return errorResponse(allocator, request.request_id, "missing process name");
+        const input = request.input orelse return errorResponse(allocator, request.request_id, "missing input");
+
+        const target_process = self.state.getProcessByLabel(target) orelse {
+            const message = try std.fmt.allocPrint(allocator, "process not found: {s}", .{target});
+            defer allocator.free(message);
+            return errorResponse(allocator, request.request_id, message);
         };
+        self.controller.sendBytes(target_process.id, input) catch |err| switch (err) {
+            error.ProcessNotFound, error.ProcessNotRunning => {
+                const message = try std.fmt.allocPrint(allocator, "{s} is not running", .{target_process.label});
+                defer allocator.free(message);
+                return errorResponse(allocator, request.request_id, message);
+            },
+            else => return errorResponse(allocator, request.request_id, @errorName(err)),
+        };
+        return successResponse(allocator, request.request_id);
     }
 
     fn handleNamedRequest(
@@ -44,12 +163,47 @@ pub const Runner = struct {
             return errorResponse(allocator, request.request_id, message);
         };
 
-        self.handleNamedProcess(request.action, target_process) catch |err| {
-            return errorResponse(allocator, request.request_id, @errorName(err));
+        if (request.action == .start and self.controller.isRunning(target_process.id)) {
+            return self.alreadyRunningResponse(allocator, request.request_id, target_process);
+        }
+        self.handleNamedProcess(request.action, target_process) catch |err| switch (err) {
+            error.ProcessCwdNotFound => {
+                const message = try std.fmt.allocPrint(allocator, "working directory for {s} does not exist: {s}", .{
+                    target_process.label,
+                    target_process.config.cwd,
+                });
+                defer allocator.free(message);
+                return errorResponse(allocator, request.request_id, message);
+            },
+            error.ReadinessTimeout, error.ProcessExitedBeforeReady => {
+                const message = try std.fmt.allocPrint(allocator, "a dependency of {s} did not become ready", .{target_process.label});
+                defer allocator.free(message);
+                return errorResponse(allocator, request.request_id, message);
+            },
+            error.UnknownDependency => {
+                const message = try std.fmt.allocPrint(allocator, "{s} depends on a process that is not configured", .{target_process.label});
+                defer allocator.free(message);
+                return errorResponse(allocator, request.request_id, message);
+            },
+            else => return errorResponse(allocator, request.request_id, @errorName(err)),
         };
         return successResponse(allocator, request.request_id);
     }
 
+    /// A start for a running process spawns nothing; `start_when_running:
+    /// focus` also makes it the current process.
+    fn alreadyRunningResponse(
+        self: Runner,
+        allocator: std.mem.Allocator,
+        request_id: u64,
+        target_process: *domain.process.Process,
+    ) !ipc.protocol.Response {
+        if (self.state.config.start_when_running == .focus) self.setCurrentProcess(target_process.id);
+        const notice = try std.fmt.allocPrint(allocator, "{s} is already running", .{target_process.label});
+        defer allocator.free(notice);
+        return noticeResponse(allocator, request_id, notice);
+    }
+
     fn handleNamedProcess(
         self: Runner,
         action: ipc.protocol.Command,
@@ -57,27 +211,73 @@ pub const Runner = struct {
     ) !void {
         switch (action) {
             .switch_process => self.setCurrentProcess(target_process.id),
-            .start => try self.startProcess(target_process),
-            .stop => try self.stopProcess(target_process),
-            .restart => {
+            .start => try self.startWithDependencies(self.state.allocator, target_process),
+            .stop => {
                 try self.stopProcess(target_process);
-                std.Thread.sleep(500 * std.time.ns_per_ms);
-                try self.startProcess(target_process);
+                self.startPause(target_process.id, target_process.label, target_process.config);
             },
+            .restart => try self.restartProcess(target_process),
             else => return error.UnsupportedCommand,
         }
     }
 
-    fn startProcess(self: Runner, target_process: *domain.process.Process) !void {
+    /// Starts `target_process` after everything in its `depends_on` chain, in
+    /// dependency order. Processes that are already running are left alone;
+    /// each dependency must pass its `ready_when` checks before the next starts.
+    pub fn startWithDependencies(
+        self: Runner,
+        allocator: std.mem.Allocator,
+        target_process: *domain.process.Process,
+    ) !void {
+        const order = try config.depends.startOrder(allocator, self.state.config, target_process.label);
+        defer allocator.free(order);
+
+        for (order, 0..) |label, index| {
+            const process = self.state.getProcessByLabel(label) orelse return error.ProcessNotFound;
+            try self.startProcess(process);
+            if (index + 1 < order.len) try self.controller.waitUntilReady(process.id, process.label);
+        }
+    }
+
+    /// Starts one process without its dependencies; already-running processes are left alone.
+    pub fn startProcess(self: Runner, target_process: *domain.process.Process) !void {
         if (self.controller.isRunning(target_process.id)) return;
         try self.controller.cleanupProcess(target_process.id);
         if (self.currentProcessID().isNone()) self.setCurrentProcess(target_process.id);
-        _ = try self.controller.startProcess(target_process.id, target_process.config);
+        const instance = try self.controller.startProcess(target_process.id, target_process.config);
+        target_process.config_changed = false;
+        runHook(self.controller, .start, target_process.label, target_process.config, instance.pid());
     }
 
-    fn stopProcess(self: Runner, target_process: *domain.process.Process) !void {
+    /// Restarts in place: the Process ID, and therefore the client's selection,
+    /// is unchanged. A process that was never started is simply started.
+    fn restartProcess(self: Runner, target_process: *domain.process.Process) !void {
+        if (self.controller.isRunning(target_process.id)) {
+            try self.stopProcess(target_process);
+            std.Thread.sleep(500 * std.time.ns_per_ms);
+        }
+        try self.startWithDependencies(self.state.allocator, target_process);
+    }
+
+    pub fn stopProcess(self: Runner, target_process: *domain.process.Process) !void {
         if (!self.controller.isRunning(target_process.id)) return;
+        const pid = self.controller.getPID(target_process.id);
         try self.controller.stopProcess(target_process.id);
+        runHook(self.controller, .stop, target_process.label, target_process.config, pid);
+    }
+
+    /// Puts the `pause` placeholder back after a user stop; startup only
+    /// covers processes that autostart left stopped. `stop_running` skips it
+    /// because quitting sends that.
+    fn startPause(
+        self: Runner,
+        id: domain.process.ProcessId,
+        label: []const u8,
+        proc_cfg: *const config.schema.ProcessConfig,
+    ) void {
+        _ = self.controller.startPause(id, proc_cfg) catch |err| {
+            log.warn("pause command failed for process '{s}': {s}", .{ label, @errorName(err) });
+        };
     }
 
     fn stopRunningResponse(self: Runner, allocator: std.mem.Allocator, request_id: u64) !ipc.protocol.Response {
@@ -90,6 +290,7 @@ pub const Runner = struct {
                     .controller = self.controller,
                     .id = target_process.id,
                     .label = target_process.label,
+                    .proc_cfg = target_process.config,
                 });
             }
         }
@@ -102,13 +303,50 @@ pub const Runner = struct {
         return successResponse(allocator, request_id);
     }
 
+    /// Starts every process after its `depends_on` chain, by `order` and then
+    /// config order. A failure is logged and collected instead of ending the batch.
+    pub fn startAll(self: Runner, allocator: std.mem.Allocator) ![]BatchFailure {
+        const labels = try config.depends.launchOrder(allocator, self.state.config);
+        defer allocator.free(labels);
+        var failures = std.array_list.Managed(BatchFailure).init(allocator);
+        errdefer failures.deinit();
+
+        for (labels) |label| {
+            const target_process = self.state.getProcessByLabel(label) orelse continue;
+            if (self.controller.isRunning(target_process.id)) continue;
+            self.startWithDependencies(allocator, target_process) catch |err| {
+                log.warn("start all: process '{s}' failed to start: {s}", .{ target_process.label, @errorName(err) });
+                try failures.append(.{ .label = target_process.label, .err = err });
+            };
+        }
+        return failures.toOwnedSlice();
+    }
+
+    /// Stops running processes in reverse dependency order, so dependents go
+    /// down before what they depend on. Failures are collected like `startAll`.
+    pub fn stopAll(self: Runner, allocator: std.mem.Allocator) ![]BatchFailure {
+        const order = try config.depends.allStartOrder(allocator, self.state.config);
+        defer allocator.free(order);
+        var failures = std.array_list.Managed(BatchFailure).init(allocator);
+        errdefer failures.deinit();
+
+        var index = order.len;
+        while (index > 0) {
+            index -= 1;
+            const target_process = self.state.getProcessByLabel(order[index]) orelse continue;
+            self.stopProcess(target_process) catch |err| {
+                log.warn("stop all: process '{s}' failed to stop: {s}", .{ target_process.label, @errorName(err) });
+                try failures.append(.{ .label = target_process.label, .err = err });
+                continue;
+            };
+            self.startPause(target_process.id, target_process.label, target_process.config);
+        }
+        return failures.toOwnedSlice();
+    }
+
     fn restartRunningResponse(self: Runner, allocator: std.mem.Allocator, request_id: u64) !ipc.protocol.Response {
         for (self.state.processes.items) |*target_process| {
-            if (self.controller.isRunning(target_process.id)) {
-                try self.controller.stopProcess(target_process.id);
-                std.Thread.sleep(500 * std.time.ns_per_ms);
-                _ = try self.controller.startProcess(target_process.id, target_process.config);
-            }
+            if (self.controller.isRunning(target_process.id)) try self.restartProcess(target_process);
         }
         return successResponse(allocator, request_id);
     }
@@ -117,16 +355,22 @@ pub const Runner = struct {
         return domain.process.ProcessId.fromInt(self.current_process_id.load(.seq_cst));
     }
 
-    fn setCurrentProcess(self: Runner, id: domain.process.ProcessId) void {
+    pub fn setCurrentProcess(self: Runner, id: domain.process.ProcessId) void {
         self.state.current_proc_id = id;
         self.current_process_id.store(id.toInt(), .seq_cst);
     }
 };
 
+pub const BatchFailure = struct {
+    label: []const u8,
+    err: anyerror,
+};
+
 const StopProcessRun = struct {
     controller: *proc_mod.controller.Controller,
     id: domain.process.ProcessId,
     label: []const u8,
+    proc_cfg: *const config.schema.ProcessConfig,
     result: ?anyerror = null,
 };
 
@@ -168,9 +412,24 @@ fn stopProcessesSequentially(stop_runs: []StopProcessRun) void {
 }
 
 fn stopProcessWorker(stop_run: *StopProcessRun) void {
+    const pid = stop_run.controller.getPID(stop_run.id);
     stop_run.controller.stopProcess(stop_run.id) catch |err| {
         stop_run.result = err;
+        return;
     };
+    runHook(stop_run.controller, .stop, stop_run.label, stop_run.proc_cfg, pid);
+}
+
+/// Lifecycle hooks only ever log failures, so a broken `on_start`/`on_stop`
+/// never turns a successful start or stop into an error response.
+fn runHook(
+    controller: *proc_mod.controller.Controller,
+    event: proc_mod.hooks.Event,
+    label: []const u8,
+    proc_cfg: *const config.schema.ProcessConfig,
+    pid: i32,
+) void {
+    proc_mod.hooks.run(controller.allocator, event, label, proc_cfg, controller.global_config, pid, null);
 }
 
 fn reportStopFailures(stop_runs: []const StopProcessRun) void {
@@ -191,6 +450,27 @@ fn reportStopFailures(stop_runs: []const StopProcessRun) void {
     }
 }
 
+/// Reports a whole batch at once: success, or every process that failed.
+/// Takes ownership of `failures`.
+fn batchResponse(
+    allocator: std.mem.Allocator,
+    request_id: u64,
+    verb: []const u8,
+    failures: []const BatchFailure,
+) !ipc.protocol.Response {
+    defer allocator.free(failures);
+    if (failures.len == 0) return successResponse(allocator, request_id);
+
+    var message = std.array_list.Managed(u8).init(allocator);
+    defer message.deinit();
+    try message.writer().print("{s} all: {d} failed: ", .{ verb, failures.len });
+    for (failures, 0..) |failure, index| {
+        if (index > 0) try message.appendSlice(", ");
+        try message.writer().print("{s} ({s})", .{ failure.label, @errorName(failure.err) });
+    }
+    return errorResponse(allocator, request_id, message.items);
+}
+
 fn successResponse(allocator: std.mem.Allocator, request_id: u64) !ipc.protocol.Response {
     return .{
         .request_id = request_id,
@@ -199,6 +479,18 @@ fn successResponse(allocator: std.mem.Allocator, request_id: u64) !ipc.protocol.
     };
 }
 
+/// Success carrying a note the client shows as is.
+fn noticeResponse(allocator: std.mem.Allocator, request_id: u64, message: []const u8) !ipc.protocol.Response {
+    const error_message = try allocator.dupe(u8, "");
+    errdefer allocator.free(error_message);
+    return .{
+        .request_id = request_id,
+        .success = true,
+        .error_message = error_message,
+        .message = try allocator.dupe(u8, message),
+    };
+}
+
 fn errorResponse(
     allocator: std.mem.Allocator,
     request_id: u64,
@@ -0,0 +1,74 @@
+//! `on_success` and `on_failure` hooks for processes that exit on their own.
+//! The Primary Server polls exit status and runs the matching hook once per run, passing the status as `PROCTMUX_EXIT_CODE`; user stops keep running `on_stop`.
+
+const std = @import("std");
+const config = @import("../config/root.zig");
+const domain = @import("../domain/root.zig");
+const proc_mod = @import("../proc/root.zig");
+const command_runner = @import("command_runner.zig");
+
+pub const Track = struct {
+    /// Last pid seen while running; the exited instance no longer reports it.
+    pid: i32 = -1,
+    notified: bool = false,
+};
+
+/// Per-process exit bookkeeping keyed by process id, so a hook fires once per
+/// run however many polls see the same exit.
+pub const Notifier = struct {
+    tracks: std.AutoHashMap(u32, Track),
+
+    pub fn init(allocator: std.mem.Allocator) Notifier {
+        return .{ .tracks = std.AutoHashMap(u32, Track).init(allocator) };
+    }
+
+    pub fn deinit(self: *Notifier) void {
+        self.tracks.deinit();
+    }
+
+    /// Starts the exit hook of every process that ended since the last call.
+    /// Hooks finish on their own threads, so a slow one never stalls a refresh.
+    pub fn tick(self: *Notifier, runner: command_runner.Runner) !void {
+        for (runner.state.processes.items) |*process| {
+            if (!hasExitHook(process.config)) continue;
+            const entry = try self.tracks.getOrPut(process.id.toInt());
+            if (!entry.found_existing) entry.value_ptr.* = .{};
+            tickProcess(runner.controller, process, entry.value_ptr);
+        }
+    }
+};
+
+fn tickProcess(controller: *proc_mod.controller.Controller, process: *const domain.process.Process, state: *Track) void {
+    if (controller.isRunning(process.id)) {
+        state.pid = controller.getPID(process.id);
+        state.notified = false;
+        return;
+    }
+    const status = controller.exitStatus(process.id) orelse {
+        state.notified = false;
+        return;
+    };
+    if (state.notified) return;
+    state.notified = true;
+    proc_mod.hooks.run(
+        controller.allocator,
+        proc_mod.hooks.Event.forExit(status),
+        process.label,
+        process.config,
+        controller.global_config,
+        state.pid,
+        status,
+    );
+}
+
+fn hasExitHook(proc_cfg: *const config.schema.ProcessConfig) bool {
+    return proc_cfg.on_success.len > 0 or proc_cfg.on_failure.len > 0;
+}
+
+/// True when any process sets an exit hook, so the watcher can be skipped.
+pub fn anyHook(cfg: *const config.schema.Config) bool {
+    for (cfg.procs.values()) |*proc| {
+        if (hasExitHook(proc)) return true;
+    }
+    return false;
+}
@@ -0,0 +1,90 @@
+//! Config reload for a running Primary Server.
+//! A freshly loaded Project Config is reconciled against AppState: new processes join the list, removed ones are stopped, and running processes whose definition changed are flagged until they are restarted.
+
+const std = @import("std");
+const config = @import("../config/root.zig");
+const domain = @import("../domain/root.zig");
+const command_runner = @import("command_runner.zig");
+
+const log = std.log.scoped(.primary_reload);
+
+pub const Summary = struct {
+    added: usize = 0,
+    removed: usize = 0,
+    changed: usize = 0,
+};
+
+/// Points AppState and the controller at `new_cfg`. Everything that can fail
+/// happens before the first mutation, so an error leaves the old config in use.
+/// Instances started from the old config keep borrowing it, so the caller must
+/// keep the old config alive.
+pub fn reconcile(
+    allocator: std.mem.Allocator,
+    runner: command_runner.Runner,
+    new_cfg: *config.schema.Config,
+) !Summary {
+    const state = runner.state;
+    var summary = Summary{};
+
+    const changed = try allocator.alloc(bool, state.processes.items.len);
+    defer allocator.free(changed);
+    for (state.processes.items, changed) |process, *is_changed| {
+        const new_proc = new_cfg.procs.get(process.label) orelse {
+            is_changed.* = false;
+            continue;
+        };
+        is_changed.* = !try config.hash.processEql(allocator, process.label, process.config.*, new_proc);
+    }
+
+    var added: usize = 0;
+    for (new_cfg.procs.keys()) |label| {
+        if (state.getProcessByLabel(label) == null) added += 1;
+    }
+    try state.processes.ensureUnusedCapacity(added);
+
+    var index = state.processes.items.len;
+    while (index > 0) {
+        index -= 1;
+        const process = &state.processes.items[index];
+        const entry = new_cfg.procs.getEntry(process.label) orelse {
+            log.info("process '{s}' was removed from the config; stopping it", .{process.label});
+            runner.stopProcess(process) catch |err| {
+                log.warn("failed to stop removed process '{s}': {s}", .{ process.label, @errorName(err) });
+            };
+            if (state.current_proc_id == process.id) runner.setCurrentProcess(.none);
+            _ = state.processes.orderedRemove(index);
+            summary.removed += 1;
+            continue;
+        };
+        if (changed[index] and runner.controller.isRunning(process.id)) {
+            log.info("process '{s}' changed; restart it to apply the new definition", .{process.label});
+            process.config_changed = true;
+            summary.changed += 1;
+        }
+        process.label = entry.key_ptr.*;
+        process.config = entry.value_ptr;
+    }
+
+    var next_id: u32 = 1;
+    for (state.processes.items) |process| next_id = @max(next_id, process.id.toInt() + 1);
+    for (new_cfg.procs.keys(), new_cfg.procs.values()) |label, *proc_cfg| {
+        if (state.getProcessByLabel(label) != null) continue;
+        log.info("process '{s}' was added to the config", .{label});
+        state.processes.appendAssumeCapacity(.{
+            .id = domain.process.ProcessId.fromInt(next_id),
+            .label = label,
+            .config = proc_cfg,
+        });
+        next_id += 1;
+        summary.added += 1;
+    }
+    std.mem.sort(domain.process.Process, state.processes.items, {}, lessProcess);
+
+    state.config = new_cfg;
+    runner.controller.global_config = new_cfg;
+    return summary;
+}
+
+fn lessProcess(_: void, a: domain.process.Process, b: domain.process.Process) bool {
+    return std.mem.order(u8, a.label, b.label) == .lt;
+}
@@ -0,0 +1,138 @@
+//! Restart policies for processes that exit on their own.
+//! The Primary Server polls exit status and re-runs matching processes with exponential backoff until they stay up or run out of retries.
+
+const std = @import("std");
+const config = @import("../config/root.zig");
+const domain = @import("../domain/root.zig");
+const command_runner = @import("command_runner.zig");
+
+const log = std.log.scoped(.primary_restarts);
+
+pub const default_max_retries = 5;
+pub const default_backoff_ms = 1000;
+const max_backoff_ms = 30_000;
+/// A run at least this long counts as recovered and resets the retry count.
+const stable_run_ms = 10_000;
+
+pub const Track = struct {
+    attempts: u32 = 0,
+    restart_at_ms: ?i64 = null,
+    running_since_ms: ?i64 = null,
+    gave_up: bool = false,
+};
+
+/// Per-process restart bookkeeping keyed by process id, so processes added
+/// or removed by a config reload keep their own history.
+pub const Supervisor = struct {
+    runner: command_runner.Runner,
+    tracks: std.AutoHashMap(u32, Track),
+
+    pub fn init(allocator: std.mem.Allocator, runner: command_runner.Runner) Supervisor {
+        return .{ .runner = runner, .tracks = std.AutoHashMap(u32, Track).init(allocator) };
+    }
+
+    pub fn deinit(self: *Supervisor) void {
+        self.tracks.deinit();
+    }
+
+    pub fn track(self: *Supervisor, id: domain.process.ProcessId) ?*Track {
+        return self.tracks.getPtr(id.toInt());
+    }
+
+    /// Applies every process's restart policy once. `now_ms` is passed in so
+    /// backoff can be tested without waiting on the wall clock.
+    pub fn tick(self: *Supervisor, now_ms: i64) !void {
+        for (self.runner.state.processes.items) |*process| {
+            if (process.config.restart == .never) continue;
+            const entry = try self.tracks.getOrPut(process.id.toInt());
+            if (!entry.found_existing) entry.value_ptr.* = .{};
+            self.tickProcess(process, entry.value_ptr, now_ms);
+        }
+    }
+
+    fn tickProcess(self: *Supervisor, process: *domain.process.Process, state: *Track, now_ms: i64) void {
+        const controller = self.runner.controller;
+        if (controller.isRunning(process.id)) {
+            // Running again after giving up means the user started it.
+            if (state.gave_up) state.attempts = 0;
+            state.gave_up = false;
+            state.restart_at_ms = null;
+            const since = state.running_since_ms orelse now_ms;
+            state.running_since_ms = since;
+            if (now_ms - since >= stable_run_ms) state.attempts = 0;
+            return;
+        }
+        state.running_since_ms = null;
+
+        const status = controller.exitStatus(process.id) orelse {
+            state.restart_at_ms = null;
+            return;
+        };
+        if (process.config.restart == .on_failure and status == 0) return;
+        if (state.gave_up) return;
+
+        const max_retries = maxRetries(process.config);
+        if (state.attempts >= max_retries) {
+            log.warn("process '{s}' exited with status {d}; not restarting after {d} attempt(s)", .{
+                process.label,
+                status,
+                state.attempts,
+            });
+            state.gave_up = true;
+            return;
+        }
+
+        const restart_at = state.restart_at_ms orelse blk: {
+            const at = now_ms + backoffMs(process.config, state.attempts);
+            state.restart_at_ms = at;
+            break :blk at;
+        };
+        if (now_ms < restart_at) return;
+
+        state.restart_at_ms = null;
+        state.attempts += 1;
+        log.info("restarting process '{s}' after exit status {d} (attempt {d} of {d})", .{
+            process.label,
+            status,
+            state.attempts,
+            max_retries,
+        });
+        self.runner.startProcess(process) catch |err| {
+            log.warn("restart of process '{s}' failed: {s}", .{ process.label, @errorName(err) });
+        };
+    }
+};
+
+/// True when any process opts into restarts, so the watcher can be skipped.
+pub fn anyPolicy(cfg: *const config.schema.Config) bool {
+    for (cfg.procs.values()) |proc| {
+        if (proc.restart != .never) return true;
+    }
+    return false;
+}
+
+fn maxRetries(proc_cfg: *const config.schema.ProcessConfig) u32 {
+    if (proc_cfg.restart_max_retries > 0) return @intCast(proc_cfg.restart_max_retries);
+    return default_max_retries;
+}
+
+/// Doubles the configured backoff for each earlier attempt, capped so a long
+/// crash loop still retries within half a minute.
+fn backoffMs(proc_cfg: *const config.schema.ProcessConfig, attempts: u32) i64 {
+    const base: i64 = if (proc_cfg.restart_backoff_ms > 0) proc_cfg.restart_backoff_ms else default_backoff_ms;
+    const shift: u6 = @intCast(@min(attempts, 16));
+    return @min(base << shift, max_backoff_ms);
+}
+
+test "restart backoff doubles up to the cap" {
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+
+    try std.testing.expectEqual(@as(i64, 1000), backoffMs(&proc_cfg, 0));
+    try std.testing.expectEqual(@as(i64, 4000), backoffMs(&proc_cfg, 2));
+    try std.testing.expectEqual(@as(i64, 30_000), backoffMs(&proc_cfg, 10));
+
+    proc_cfg.restart_backoff_ms = 50;
+    try std.testing.expectEqual(@as(i64, 100), backoffMs(&proc_cfg, 1));
+    try std.testing.expectEqual(@as(u32, default_max_retries), maxRetries(&proc_cfg));
+}
@@ -6,7 +6,14 @@ const config = @import("../config/root.zig");
 const domain = @import("../domain/root.zig");
 const ipc = @import("../ipc/root.zig");
 const proc_mod = @import("../proc/root.zig");
+const terminal = @import("../terminal/root.zig");
 const command_runner = @import("command_runner.zig");
+const exit_hooks = @import("exit_hooks.zig");
+const reload = @import("reload.zig");
+const restarts = @import("restarts.zig");
+const status_endpoint = @import("status_endpoint.zig");
+const tasks = @import("tasks.zig");
+const timeouts = @import("timeouts.zig");
 const test_config = @import("../test_support/config.zig");
 const test_ipc = @import("../test_support/ipc.zig");
 
@@ -20,6 +27,29 @@ pub const Server = struct {
     state: domain.state.AppState,
     current_proc_id: std.atomic.Value(u32) = std.atomic.Value(u32).init(0),
     controller: proc_mod.controller.Controller,
+    socket_path: []const u8 = "",
+    /// Serializes Process Commands, startup and the state poller. Commands
+    /// hold it through readiness waits and stop timeouts, so snapshot readers
+    /// never take it.
+    command_mutex: std.Thread.Mutex = .{},
+    /// Guards the process list and config pointer that snapshots read. Only
+    /// reload changes those, and it already holds the command lock.
+    state_mutex: std.Thread.Mutex = .{},
+    /// Where `reload` reads config from; modes set these to their startup
+    /// arguments. Null means the working directory.
+    config_dir: ?std.fs.Dir = null,
+    config_files: []const []const u8 = &.{},
+    /// `--set` overrides, reapplied on every reload.
+    set_overrides: []const []const u8 = &.{},
+    /// Configs loaded by `reload`. Older ones stay alive because instances
+    /// started from them still borrow their process definitions.
+    reloaded: std.array_list.Managed(*config.runtime.LoadedRuntimeConfig),
+    /// `http_status_addr` listener, running while commands are served.
+    status_endpoint: status_endpoint.Slot = .{},
+    /// Raised once `setup` has succeeded, so `teardown` runs exactly once.
+    prepared: std.atomic.Value(bool) = std.atomic.Value(bool).init(false),
+    /// Which exits already ran their `on_success`/`on_failure` hook.
+    exit_hooks: exit_hooks.Notifier,
 
     pub fn init(allocator: std.mem.Allocator, cfg: *config.schema.Config) !Server {
         var state = try domain.state.AppState.init(allocator, cfg);
@@ -30,12 +60,61 @@ pub const Server = struct {
             .cfg = cfg,
             .state = state,
             .controller = proc_mod.controller.Controller.init(allocator, cfg),
+            .reloaded = std.array_list.Managed(*config.runtime.LoadedRuntimeConfig).init(allocator),
+            .exit_hooks = exit_hooks.Notifier.init(allocator),
         };
     }
 
     pub fn deinit(self: *Server) void {
+        self.status_endpoint.stop();
+        proc_mod.hooks.waitForPending();
         self.controller.deinit();
+        self.exit_hooks.deinit();
         self.state.deinit();
+        for (self.reloaded.items) |loaded| {
+            loaded.deinit();
+            self.allocator.destroy(loaded);
+        }
+        self.reloaded.deinit();
+    }
+
+    pub fn lockCommands(self: *Server) void {
+        self.command_mutex.lock();
+    }
+
+    pub fn unlockCommands(self: *Server) void {
+        self.command_mutex.unlock();
+    }
+
+    pub fn lockState(self: *Server) void {
+        self.state_mutex.lock();
+    }
+
+    pub fn unlockState(self: *Server) void {
+        self.state_mutex.unlock();
+    }
+
+    /// Re-reads the startup config files and reconciles running state with
+    /// them. On a load error the current config stays in use. The caller must
+    /// hold the command lock when other threads are serving.
+    pub fn reloadConfig(self: *Server) !reload.Summary {
+        const loaded = try self.allocator.create(config.runtime.LoadedRuntimeConfig);
+        errdefer self.allocator.destroy(loaded);
+        loaded.* = try config.runtime.loadFilesWithOverridesInDir(
+            self.allocator,
+            self.config_dir orelse std.fs.cwd(),
+            self.config_files,
+            self.set_overrides,
+        );
+        errdefer loaded.deinit();
+
+        try self.reloaded.ensureUnusedCapacity(1);
+        self.lockState();
+        defer self.unlockState();
+        const summary = try reload.reconcile(self.allocator, self.commandRunner(), &loaded.config);
+        self.reloaded.appendAssumeCapacity(loaded);
+        log.info("reloaded config: {d} added, {d} removed, {d} changed", .{ summary.added, summary.removed, summary.changed });
+        return summary;
     }
 
     pub fn getState(self: *Server) *domain.state.AppState {
@@ -71,16 +150,60 @@ pub const Server = struct {
         };
     }
 
-    /// Starts autostart processes before clients attach so initial snapshots
-    /// already reflect the configured startup state.
+    /// Starts autostart processes by `order`, then config file order, each
+    /// after its `depends_on` chain, before clients attach so initial snapshots already
+    /// reflect the configured startup state.
     pub fn startAutostartProcesses(self: *Server) void {
-        for (self.state.processes.items) |*process| {
-            if (process.config.autostart) self.startProcess(process) catch |err| {
+        self.autostartUntil(null);
+    }
+
+    fn autostartUntil(self: *Server, stopped: ?*const std.atomic.Value(bool)) void {
+        // Startup follows the config the server started with; state.config
+        // may be replaced by a reload while this waits.
+        const labels = config.depends.launchOrder(self.allocator, self.cfg) catch |err| {
+            log.warn("autostart failed: {s}", .{@errorName(err)});
+            return;
+        };
+        defer self.allocator.free(labels);
+
+        var launched = false;
+        for (labels) |label| {
+            const proc_cfg = self.cfg.procs.getPtr(label).?;
+            if (!proc_cfg.autostart) continue;
+
+            const delay_ms = autostartDelayMs(self.cfg, proc_cfg, launched);
+            if (delay_ms > 0) {
+                log.debug("waiting {d}ms before autostarting process '{s}'", .{ delay_ms, label });
+                if (!sleepUnlessStopped(delay_ms, stopped)) return;
+            }
+            launched = true;
+
+            self.lockCommands();
+            defer self.unlockCommands();
+            // A reload while waiting may have removed the process.
+            const process = self.state.getProcessByLabel(label) orelse continue;
+            if (self.controller.isRunning(process.id)) continue;
+            self.commandRunner().startWithDependencies(self.allocator, process) catch |err| {
                 log.warn("autostart failed for process '{s}': {s}", .{ process.label, @errorName(err) });
             };
         }
     }
 
+    /// Runs `pause` placeholders for processes that are not running, so their
+    /// terminals show something until they start. Processes that already hold
+    /// a placeholder or an exited run are left alone.
+    pub fn startPauseCommands(self: *Server) void {
+        self.lockCommands();
+        defer self.unlockCommands();
+        for (self.state.config.procs.keys()) |label| {
+            const process = self.state.getProcessByLabel(label) orelse continue;
+            if (self.controller.isRunning(process.id)) continue;
+            _ = self.controller.startPause(process.id, process.config) catch |err| {
+                log.warn("pause command failed for process '{s}': {s}", .{ process.label, @errorName(err) });
+            };
+        }
+    }
+
     /// Forwards raw terminal input to the selected process. Missing/stopped
     /// processes are ignored because process selection can race with exits.
     pub fn sendInputToCurrentProcess(self: *Server, bytes: []const u8) !void {
@@ -92,12 +215,45 @@ pub const Server = struct {
         };
     }
 
+    /// Starts `label` after its `depends_on` chain, as the `start` command does.
+    pub fn startWithDependencies(self: *Server, label: []const u8) !domain.process.ProcessId {
+        const process = self.state.getProcessByLabel(label) orelse return error.ProcessNotFound;
+        try self.commandRunner().startWithDependencies(self.allocator, process);
+        return process.id;
+    }
+
+    /// Serves IPC clients until `stopped` is raised. When `control_socket` is
+    /// configured, the text control socket runs alongside and is removed on exit.
     pub fn serveCommandsAtPath(
         self: *Server,
         socket_path: []const u8,
         stopped: *std.atomic.Value(bool),
     ) !void {
-        self.startAutostartProcesses();
+        try self.prepare();
+        defer self.teardown();
+        self.socket_path = socket_path;
+        defer self.socket_path = "";
+        const cleanup_slot = try terminal.shutdown.register(self.shutdownCleanup());
+        defer terminal.shutdown.unregister(cleanup_slot);
+
+        const startup_thread = try self.startStartup(stopped);
+        defer if (startup_thread) |thread| {
+            stopped.store(true, .seq_cst);
+            thread.join();
+        };
+        const control_thread = try self.startControlSocket(stopped);
+        defer if (control_thread) |thread| {
+            stopped.store(true, .seq_cst);
+            unblockSocket(self.cfg.control_socket);
+            thread.join();
+        };
+        self.status_endpoint.start(self.allocator, self.cfg.http_status_addr, self.snapshotProvider());
+        defer self.status_endpoint.stop();
+        const poller_thread = try self.startStatePoller(stopped);
+        defer if (poller_thread) |thread| {
+            stopped.store(true, .seq_cst);
+            thread.join();
+        };
         try ipc.server.serveCommandsAtPathWithSnapshots(
             self.allocator,
             socket_path,
@@ -107,6 +263,116 @@ pub const Server = struct {
         );
     }
 
+    /// Signal-time cleanup: closes the status endpoint, stops every process,
+    /// runs `teardown` and removes the socket files.
+    /// Normal shutdown repeats the same steps harmlessly.
+    pub fn shutdownCleanup(self: *Server) terminal.shutdown.Cleanup {
+        return .{ .context = self, .run = cleanupAdapter };
+    }
+
+    /// Socket files are only removed while serving, so a server that never
+    /// listened (the headless run mode) leaves another instance's sockets alone.
+    pub fn cleanup(self: *Server) void {
+        self.status_endpoint.stop();
+        self.controller.stopAll();
+        self.teardown();
+        if (self.socket_path.len == 0) return;
+        std.fs.deleteFileAbsolute(self.socket_path) catch {};
+        if (self.cfg.control_socket.len > 0) std.fs.deleteFileAbsolute(self.cfg.control_socket) catch {};
+    }
+
+    /// Runs the project `setup` command before any process starts. A failure
+    /// aborts startup and leaves `teardown` unarmed.
+    pub fn prepare(self: *Server) !void {
+        try proc_mod.setup.run(self.allocator, .setup, self.cfg);
+        self.prepared.store(true, .seq_cst);
+    }
+
+    /// Stops every process, then runs `teardown` once after a successful
+    /// `prepare`, whatever happened to the processes in between.
+    pub fn teardown(self: *Server) void {
+        if (!self.prepared.swap(false, .seq_cst)) return;
+        if (self.cfg.teardown.len == 0) return;
+        self.controller.stopAll();
+        proc_mod.setup.run(self.allocator, .teardown, self.cfg) catch |err| {
+            log.warn("teardown did not complete: {s}", .{@errorName(err)});
+        };
+    }
+
+    fn startControlSocket(self: *Server, stopped: *std.atomic.Value(bool)) !?std.Thread {
+        const path = self.cfg.control_socket;
+        if (path.len == 0) return null;
+        const thread = try std.Thread.spawn(.{}, runControlSocket, .{ self, path, stopped });
+        errdefer thread.join();
+        try ipc.socket.waitPath(path, 5 * 1000, 20);
+        return thread;
+    }
+
+    /// Autostart runs inline unless start delays are configured; then it moves
+    /// to a thread so clients can attach while processes are still launching.
+    fn startStartup(self: *Server, stopped: *std.atomic.Value(bool)) !?std.Thread {
+        if (!hasStartDelays(self.cfg)) {
+            self.startAutostartProcesses();
+            self.startPauseCommands();
+            return null;
+        }
+        return try std.Thread.spawn(.{}, runStartup, .{ self, stopped });
+    }
+
+    /// One thread serves every exit-driven feature, so a refresh reads process
+    /// state once per `refresh_interval_ms` however many are configured.
+    fn startStatePoller(self: *Server, stopped: *std.atomic.Value(bool)) !?std.Thread {
+        if (!restarts.anyPolicy(self.cfg) and
+            !timeouts.anyLimit(self.cfg) and
+            !exit_hooks.anyHook(self.cfg) and
+            !tasks.anyDismissed(self.cfg)) return null;
+        return try std.Thread.spawn(.{}, runStatePoller, .{ self, stopped });
+    }
+
+    /// One refresh under a single lock: runtime limits first, so an overdue
+    /// stop is seen as an exit by the hooks and restart policies in the same
+    /// pass, then dismissal of what should not remain.
+    pub fn refreshProcessState(self: *Server, supervisor: *restarts.Supervisor, now_ms: i64) void {
+        self.lockCommands();
+        defer self.unlockCommands();
+        const runner = self.commandRunner();
+        timeouts.Watchdog.init(runner).tick(now_ms);
+        self.exit_hooks.tick(runner) catch |err| {
+            log.warn("exit hook tick failed: {s}", .{@errorName(err)});
+        };
+        supervisor.tick(now_ms) catch |err| {
+            log.warn("restart tick failed: {s}", .{@errorName(err)});
+        };
+        tasks.tick(runner);
+    }
+
+    /// Stops processes that ran past `max_runtime_secs`. The state poller
+    /// covers this while serving; the headless run mode calls it from its poll loop.
+    pub fn enforceRuntimeLimits(self: *Server) void {
+        self.lockCommands();
+        defer self.unlockCommands();
+        timeouts.Watchdog.init(self.commandRunner()).tick(std.time.milliTimestamp());
+    }
+
+    /// Runs `on_success`/`on_failure` for processes that exited since the last
+    /// call. The state poller covers this while serving; the headless run mode
+    /// calls it from its poll loop.
+    pub fn runExitHooks(self: *Server) void {
+        self.lockCommands();
+        defer self.unlockCommands();
+        self.exit_hooks.tick(self.commandRunner()) catch |err| {
+            log.warn("exit hook watcher tick failed: {s}", .{@errorName(err)});
+        };
+    }
+
+    /// Dismisses tasks and `remain_on_exit: false` processes that exited
+    /// since the last call.
+    pub fn dismissExitedProcesses(self: *Server) void {
+        self.lockCommands();
+        defer self.unlockCommands();
+        tasks.tick(self.commandRunner());
+    }
+
     pub fn handleRequest(
         self: *Server,
         allocator: std.mem.Allocator,
@@ -120,16 +386,91 @@ pub const Server = struct {
             .state = &self.state,
             .controller = &self.controller,
             .current_process_id = &self.current_proc_id,
+            .reloader = .{ .context = self, .reload = reloadAdapter },
         };
     }
+};
+
+fn reloadAdapter(context: *anyopaque) anyerror!void {
+    const server: *Server = @ptrCast(@alignCast(context));
+    _ = try server.reloadConfig();
+}
+
+pub const default_refresh_interval_ms = 100;
+/// Shorter intervals are raised to this so the poller cannot spin on the lock.
+pub const min_refresh_interval_ms = 20;
+
+/// The configured `refresh_interval_ms`, or the default when it is unset,
+/// never below `min_refresh_interval_ms`.
+pub fn refreshIntervalMs(cfg: *const config.schema.Config) u32 {
+    if (cfg.refresh_interval_ms <= 0) return default_refresh_interval_ms;
+    return @intCast(@max(cfg.refresh_interval_ms, min_refresh_interval_ms));
+}
+
+/// A process's own `start_delay_ms` always applies; the top-level value only
+/// spaces out launches, so the first autostarted process is not held back.
+fn autostartDelayMs(cfg: *const config.schema.Config, proc_cfg: *const config.schema.ProcessConfig, launched: bool) u32 {
+    if (proc_cfg.start_delay_ms > 0) return @intCast(proc_cfg.start_delay_ms);
+    if (launched and cfg.start_delay_ms > 0) return @intCast(cfg.start_delay_ms);
+    return 0;
+}
+
+fn hasStartDelays(cfg: *const config.schema.Config) bool {
+    if (cfg.start_delay_ms > 0) return true;
+    for (cfg.procs.values()) |proc_cfg| {
+        if (proc_cfg.autostart and proc_cfg.start_delay_ms > 0) return true;
+    }
+    return false;
+}
 
-    fn startProcess(self: *Server, process: *domain.process.Process) !void {
-        if (self.controller.isRunning(process.id)) return;
-        try self.controller.cleanupProcess(process.id);
-        if (self.currentProcessID().isNone()) self.setCurrentProcess(process.id);
-        _ = try self.controller.startProcess(process.id, process.config);
+/// Sleeps in short slices so shutdown is not held up by a long start delay.
+/// Returns false when `stopped` was raised first.
+fn sleepUnlessStopped(delay_ms: u32, stopped: ?*const std.atomic.Value(bool)) bool {
+    const slice_ms: u32 = 50;
+    var remaining_ms = delay_ms;
+    while (remaining_ms > 0) {
+        if (stopped) |flag| if (flag.load(.seq_cst)) return false;
+        const current_ms = @min(slice_ms, remaining_ms);
+        std.Thread.sleep(@as(u64, current_ms) * std.time.ns_per_ms);
+        remaining_ms -= current_ms;
     }
-};
+    return true;
+}
+
+/// Placeholders go up first so processes waiting on a start delay show them;
+/// the second pass covers autostarts that failed and dropped theirs.
+fn runStartup(server: *Server, stopped: *const std.atomic.Value(bool)) void {
+    server.startPauseCommands();
+    server.autostartUntil(stopped);
+    if (stopped.load(.seq_cst)) return;
+    server.startPauseCommands();
+}
+
+fn cleanupAdapter(context: *anyopaque) void {
+    const server: *Server = @ptrCast(@alignCast(context));
+    server.cleanup();
+}
+
+fn runControlSocket(server: *Server, path: []const u8, stopped: *std.atomic.Value(bool)) void {
+    ipc.control.serveAtPath(server.allocator, path, server.commandHandler(), server.snapshotProvider(), stopped) catch |err| {
+        log.warn("control socket '{s}' failed: {s}", .{ path, @errorName(err) });
+    };
+}
+
+fn runStatePoller(server: *Server, stopped: *std.atomic.Value(bool)) void {
+    var supervisor = restarts.Supervisor.init(server.allocator, server.commandRunner());
+    defer supervisor.deinit();
+    const interval_ms = refreshIntervalMs(server.cfg);
+    while (!stopped.load(.seq_cst)) {
+        server.refreshProcessState(&supervisor, std.time.milliTimestamp());
+        _ = sleepUnlessStopped(interval_ms, stopped);
+    }
+}
+
+fn unblockSocket(path: []const u8) void {
+    var stream = std.net.connectUnixSocket(path) catch return;
+    stream.close();
+}
 
 fn handleCommandAdapter(
     context: *anyopaque,
@@ -137,13 +478,19 @@ fn handleCommandAdapter(
     request: ipc.protocol.CommandRequest,
 ) !ipc.protocol.Response {
     const self: *Server = @ptrCast(@alignCast(context));
+    self.lockCommands();
+    defer self.unlockCommands();
     return self.handleRequest(allocator, request);
 }
 
 fn snapshotLineAdapter(context: *anyopaque, allocator: std.mem.Allocator) ![]const u8 {
     const self: *Server = @ptrCast(@alignCast(context));
+    self.lockState();
+    defer self.unlockState();
     var snapshot = try domain.client_snapshot.fromAppState(allocator, &self.state, self.getProcessController());
     defer snapshot.deinit(allocator);
+    // Commands change the selection without the state lock.
+    snapshot.value.current_process_id = self.currentProcessID().toInt();
     return ipc.protocol.snapshotLine(allocator, snapshot.view());
 }
 
@@ -184,6 +531,61 @@ test "primary command handler starts switches and stops processes" {
     try std.testing.expect(!primary.controller.isRunning(domain.process.ProcessId.fromInt(1)));
 }
 
+test "primary restart starts a never-started process and keeps its id selected" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "api", "sleep 5", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "worker", "sleep 5", 500);
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+
+    const worker_id = primary.getState().getProcessByLabel("worker").?.id;
+    for ([_]u64{ 1, 2 }) |request_id| {
+        var restarted = try primary.handleRequest(std.testing.allocator, .{
+            .request_id = request_id,
+            .action = .restart,
+            .target = "worker",
+        });
+        defer restarted.deinit(std.testing.allocator);
+        try std.testing.expect(restarted.success);
+        try std.testing.expect(primary.controller.isRunning(worker_id));
+        try std.testing.expectEqual(worker_id, primary.currentProcessID());
+    }
+}
+
+test "primary teardown runs once after setup and stops processes first" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    const dir_path = try tmp.dir.realpathAlloc(std.testing.allocator, ".");
+    defer std.testing.allocator.free(dir_path);
+    const file_path = try std.fs.path.join(std.testing.allocator, &.{ dir_path, "proctmux.yaml" });
+    defer std.testing.allocator.free(file_path);
+
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "api", "sleep 5", 500);
+    cfg.file_path = file_path;
+    cfg.teardown = "echo down >> teardown.txt";
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+
+    primary.teardown();
+    try std.testing.expectError(error.FileNotFound, tmp.dir.access("teardown.txt", .{}));
+
+    try primary.prepare();
+    const api_id = try primary.startWithDependencies("api");
+    primary.cleanup();
+    primary.cleanup();
+    try std.testing.expect(!primary.controller.isRunning(api_id));
+    const written = try tmp.dir.readFileAlloc(std.testing.allocator, "teardown.txt", 64);
+    defer std.testing.allocator.free(written);
+    try std.testing.expectEqualStrings("down\n", written);
+}
+
 test "primary command handler stops all running processes" {
     var cfg = config.schema.Config.empty(std.testing.allocator);
     defer cfg.deinit();
@@ -251,6 +653,208 @@ test "primary startup starts autostart processes only" {
     try std.testing.expect(!primary.controller.isRunning(domain.process.ProcessId.fromInt(2)));
 }
 
+test "primary startup runs pause commands until the real start" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "api", "echo real-api; sleep 5", 500);
+    cfg.pause = "echo press-start; sleep 5";
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+
+    const id = domain.process.ProcessId.fromInt(1);
+    primary.startAutostartProcesses();
+    primary.startPauseCommands();
+    try waitForPrimaryScrollbackContains(&primary, id, "press-start");
+    try std.testing.expect(!primary.controller.isRunning(id));
+    try std.testing.expectEqual(@as(i32, -1), primary.controller.getPID(id));
+
+    var response = try primary.handleRequest(std.testing.allocator, .{
+        .request_id = 1,
+        .action = .start,
+        .target = "api",
+    });
+    defer response.deinit(std.testing.allocator);
+    try std.testing.expect(response.success);
+    try std.testing.expect(primary.controller.isRunning(id));
+    try waitForPrimaryScrollbackContains(&primary, id, "real-api");
+
+    var stopped = try primary.handleRequest(std.testing.allocator, .{
+        .request_id = 2,
+        .action = .stop,
+        .target = "api",
+    });
+    defer stopped.deinit(std.testing.allocator);
+    try std.testing.expect(stopped.success);
+    try waitForPrimaryScrollbackContains(&primary, id, "press-start");
+    try waitForPrimaryScrollbackContains(&primary, id, "real-api");
+    try std.testing.expect(!primary.controller.isRunning(id));
+}
+
+test "primary start of a running process spawns nothing and can switch to it" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "api", "sleep 5", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "worker", "sleep 5", 500);
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+
+    const api = domain.process.ProcessId.fromInt(1);
+    var first = try primary.handleRequest(std.testing.allocator, .{ .request_id = 1, .action = .start, .target = "api" });
+    defer first.deinit(std.testing.allocator);
+    try std.testing.expect(first.success);
+    const pid = primary.controller.getPID(api);
+
+    var second = try primary.handleRequest(std.testing.allocator, .{ .request_id = 2, .action = .start, .target = "api" });
+    defer second.deinit(std.testing.allocator);
+    try std.testing.expect(second.success);
+    try std.testing.expectEqualStrings("api is already running", second.message);
+    try std.testing.expectEqual(pid, primary.controller.getPID(api));
+
+    var worker = try primary.handleRequest(std.testing.allocator, .{ .request_id = 3, .action = .start, .target = "worker" });
+    defer worker.deinit(std.testing.allocator);
+    primary.setCurrentProcess(domain.process.ProcessId.fromInt(2));
+
+    var noticed = try primary.handleRequest(std.testing.allocator, .{ .request_id = 4, .action = .start, .target = "api" });
+    defer noticed.deinit(std.testing.allocator);
+    try std.testing.expectEqual(domain.process.ProcessId.fromInt(2), primary.currentProcessID());
+
+    cfg.start_when_running = .focus;
+    var focused = try primary.handleRequest(std.testing.allocator, .{ .request_id = 5, .action = .start, .target = "api" });
+    defer focused.deinit(std.testing.allocator);
+    try std.testing.expectEqualStrings("api is already running", focused.message);
+    try std.testing.expectEqual(api, primary.currentProcessID());
+    try std.testing.expectEqual(pid, primary.controller.getPID(api));
+}
+
+test "primary autostart follows config order and continues past failures" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "zeta", "sleep 5", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "broken", "sleep 5", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "alpha", "sleep 5", 500);
+    cfg.procs.getPtr("zeta").?.autostart = true;
+    cfg.procs.getPtr("broken").?.autostart = true;
+    cfg.procs.getPtr("broken").?.cwd = try std.testing.allocator.dupe(u8, "/tmp/proctmux-zig-autostart-missing-cwd");
+    cfg.procs.getPtr("alpha").?.autostart = true;
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+
+    primary.startAutostartProcesses();
+
+    const zeta = primary.state.getProcessByLabel("zeta").?;
+    const broken = primary.state.getProcessByLabel("broken").?;
+    const alpha = primary.state.getProcessByLabel("alpha").?;
+    try std.testing.expect(primary.controller.isRunning(zeta.id));
+    try std.testing.expect(!primary.controller.isRunning(broken.id));
+    try std.testing.expect(primary.controller.isRunning(alpha.id));
+    try std.testing.expectEqual(zeta.id, primary.currentProcessID());
+}
+
+test "primary autostart launches by order before config order" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "zeta", "sleep 5", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "alpha", "sleep 5", 500);
+    for (cfg.procs.values()) |*proc_cfg| proc_cfg.autostart = true;
+    cfg.procs.getPtr("alpha").?.order = 1;
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+
+    primary.startAutostartProcesses();
+
+    const zeta = primary.state.getProcessByLabel("zeta").?;
+    const alpha = primary.state.getProcessByLabel("alpha").?;
+    try std.testing.expect(primary.controller.isRunning(zeta.id));
+    try std.testing.expectEqual(alpha.id, primary.currentProcessID());
+}
+
+test "primary autostart waits between launches" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "db", "sleep 5", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "api", "sleep 5", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "web", "sleep 5", 500);
+    for (cfg.procs.values()) |*proc_cfg| proc_cfg.autostart = true;
+    cfg.procs.getPtr("web").?.start_delay_ms = 150;
+    cfg.start_delay_ms = 100;
+
+    try std.testing.expectEqual(@as(u32, 0), autostartDelayMs(&cfg, cfg.procs.getPtr("db").?, false));
+    try std.testing.expectEqual(@as(u32, 100), autostartDelayMs(&cfg, cfg.procs.getPtr("api").?, true));
+    try std.testing.expectEqual(@as(u32, 150), autostartDelayMs(&cfg, cfg.procs.getPtr("web").?, false));
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+
+    const started_at = std.time.milliTimestamp();
+    primary.startAutostartProcesses();
+    try std.testing.expect(std.time.milliTimestamp() - started_at >= 250);
+    for (primary.state.processes.items) |process| {
+        try std.testing.expect(primary.controller.isRunning(process.id));
+    }
+}
+
+test "primary start_all and stop_all cover every process and collect failures" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "web", "sleep 5", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "broken", "sleep 5", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "api", "sleep 5", 500);
+    try config.schema.appendOwned(std.testing.allocator, &cfg.procs.getPtr("web").?.depends_on, "api");
+    cfg.procs.getPtr("broken").?.cwd = try std.testing.allocator.dupe(u8, "/nonexistent/proctmux-start-all");
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+    const web = primary.state.getProcessByLabel("web").?.id;
+    const api = primary.state.getProcessByLabel("api").?.id;
+
+    var started = try primary.handleRequest(std.testing.allocator, .{ .request_id = 1, .action = .start_all });
+    defer started.deinit(std.testing.allocator);
+    try std.testing.expect(!started.success);
+    try std.testing.expectEqualStrings("start all: 1 failed: broken (ProcessCwdNotFound)", started.error_message);
+    try std.testing.expect(primary.controller.isRunning(web));
+    try std.testing.expect(primary.controller.isRunning(api));
+
+    var stopped = try primary.handleRequest(std.testing.allocator, .{ .request_id = 2, .action = .stop_all });
+    defer stopped.deinit(std.testing.allocator);
+    try std.testing.expect(stopped.success);
+    try std.testing.expect(!primary.controller.isRunning(web));
+    try std.testing.expect(!primary.controller.isRunning(api));
+}
+
+test "primary autostart starts dependencies first" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "worker", "sleep 5", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "api", "sleep 5", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "idle", "sleep 5", 500);
+    cfg.procs.getPtr("worker").?.autostart = true;
+    try config.schema.appendOwned(std.testing.allocator, &cfg.procs.getPtr("worker").?.depends_on, "api");
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+
+    primary.startAutostartProcesses();
+
+    const worker = primary.state.getProcessByLabel("worker").?;
+    const api = primary.state.getProcessByLabel("api").?;
+    const idle = primary.state.getProcessByLabel("idle").?;
+    try std.testing.expect(primary.controller.isRunning(api.id));
+    try std.testing.expect(primary.controller.isRunning(worker.id));
+    try std.testing.expect(!primary.controller.isRunning(idle.id));
+    try std.testing.expectEqual(api.id, primary.currentProcessID());
+}
+
 test "primary can start a process again after natural exit" {
     var cfg = config.schema.Config.empty(std.testing.allocator);
     defer cfg.deinit();
@@ -279,6 +883,185 @@ test "primary can start a process again after natural exit" {
     try std.testing.expect(second.success);
 }
 
+test "primary restarts failing processes until retries run out" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "flaky", "exit 3", 500);
+    const proc_cfg = cfg.procs.getPtr("flaky").?;
+    proc_cfg.restart = .on_failure;
+    proc_cfg.restart_max_retries = 2;
+    proc_cfg.restart_backoff_ms = 1;
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+    const id = domain.process.ProcessId.fromInt(1);
+
+    var supervisor = restarts.Supervisor.init(std.testing.allocator, primary.commandRunner());
+    defer supervisor.deinit();
+    try primary.commandRunner().startProcess(&primary.state.processes.items[0]);
+
+    var now_ms: i64 = 0;
+    while (now_ms < 2000) : (now_ms += 10) {
+        try supervisor.tick(now_ms);
+        if (supervisor.track(id).?.gave_up) break;
+        std.Thread.sleep(5 * std.time.ns_per_ms);
+    }
+    try std.testing.expect(supervisor.track(id).?.gave_up);
+    try std.testing.expectEqual(@as(u32, 2), supervisor.track(id).?.attempts);
+    try waitForProcessStopped(&primary, id);
+    try std.testing.expectEqual(@as(?u32, 3), primary.controller.exitStatus(id));
+}
+
+test "primary runs the failure hook once when a process exits on its own" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    const cwd = try tmp.dir.realpathAlloc(std.testing.allocator, ".");
+    defer std.testing.allocator.free(cwd);
+
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "job", "exit 3", 500);
+    const proc_cfg = cfg.procs.getPtr("job").?;
+    proc_cfg.cwd = try std.testing.allocator.dupe(u8, cwd);
+    proc_cfg.on_success = try std.testing.allocator.dupe(u8, "echo ok >> hook.txt");
+    proc_cfg.on_failure = try std.testing.allocator.dupe(u8, "echo \"failed $PROCTMUX_EXIT_CODE\" >> hook.txt");
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+    const id = domain.process.ProcessId.fromInt(1);
+    try primary.commandRunner().startProcess(&primary.state.processes.items[0]);
+    try waitForProcessStopped(&primary, id);
+
+    primary.runExitHooks();
+    primary.runExitHooks();
+    proc_mod.hooks.waitForPending();
+    const written = try tmp.dir.readFileAlloc(std.testing.allocator, "hook.txt", 64);
+    defer std.testing.allocator.free(written);
+    try std.testing.expectEqualStrings("failed 3\n", written);
+}
+
+test "primary dismisses finished tasks but keeps failed ones when asked" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "build", "echo built", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "migrate", "echo boom; exit 4", 500);
+    cfg.procs.getPtr("build").?.task = true;
+    cfg.procs.getPtr("migrate").?.task = true;
+    cfg.procs.getPtr("migrate").?.keep_on_failure = true;
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+    const build_id = domain.process.ProcessId.fromInt(1);
+    const migrate_id = domain.process.ProcessId.fromInt(2);
+    try primary.commandRunner().startProcess(&primary.state.processes.items[0]);
+    try primary.commandRunner().startProcess(&primary.state.processes.items[1]);
+    try waitForProcessStopped(&primary, build_id);
+    try waitForProcessStopped(&primary, migrate_id);
+
+    primary.dismissExitedProcesses();
+    try std.testing.expectEqual(@as(?u32, 0), primary.controller.exitStatus(build_id));
+    try std.testing.expect(!try primary.controller.dismissExited(build_id));
+    const build_output = try primary.controller.getScrollback(std.testing.allocator, build_id);
+    defer std.testing.allocator.free(build_output);
+    try std.testing.expectEqualStrings("", build_output);
+
+    try std.testing.expectEqual(@as(?u32, 4), primary.controller.exitStatus(migrate_id));
+    try std.testing.expect(try primary.controller.dismissExited(migrate_id));
+}
+
+test "primary dismisses exited processes unless they remain on exit" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    cfg.remain_on_exit = false;
+    try test_config.putShellProcessWithStopTimeout(&cfg, "lint", "echo clean", 500);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "server", "echo crashed; exit 1", 500);
+    cfg.procs.getPtr("server").?.remain_on_exit = true;
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+    const lint_id = domain.process.ProcessId.fromInt(1);
+    const server_id = domain.process.ProcessId.fromInt(2);
+    try primary.commandRunner().startProcess(&primary.state.processes.items[0]);
+    try primary.commandRunner().startProcess(&primary.state.processes.items[1]);
+    try waitForProcessStopped(&primary, lint_id);
+    try waitForProcessStopped(&primary, server_id);
+
+    primary.dismissExitedProcesses();
+    try std.testing.expectEqual(@as(?u32, 0), primary.controller.exitStatus(lint_id));
+    try std.testing.expect(!try primary.controller.dismissExited(lint_id));
+    try std.testing.expectEqual(@as(?u32, 1), primary.controller.exitStatus(server_id));
+    try std.testing.expect(try primary.controller.dismissExited(server_id));
+}
+
+test "primary stops processes that run past max_runtime_secs and marks them timed out" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "slow", "sleep 5", 500);
+    cfg.procs.getPtr("slow").?.max_runtime_secs = 2;
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+    const id = domain.process.ProcessId.fromInt(1);
+    try primary.commandRunner().startProcess(&primary.state.processes.items[0]);
+    const started_at = primary.controller.runningSinceMs(id).?;
+
+    const watchdog = timeouts.Watchdog.init(primary.commandRunner());
+    watchdog.tick(started_at + 1000);
+    try std.testing.expect(primary.controller.isRunning(id));
+    try std.testing.expect(!primary.controller.timedOut(id));
+
+    watchdog.tick(started_at + 2000);
+    try std.testing.expect(!primary.controller.isRunning(id));
+    try std.testing.expect(primary.controller.timedOut(id));
+    try std.testing.expectEqual(@as(?u32, null), primary.controller.exitStatus(id));
+
+    try primary.commandRunner().startProcess(&primary.state.processes.items[0]);
+    try std.testing.expect(!primary.controller.timedOut(id));
+}
+
+test "primary refresh pass runs exit hooks before dismissing a task" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    const cwd = try tmp.dir.realpathAlloc(std.testing.allocator, ".");
+    defer std.testing.allocator.free(cwd);
+
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "migrate", "exit 2", 500);
+    const proc_cfg = cfg.procs.getPtr("migrate").?;
+    proc_cfg.task = true;
+    proc_cfg.cwd = try std.testing.allocator.dupe(u8, cwd);
+    proc_cfg.on_failure = try std.testing.allocator.dupe(u8, "echo \"failed $PROCTMUX_EXIT_CODE\" >> hook.txt");
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+    const id = domain.process.ProcessId.fromInt(1);
+    var supervisor = restarts.Supervisor.init(std.testing.allocator, primary.commandRunner());
+    defer supervisor.deinit();
+    try primary.commandRunner().startProcess(&primary.state.processes.items[0]);
+    try waitForProcessStopped(&primary, id);
+
+    primary.refreshProcessState(&supervisor, std.time.milliTimestamp());
+    proc_mod.hooks.waitForPending();
+    const written = try tmp.dir.readFileAlloc(std.testing.allocator, "hook.txt", 64);
+    defer std.testing.allocator.free(written);
+    try std.testing.expectEqualStrings("failed 2\n", written);
+    try std.testing.expectEqual(@as(?u32, 2), primary.controller.exitStatus(id));
+    try std.testing.expect(!try primary.controller.dismissExited(id));
+
+    try std.testing.expectEqual(@as(u32, default_refresh_interval_ms), refreshIntervalMs(&cfg));
+    cfg.refresh_interval_ms = 5;
+    try std.testing.expectEqual(@as(u32, min_refresh_interval_ms), refreshIntervalMs(&cfg));
+    cfg.refresh_interval_ms = 500;
+    try std.testing.expectEqual(@as(u32, 500), refreshIntervalMs(&cfg));
+}
+
 test "primary forwards stdin bytes to selected running process" {
     var cfg = config.schema.Config.empty(std.testing.allocator);
     defer cfg.deinit();
@@ -317,6 +1100,172 @@ test "primary forwards stdin bytes to selected running process" {
     try waitForPrimaryScrollbackContains(&primary, domain.process.ProcessId.fromInt(1), "got:hello");
 }
 
+test "primary send_input writes a typed line to the named process" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "repl", "IFS= read line; printf 'got:%s' \"$line\"", 500);
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+
+    var stopped = try primary.handleRequest(std.testing.allocator, .{
+        .request_id = 1,
+        .action = .send_input,
+        .target = "repl",
+        .input = "early\r",
+    });
+    defer stopped.deinit(std.testing.allocator);
+    try std.testing.expect(!stopped.success);
+    try std.testing.expectEqualStrings("repl is not running", stopped.error_message);
+
+    var started = try primary.handleRequest(std.testing.allocator, .{
+        .request_id = 2,
+        .action = .start,
+        .target = "repl",
+    });
+    defer started.deinit(std.testing.allocator);
+    try std.testing.expect(started.success);
+
+    var sent = try primary.handleRequest(std.testing.allocator, .{
+        .request_id = 3,
+        .action = .send_input,
+        .target = "repl",
+        .input = "a;b $x\r",
+    });
+    defer sent.deinit(std.testing.allocator);
+    try std.testing.expect(sent.success);
+    try waitForPrimaryScrollbackContains(&primary, domain.process.ProcessId.fromInt(1), "got:a;b $x");
+}
+
+test "primary clear_output drops retained output of a running process" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "api", "printf 'before\\n'; IFS= read line; printf 'after\\n'; sleep 5", 500);
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+    const id = domain.process.ProcessId.fromInt(1);
+
+    var idle = try primary.handleRequest(std.testing.allocator, .{ .request_id = 1, .action = .clear_output, .target = "api" });
+    defer idle.deinit(std.testing.allocator);
+    try std.testing.expect(idle.success);
+
+    var started = try primary.handleRequest(std.testing.allocator, .{ .request_id = 2, .action = .start, .target = "api" });
+    defer started.deinit(std.testing.allocator);
+    try std.testing.expect(started.success);
+    try waitForPrimaryScrollbackContains(&primary, id, "before");
+
+    var cleared = try primary.handleRequest(std.testing.allocator, .{ .request_id = 3, .action = .clear_output, .target = "api" });
+    defer cleared.deinit(std.testing.allocator);
+    try std.testing.expect(cleared.success);
+
+    var sent = try primary.handleRequest(std.testing.allocator, .{ .request_id = 4, .action = .send_input, .target = "api", .input = "\r" });
+    defer sent.deinit(std.testing.allocator);
+    try waitForPrimaryScrollbackContains(&primary, id, "after");
+    const bytes = try primary.controller.getScrollback(std.testing.allocator, id);
+    defer std.testing.allocator.free(bytes);
+    try std.testing.expect(std.mem.indexOf(u8, bytes, "before") == null);
+}
+
+test "primary copy_output pipes plain output to the clipboard command" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    const dir_path = try tmp.dir.realpathAlloc(std.testing.allocator, ".");
+    defer std.testing.allocator.free(dir_path);
+    const clipboard_command = try std.fmt.allocPrint(std.testing.allocator, "cat > '{s}/copied.txt'", .{dir_path});
+    defer std.testing.allocator.free(clipboard_command);
+
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    cfg.clipboard_command = clipboard_command;
+    try test_config.putShellProcessWithStopTimeout(&cfg, "api", "printf '\\033[31mboom\\033[0m\\n'; sleep 5", 500);
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+
+    var empty = try primary.handleRequest(std.testing.allocator, .{ .request_id = 1, .action = .copy_output, .target = "api" });
+    defer empty.deinit(std.testing.allocator);
+    try std.testing.expect(!empty.success);
+    try std.testing.expectEqualStrings("api has no output to copy", empty.error_message);
+
+    var started = try primary.handleRequest(std.testing.allocator, .{ .request_id = 2, .action = .start, .target = "api" });
+    defer started.deinit(std.testing.allocator);
+    try waitForPrimaryScrollbackContains(&primary, domain.process.ProcessId.fromInt(1), "boom");
+
+    var copied = try primary.handleRequest(std.testing.allocator, .{ .request_id = 3, .action = .copy_output, .target = "api" });
+    defer copied.deinit(std.testing.allocator);
+    try std.testing.expect(copied.success);
+    try std.testing.expectEqualStrings("copied api output to the clipboard", copied.message);
+    const text = try tmp.dir.readFileAlloc(std.testing.allocator, "copied.txt", 64);
+    defer std.testing.allocator.free(text);
+    try std.testing.expectEqualStrings("boom\n", text);
+}
+
+test "primary reload adds removes and flags processes from the config file" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    try tmp.dir.writeFile(.{ .sub_path = "proctmux.yaml", .data =
+        \\procs:
+        \\  api:
+        \\    shell: "sleep 5"
+        \\    stop_timeout_ms: 500
+        \\  old:
+        \\    shell: "sleep 5"
+        \\    stop_timeout_ms: 500
+        \\
+    });
+    const config_files = [_][]const u8{"proctmux.yaml"};
+
+    var loaded = try config.runtime.loadFilesInDir(std.testing.allocator, tmp.dir, &config_files);
+    defer loaded.deinit();
+    var primary = try Server.init(std.testing.allocator, &loaded.config);
+    defer primary.deinit();
+    primary.config_dir = tmp.dir;
+    primary.config_files = &config_files;
+
+    for ([_][]const u8{ "api", "old" }, 1..) |label, request_id| {
+        var started = try primary.handleRequest(std.testing.allocator, .{
+            .request_id = request_id,
+            .action = .start,
+            .target = label,
+        });
+        defer started.deinit(std.testing.allocator);
+        try std.testing.expect(started.success);
+    }
+    const old_id = primary.state.getProcessByLabel("old").?.id;
+
+    try tmp.dir.writeFile(.{ .sub_path = "proctmux.yaml", .data = "procs: 5\n" });
+    if (primary.reloadConfig()) |_| return error.TestUnexpectedResult else |_| {}
+    try std.testing.expect(primary.state.config == &loaded.config);
+
+    try tmp.dir.writeFile(.{ .sub_path = "proctmux.yaml", .data =
+        \\procs:
+        \\  api:
+        \\    shell: "sleep 6"
+        \\    stop_timeout_ms: 500
+        \\  web:
+        \\    shell: "sleep 5"
+        \\
+    });
+    var reloaded = try primary.handleRequest(std.testing.allocator, .{
+        .request_id = 3,
+        .action = .reload,
+    });
+    defer reloaded.deinit(std.testing.allocator);
+    try std.testing.expect(reloaded.success);
+
+    try std.testing.expectEqual(@as(usize, 2), primary.state.processes.items.len);
+    try std.testing.expect(primary.state.getProcessByLabel("old") == null);
+    try std.testing.expect(!primary.controller.isRunning(old_id));
+    const api = primary.state.getProcessByLabel("api").?;
+    try std.testing.expect(api.config_changed);
+    try std.testing.expectEqualStrings("sleep 6", api.config.shell);
+    try std.testing.expect(!primary.state.getProcessByLabel("web").?.config_changed);
+}
+
 test "primary snapshot provider serializes minimal snapshot" {
     var cfg = config.schema.Config.empty(std.testing.allocator);
     defer cfg.deinit();
@@ -339,6 +1288,29 @@ test "primary snapshot provider serializes minimal snapshot" {
     try std.testing.expectEqualStrings("api", snapshot.processes[0].label);
     try std.testing.expect(std.mem.indexOf(u8, line, "TOKEN") == null);
     try std.testing.expect(std.mem.indexOf(u8, line, "\"env\"") == null);
+    try std.testing.expect(std.mem.indexOf(u8, line, "sleep 5") == null);
+}
+
+test "primary snapshots do not wait for a running command" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "api", "sleep 5", 500);
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+    primary.setCurrentProcess(domain.process.ProcessId.fromInt(1));
+
+    // Stands in for a restart or dependency start that is still waiting.
+    primary.lockCommands();
+    defer primary.unlockCommands();
+    const provider = primary.snapshotProvider();
+    const line = try provider.snapshot_line(provider.context, std.testing.allocator);
+    defer std.testing.allocator.free(line);
+
+    var update = try ipc.protocol.parseSnapshotLine(std.testing.allocator, line);
+    defer update.deinit();
+    try std.testing.expectEqual(@as(u32, 1), update.snapshot().current_process_id);
 }
 
 test "primary command server handles repeated IPC clients" {
@@ -380,6 +1352,75 @@ test "primary command server handles repeated IPC clients" {
     if (run.err) |err| return err;
 }
 
+test "primary control socket runs text commands and is removed on shutdown" {
+    const path = "/tmp/proctmux-zig-primary-control-loop-test.socket";
+    const control_path = "/tmp/proctmux-zig-primary-control-text-test.socket";
+    std.fs.deleteFileAbsolute(path) catch {};
+    defer std.fs.deleteFileAbsolute(path) catch {};
+    defer std.fs.deleteFileAbsolute(control_path) catch {};
+
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "api", "sleep 5", 500);
+    cfg.control_socket = control_path;
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+
+    var stopped = std.atomic.Value(bool).init(false);
+    var run = PrimaryServerRun{
+        .primary = &primary,
+        .path = path,
+        .stopped = &stopped,
+    };
+    const thread = try std.Thread.spawn(.{}, runPrimaryServer, .{&run});
+    test_ipc.waitForSocketFile(path);
+
+    var stream = try std.net.connectUnixSocket(control_path);
+    defer stream.close();
+    try stream.writeAll("start api\n");
+    const reply = try ipc.line.readTimeout(std.testing.allocator, stream, 1024, 2000);
+    defer std.testing.allocator.free(reply);
+    try std.testing.expectEqualStrings("ok\n", reply);
+    try std.testing.expect(primary.controller.isRunning(domain.process.ProcessId.fromInt(1)));
+
+    stopped.store(true, .seq_cst);
+    test_ipc.unblockServer(path);
+    thread.join();
+    if (run.err) |err| return err;
+    try std.testing.expectError(error.FileNotFound, std.fs.accessAbsolute(control_path, .{}));
+}
+
+test "primary cleanup stops processes and tolerates a second run" {
+    const path = "/tmp/proctmux-zig-primary-cleanup-test.socket";
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    try config.defaults.apply(&cfg, std.testing.allocator);
+    try test_config.putShellProcessWithStopTimeout(&cfg, "api", "sleep 5", 500);
+
+    var primary = try Server.init(std.testing.allocator, &cfg);
+    defer primary.deinit();
+    var started = try primary.handleRequest(std.testing.allocator, .{
+        .request_id = 1,
+        .action = .start,
+        .target = "api",
+    });
+    defer started.deinit(std.testing.allocator);
+    try std.testing.expect(started.success);
+
+    const file = try std.fs.createFileAbsolute(path, .{});
+    file.close();
+    primary.socket_path = path;
+
+    const cleanup = primary.shutdownCleanup();
+    cleanup.run(cleanup.context);
+    cleanup.run(cleanup.context);
+
+    try std.testing.expect(!primary.controller.isRunning(domain.process.ProcessId.fromInt(1)));
+    try std.testing.expectError(error.FileNotFound, std.fs.accessAbsolute(path, .{}));
+}
+
 const PrimaryServerRun = struct {
     primary: *Server,
     path: []const u8,
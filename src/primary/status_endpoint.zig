@@ -0,0 +1,102 @@
+//! HTTP status endpoint lifecycle for the Primary Server.
+//! The endpoint binds once when serving starts, answers on its own thread, and is torn down by server cleanup; a bad address or bind failure only warns.
+
+const std = @import("std");
+const ipc = @import("../ipc/root.zig");
+
+const log = std.log.scoped(.primary_status);
+
+pub const Endpoint = struct {
+    listener: std.net.Server,
+    thread: std.Thread,
+    stopped: std.atomic.Value(bool) = std.atomic.Value(bool).init(false),
+};
+
+/// Holds at most one running endpoint. `stop` is safe to call repeatedly and
+/// from the signal cleanup thread.
+pub const Slot = struct {
+    mutex: std.Thread.Mutex = .{},
+    endpoint: ?Endpoint = null,
+
+    /// Binds `address` and starts answering requests. An empty address does
+    /// nothing; failures are logged and leave the slot empty.
+    pub fn start(
+        self: *Slot,
+        allocator: std.mem.Allocator,
+        address: []const u8,
+        snapshot_provider: ipc.interfaces.SnapshotProvider,
+    ) void {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        if (address.len == 0 or self.endpoint != null) return;
+
+        const listener = ipc.http_status.listen(address) catch |err| {
+            log.warn("http status endpoint disabled; cannot listen on '{s}': {s}", .{ address, @errorName(err) });
+            return;
+        };
+        self.endpoint = .{ .listener = listener, .thread = undefined };
+        const endpoint = &self.endpoint.?;
+        endpoint.thread = std.Thread.spawn(.{}, ipc.http_status.serve, .{
+            allocator,
+            &endpoint.listener,
+            snapshot_provider,
+            &endpoint.stopped,
+        }) catch |err| {
+            log.warn("http status endpoint disabled; cannot start its thread: {s}", .{@errorName(err)});
+            endpoint.listener.deinit();
+            self.endpoint = null;
+            return;
+        };
+        log.info("serving http status on {s}", .{address});
+    }
+
+    pub fn stop(self: *Slot) void {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        if (self.endpoint) |*endpoint| {
+            endpoint.stopped.store(true, .seq_cst);
+            ipc.http_status.unblock(&endpoint.listener);
+            endpoint.thread.join();
+            endpoint.listener.deinit();
+            self.endpoint = null;
+        }
+    }
+
+    /// Bound address, for tests that listen on port 0.
+    pub fn address(self: *Slot) ?std.net.Address {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        const endpoint = self.endpoint orelse return null;
+        return endpoint.listener.listen_address;
+    }
+};
+
+test "status endpoint answers over tcp and a bad address leaves it off" {
+    const test_ipc = @import("../test_support/ipc.zig");
+    var provider = test_ipc.FakeSnapshotProvider{ .line = test_ipc.apiWorkerSnapshotLine };
+
+    var rejected = Slot{};
+    rejected.start(std.testing.allocator, "localhost:80", provider.provider());
+    try std.testing.expect(rejected.address() == null);
+
+    var slot = Slot{};
+    slot.start(std.testing.allocator, "127.0.0.1:0", provider.provider());
+    defer slot.stop();
+    const bound = slot.address() orelse return error.TestUnexpectedResult;
+
+    var stream = try std.net.tcpConnectToAddress(bound);
+    defer stream.close();
+    try stream.writeAll("GET /status HTTP/1.1\r\nHost: test\r\n\r\n");
+    var buf: [1024]u8 = undefined;
+    var len: usize = 0;
+    while (len < buf.len) {
+        const read = try stream.read(buf[len..]);
+        if (read == 0) break;
+        len += read;
+    }
+    try std.testing.expect(std.mem.startsWith(u8, buf[0..len], "HTTP/1.1 200 OK\r\n"));
+    try std.testing.expect(std.mem.indexOf(u8, buf[0..len], "\"name\":\"api\"") != null);
+
+    slot.stop();
+    try std.testing.expect(slot.address() == null);
+}
@@ -0,0 +1,58 @@
+//! Exited processes that should not stay on screen: one-shot `task` runs and processes with `remain_on_exit: false`.
+//! The Primary Server polls for them and dismisses their instance and output, so only the exit status stays in the list; `keep_on_failure` keeps a failed task for inspection.
+
+const std = @import("std");
+const config = @import("../config/root.zig");
+const command_runner = @import("command_runner.zig");
+
+const log = std.log.scoped(.primary_tasks);
+
+/// Dismisses every exited process that should not remain, once.
+pub fn tick(runner: command_runner.Runner) void {
+    const controller = runner.controller;
+    for (runner.state.processes.items) |*process| {
+        if (!dismissesOnExit(process.config, controller.global_config)) continue;
+        const status = controller.exitStatus(process.id) orelse continue;
+        if (status != 0 and process.config.task and process.config.keep_on_failure) continue;
+        const dismissed = controller.dismissExited(process.id) catch |err| {
+            log.warn("could not dismiss exited process '{s}': {s}", .{ process.label, @errorName(err) });
+            continue;
+        };
+        if (dismissed) log.info("process '{s}' exited with status {d} and was dismissed", .{ process.label, status });
+    }
+}
+
+/// Tasks are always dismissed; `task` wins over `remain_on_exit`, and
+/// `keep_on_failure` is what keeps a failed one. Other processes follow their
+/// own `remain_on_exit`, falling back to the top-level default.
+pub fn dismissesOnExit(proc_cfg: *const config.schema.ProcessConfig, global_config: ?*const config.schema.Config) bool {
+    if (proc_cfg.task) return true;
+    const global_remain = if (global_config) |cfg| cfg.remain_on_exit else true;
+    return !(proc_cfg.remain_on_exit orelse global_remain);
+}
+
+/// True when any process is dismissed on exit, so the watcher can be skipped.
+pub fn anyDismissed(cfg: *const config.schema.Config) bool {
+    for (cfg.procs.values()) |*proc| {
+        if (dismissesOnExit(proc, cfg)) return true;
+    }
+    return false;
+}
+
+test "task wins over remain_on_exit and processes inherit the default" {
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+
+    try std.testing.expect(!dismissesOnExit(&proc_cfg, &cfg));
+    cfg.remain_on_exit = false;
+    try std.testing.expect(dismissesOnExit(&proc_cfg, &cfg));
+    proc_cfg.remain_on_exit = true;
+    try std.testing.expect(!dismissesOnExit(&proc_cfg, &cfg));
+    proc_cfg.task = true;
+    try std.testing.expect(dismissesOnExit(&proc_cfg, &cfg));
+    proc_cfg.task = false;
+    proc_cfg.remain_on_exit = null;
+    try std.testing.expect(!dismissesOnExit(&proc_cfg, null));
+}
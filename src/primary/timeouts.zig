@@ -0,0 +1,47 @@
+//! Runtime limits for processes with `max_runtime_secs`.
+//! The Primary Server polls start times and stops any process that has run past its limit with the configured stop sequence or signal, marking it timed out.
+
+const std = @import("std");
+const config = @import("../config/root.zig");
+const domain = @import("../domain/root.zig");
+const command_runner = @import("command_runner.zig");
+
+const log = std.log.scoped(.primary_timeouts);
+
+pub const Watchdog = struct {
+    runner: command_runner.Runner,
+
+    pub fn init(runner: command_runner.Runner) Watchdog {
+        return .{ .runner = runner };
+    }
+
+    /// Stops every process that has outlived its limit. `now_ms` is passed in
+    /// so limits can be tested without waiting on the wall clock.
+    pub fn tick(self: Watchdog, now_ms: i64) void {
+        for (self.runner.state.processes.items) |*process| {
+            if (!overdue(self.runner, process, now_ms)) continue;
+            log.warn("process '{s}' ran longer than {d}s; stopping it", .{ process.label, process.config.max_runtime_secs });
+            self.runner.controller.markTimedOut(process.id) catch |err| {
+                log.warn("could not mark process '{s}' timed out: {s}", .{ process.label, @errorName(err) });
+            };
+            self.runner.stopProcess(process) catch |err| {
+                log.warn("stopping timed-out process '{s}' failed: {s}", .{ process.label, @errorName(err) });
+            };
+        }
+    }
+};
+
+fn overdue(runner: command_runner.Runner, process: *const domain.process.Process, now_ms: i64) bool {
+    const limit_secs = process.config.max_runtime_secs;
+    if (limit_secs <= 0) return false;
+    const since = runner.controller.runningSinceMs(process.id) orelse return false;
+    return now_ms - since >= @as(i64, limit_secs) * std.time.ms_per_s;
+}
+
+/// True when any process sets a runtime limit, so the watcher can be skipped.
+pub fn anyLimit(cfg: *const config.schema.Config) bool {
+    for (cfg.procs.values()) |proc| {
+        if (proc.max_runtime_secs > 0) return true;
+    }
+    return false;
+}
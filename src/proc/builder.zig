@@ -16,23 +16,17 @@ pub const CommandSpec = struct {
 };
 
 /// Resolves process config into argv. `shell` and `cmd` are intentionally
-/// mutually exclusive so startup behavior is predictable.
+/// mutually exclusive so startup behavior is predictable; `args` follow either.
 pub fn buildCommand(
     allocator: std.mem.Allocator,
     proc_cfg: *const config.schema.ProcessConfig,
     global_config: ?*const config.schema.Config,
 ) !?CommandSpec {
     if (proc_cfg.shell.len > 0) {
-        const shell_cmd = if (global_config) |cfg|
-            if (cfg.shell_cmd.items.len > 0) cfg.shell_cmd.items else default_shell_cmd[0..]
-        else
-            default_shell_cmd[0..];
-
-        var argv = std.array_list.Managed([]const u8).init(allocator);
-        errdefer deinitArgv(allocator, &argv);
-        for (shell_cmd) |part| try argv.append(try allocator.dupe(u8, part));
-        try argv.append(try allocator.dupe(u8, proc_cfg.shell));
-        return .{ .argv = try argv.toOwnedSlice() };
+        if (proc_cfg.args.items.len == 0) return try buildShellCommand(allocator, proc_cfg.shell, proc_cfg, global_config);
+        const command_line = try shellLineWithArgs(allocator, proc_cfg.shell, proc_cfg.args.items);
+        defer allocator.free(command_line);
+        return try buildShellCommand(allocator, command_line, proc_cfg, global_config);
     }
 
     if (proc_cfg.cmd.items.len == 0) return null;
@@ -40,9 +34,93 @@ pub fn buildCommand(
     var argv = std.array_list.Managed([]const u8).init(allocator);
     errdefer deinitArgv(allocator, &argv);
     for (proc_cfg.cmd.items) |part| try argv.append(try allocator.dupe(u8, part));
+    for (proc_cfg.args.items) |part| try argv.append(try allocator.dupe(u8, part));
     return .{ .argv = try argv.toOwnedSlice() };
 }
 
+/// Appends each arg to `command_line` as one shell word, so spaces and quotes
+/// in an arg never split it or end the command early.
+fn shellLineWithArgs(allocator: std.mem.Allocator, command_line: []const u8, args: []const []const u8) ![]u8 {
+    var out = std.array_list.Managed(u8).init(allocator);
+    errdefer out.deinit();
+    try out.appendSlice(command_line);
+    for (args) |arg| {
+        try out.append(' ');
+        try appendShellQuoted(&out, arg);
+    }
+    return out.toOwnedSlice();
+}
+
+/// Quotes `arg` for a POSIX shell; safe words are left bare for readability.
+pub fn appendShellQuoted(out: *std.array_list.Managed(u8), arg: []const u8) !void {
+    if (arg.len > 0 and isShellSafe(arg)) {
+        try out.appendSlice(arg);
+        return;
+    }
+    try out.append('\'');
+    for (arg) |c| {
+        if (c == '\'') {
+            try out.appendSlice("'\\''");
+        } else {
+            try out.append(c);
+        }
+    }
+    try out.append('\'');
+}
+
+fn isShellSafe(arg: []const u8) bool {
+    for (arg) |c| {
+        if (std.ascii.isAlphanumeric(c)) continue;
+        switch (c) {
+            '_', '-', '.', '/', ':', '=', '@', '%', '+', ',' => continue,
+            else => return false,
+        }
+    }
+    return true;
+}
+
+/// Wraps a shell command line in the process `shell_cmd`, then the global
+/// one, then `sh -c`. The line stays one argv entry, so it needs no quoting.
+pub fn buildShellCommand(
+    allocator: std.mem.Allocator,
+    command_line: []const u8,
+    proc_cfg: *const config.schema.ProcessConfig,
+    global_config: ?*const config.schema.Config,
+) !CommandSpec {
+    const shell_cmd = shellCommandPrefix(proc_cfg, global_config);
+
+    var argv = std.array_list.Managed([]const u8).init(allocator);
+    errdefer deinitArgv(allocator, &argv);
+    for (shell_cmd) |part| try argv.append(try allocator.dupe(u8, part));
+    try argv.append(try allocator.dupe(u8, command_line));
+    return .{ .argv = try argv.toOwnedSlice() };
+}
+
+/// Resolves the placeholder command shown in a process terminal before the
+/// real command is started: the process `pause`, then the global one.
+pub fn buildPauseCommand(
+    allocator: std.mem.Allocator,
+    proc_cfg: *const config.schema.ProcessConfig,
+    global_config: ?*const config.schema.Config,
+) !?CommandSpec {
+    const command_line = if (proc_cfg.pause.len > 0)
+        proc_cfg.pause
+    else if (global_config) |cfg| cfg.pause else "";
+    if (std.mem.trim(u8, command_line, " \t\r\n").len == 0) return null;
+    return try buildShellCommand(allocator, command_line, proc_cfg, global_config);
+}
+
+pub fn shellCommandPrefix(
+    proc_cfg: *const config.schema.ProcessConfig,
+    global_config: ?*const config.schema.Config,
+) []const []const u8 {
+    if (proc_cfg.shell_cmd.items.len > 0) return proc_cfg.shell_cmd.items;
+    if (global_config) |cfg| {
+        if (cfg.shell_cmd.items.len > 0) return cfg.shell_cmd.items;
+    }
+    return default_shell_cmd[0..];
+}
+
 pub fn buildEnvironmentFromBase(
     allocator: std.mem.Allocator,
     base_env: []const []const u8,
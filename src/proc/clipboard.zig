@@ -0,0 +1,189 @@
+//! Copying a process's retained output to the system clipboard.
+//! `clipboard_command` wins when set; otherwise pbcopy, wl-copy, or xclip is picked from the environment, and without any of them the text goes to a temp file instead.
+
+const std = @import("std");
+const builtin = @import("builtin");
+const config = @import("../config/root.zig");
+const builder = @import("builder.zig");
+const hooks = @import("hooks.zig");
+
+const timeout_ms = 5000;
+
+pub const Outcome = union(enum) {
+    /// Shell command that received the text on stdin.
+    clipboard: []const u8,
+    /// Owned path of the fallback file.
+    file: []u8,
+
+    pub fn deinit(self: Outcome, allocator: std.mem.Allocator) void {
+        switch (self) {
+            .clipboard => {},
+            .file => |path| allocator.free(path),
+        }
+    }
+};
+
+/// Sends `text` to the clipboard, or writes it to a temp file named after
+/// `label` when no clipboard tool is available. A tool that fails is an error.
+pub fn copy(
+    allocator: std.mem.Allocator,
+    text: []const u8,
+    label: []const u8,
+    global_config: ?*const config.schema.Config,
+) !Outcome {
+    const configured = if (global_config) |cfg| std.mem.trim(u8, cfg.clipboard_command, " \t\r\n") else "";
+    const command_line = if (configured.len > 0) configured else detectCommand(allocator) orelse {
+        return .{ .file = try writeTempFile(allocator, text, label) };
+    };
+    try pipeTo(allocator, command_line, text, global_config);
+    return .{ .clipboard = command_line };
+}
+
+/// Picks a clipboard tool the session can reach: pbcopy on macOS, wl-copy
+/// under Wayland, xclip under X11.
+fn detectCommand(allocator: std.mem.Allocator) ?[]const u8 {
+    if (builtin.os.tag == .macos) {
+        if (onPath(allocator, "pbcopy")) return "pbcopy";
+        return null;
+    }
+    if (std.posix.getenv("WAYLAND_DISPLAY") != null and onPath(allocator, "wl-copy")) return "wl-copy";
+    if (std.posix.getenv("DISPLAY") != null and onPath(allocator, "xclip")) return "xclip -selection clipboard";
+    return null;
+}
+
+fn onPath(allocator: std.mem.Allocator, name: []const u8) bool {
+    const path_env = std.posix.getenv("PATH") orelse return false;
+    var dirs = std.mem.tokenizeScalar(u8, path_env, ':');
+    while (dirs.next()) |dir| {
+        const candidate = std.fs.path.join(allocator, &.{ dir, name }) catch return false;
+        defer allocator.free(candidate);
+        std.posix.access(candidate, std.posix.X_OK) catch continue;
+        return true;
+    }
+    return false;
+}
+
+fn pipeTo(
+    allocator: std.mem.Allocator,
+    command_line: []const u8,
+    text: []const u8,
+    global_config: ?*const config.schema.Config,
+) !void {
+    var host_cfg = config.schema.ProcessConfig.empty(allocator);
+    defer host_cfg.deinit(allocator);
+    const command_spec = try builder.buildShellCommand(allocator, command_line, &host_cfg, global_config);
+    defer command_spec.deinit(allocator);
+
+    var child = std.process.Child.init(command_spec.argv, allocator);
+    child.stdin_behavior = .Pipe;
+    child.stdout_behavior = .Ignore;
+    child.stderr_behavior = .Ignore;
+    try child.spawn();
+
+    var stdin = child.stdin.?;
+    child.stdin = null;
+    const written = stdin.writeAll(text);
+    stdin.close();
+
+    const term = try hooks.waitWithTimeout(&child, timeout_ms);
+    switch (term) {
+        .Exited => |code| if (code != 0) return error.ClipboardCommandFailed,
+        else => return error.ClipboardCommandFailed,
+    }
+    try written;
+}
+
+fn writeTempFile(allocator: std.mem.Allocator, text: []const u8, label: []const u8) ![]u8 {
+    const tmp_dir = std.posix.getenv("TMPDIR") orelse "/tmp";
+    const name = try std.fmt.allocPrint(allocator, "proctmux-{s}-{d}.txt", .{ label, std.time.milliTimestamp() });
+    defer allocator.free(name);
+    for (name) |*c| {
+        if (c.* == '/') c.* = '_';
+    }
+    const path = try std.fs.path.join(allocator, &.{ tmp_dir, name });
+    errdefer allocator.free(path);
+
+    const file = try std.fs.createFileAbsolute(path, .{ .mode = 0o600 });
+    defer file.close();
+    try file.writeAll(text);
+    return path;
+}
+
+/// Drops terminal escape sequences and carriage returns so the copied text
+/// reads like the screen did. Caller owns the result.
+pub fn plainText(allocator: std.mem.Allocator, bytes: []const u8) ![]u8 {
+    var out = try std.array_list.Managed(u8).initCapacity(allocator, bytes.len);
+    errdefer out.deinit();
+
+    var index: usize = 0;
+    while (index < bytes.len) {
+        const byte = bytes[index];
+        if (byte == '\r') {
+            index += 1;
+            continue;
+        }
+        if (byte != 0x1b) {
+            out.appendAssumeCapacity(byte);
+            index += 1;
+            continue;
+        }
+        index += 1;
+        if (index >= bytes.len) break;
+        switch (bytes[index]) {
+            '[' => {
+                index += 1;
+                while (index < bytes.len and (bytes[index] < 0x40 or bytes[index] > 0x7e)) index += 1;
+                index += 1;
+            },
+            ']' => {
+                index += 1;
+                while (index < bytes.len) : (index += 1) {
+                    if (bytes[index] == 0x07) {
+                        index += 1;
+                        break;
+                    }
+                    if (bytes[index] == 0x1b and index + 1 < bytes.len and bytes[index + 1] == '\\') {
+                        index += 2;
+                        break;
+                    }
+                }
+            },
+            else => index += 1,
+        }
+    }
+    return out.toOwnedSlice();
+}
+
+test "plain text drops escape sequences and carriage returns" {
+    const text = try plainText(std.testing.allocator, "\x1b[31merror\x1b[0m: boom\r\n\x1b]0;title\x07at main\x1b7\r\n");
+    defer std.testing.allocator.free(text);
+    try std.testing.expectEqualStrings("error: boom\nat main\n", text);
+}
+
+test "configured clipboard command receives the text on stdin" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    const dir_path = try tmp.dir.realpathAlloc(std.testing.allocator, ".");
+    defer std.testing.allocator.free(dir_path);
+
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    const command_line = try std.fmt.allocPrint(std.testing.allocator, "cat > '{s}/copied.txt'", .{dir_path});
+    defer std.testing.allocator.free(command_line);
+    cfg.clipboard_command = command_line;
+
+    const outcome = try copy(std.testing.allocator, "stack trace\n", "api", &cfg);
+    defer outcome.deinit(std.testing.allocator);
+    try std.testing.expectEqualStrings(command_line, outcome.clipboard);
+    const copied = try tmp.dir.readFileAlloc(std.testing.allocator, "copied.txt", 64);
+    defer std.testing.allocator.free(copied);
+    try std.testing.expectEqualStrings("stack trace\n", copied);
+
+    cfg.clipboard_command = "exit 1";
+    try std.testing.expectError(error.ClipboardCommandFailed, copy(std.testing.allocator, "x", "api", &cfg));
+
+    const saved = try writeTempFile(std.testing.allocator, "fallback", "web/api");
+    defer std.testing.allocator.free(saved);
+    defer std.fs.deleteFileAbsolute(saved) catch {};
+    try std.testing.expect(std.mem.indexOf(u8, std.fs.path.basename(saved), "proctmux-web_api-") != null);
+}
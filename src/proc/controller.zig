@@ -6,17 +6,34 @@ const config = @import("../config/root.zig");
 const domain = @import("../domain/root.zig");
 const ring = @import("../ring/root.zig");
 const builder = @import("builder.zig");
+const clipboard = @import("clipboard.zig");
 const env = @import("env.zig");
 const instance_mod = @import("instance.zig");
+const keys = @import("keys.zig");
 const on_kill = @import("on_kill.zig");
 const output = @import("output.zig");
+const ready = @import("ready.zig");
 const spawn = @import("spawn.zig");
 
+const log = std.log.scoped(.proc_controller);
+
 const default_scrollback_capacity = 1024 * 1024;
 const default_stop_timeout_ms = 3000;
 
 pub const Instance = instance_mod.Instance;
 
+/// Longest last-line preview kept per process, in bytes.
+pub const preview_max_bytes = 60;
+/// How often a last-line preview is re-read from scrollback, so a chatty
+/// process does not change every published snapshot.
+pub const preview_interval_ms = 1000;
+
+const Preview = struct {
+    buf: [preview_max_bytes]u8 = undefined,
+    len: usize = 0,
+    refreshed_ms: i64 = 0,
+};
+
 /// Owns currently running process instances plus per-process scrollback history.
 /// Callers interact through stable ProcessIds; OS handles, retained output, and
 /// cleanup hooks stay behind this Module's mutex-protected maps.
@@ -25,6 +42,12 @@ pub const Controller = struct {
     global_config: ?*const config.schema.Config,
     processes: std.AutoHashMap(domain.process.ProcessId, *Instance),
     scrollbacks: std.AutoHashMap(domain.process.ProcessId, *ring.RingBuffer),
+    /// Processes stopped for exceeding `max_runtime_secs`; cleared on the next start.
+    timed_out: std.AutoHashMap(domain.process.ProcessId, void),
+    /// Exit status of runs whose exited instance was dismissed; cleared on the
+    /// next start.
+    dismissed: std.AutoHashMap(domain.process.ProcessId, u32),
+    previews: std.AutoHashMap(domain.process.ProcessId, *Preview),
     mutex: std.Thread.Mutex = .{},
 
     pub fn init(
@@ -36,10 +59,32 @@ pub const Controller = struct {
             .global_config = global_config,
             .processes = std.AutoHashMap(domain.process.ProcessId, *Instance).init(allocator),
             .scrollbacks = std.AutoHashMap(domain.process.ProcessId, *ring.RingBuffer).init(allocator),
+            .timed_out = std.AutoHashMap(domain.process.ProcessId, void).init(allocator),
+            .dismissed = std.AutoHashMap(domain.process.ProcessId, u32).init(allocator),
+            .previews = std.AutoHashMap(domain.process.ProcessId, *Preview).init(allocator),
         };
     }
 
     pub fn deinit(self: *Controller) void {
+        self.stopAll();
+
+        var scrollback_it = self.scrollbacks.valueIterator();
+        while (scrollback_it.next()) |scrollback| {
+            scrollback.*.deinit();
+            self.allocator.destroy(scrollback.*);
+        }
+        self.scrollbacks.deinit();
+        self.timed_out.deinit();
+        self.dismissed.deinit();
+        var preview_it = self.previews.valueIterator();
+        while (preview_it.next()) |preview| self.allocator.destroy(preview.*);
+        self.previews.deinit();
+        self.processes.deinit();
+    }
+
+    /// Stops every running instance and drops exited ones. Safe to call more
+    /// than once, so signal cleanup and normal shutdown can both run it.
+    pub fn stopAll(self: *Controller) void {
         while (true) {
             self.mutex.lock();
             var it = self.processes.keyIterator();
@@ -54,14 +99,52 @@ pub const Controller = struct {
                 self.cleanupProcess(id) catch {};
             }
         }
+    }
 
-        var scrollback_it = self.scrollbacks.valueIterator();
-        while (scrollback_it.next()) |scrollback| {
-            scrollback.*.deinit();
-            self.allocator.destroy(scrollback.*);
+    /// Sends every running process its stop sequence or signal at once and
+    /// gives them `grace_ms` in total to exit before killing the rest, then
+    /// releases everything as `stopAll` does. Returns the ids that had to be
+    /// killed; the caller owns the slice.
+    pub fn stopAllWithin(self: *Controller, allocator: std.mem.Allocator, grace_ms: u64) ![]domain.process.ProcessId {
+        const ids = try self.getAllProcessIDs(allocator);
+        defer allocator.free(ids);
+        var stopping = std.array_list.Managed(*Instance).init(allocator);
+        defer stopping.deinit();
+        for (ids) |id| {
+            const instance = self.getInstance(id) orelse continue;
+            if (instance.paused or !instance.isRunning()) continue;
+            instance.markStopRequested();
+            if (instance.config.stop_sequence.len > 0) {
+                self.sendKeys(id, instance.config.stop_sequence, false) catch {
+                    signalProcessTree(instance.pid(), resolveStopSignal(instance.config));
+                };
+            } else {
+                signalProcessTree(instance.pid(), resolveStopSignal(instance.config));
+            }
+            try stopping.append(instance);
         }
-        self.scrollbacks.deinit();
-        self.processes.deinit();
+
+        const deadline_ms = std.time.milliTimestamp() + @as(i64, @intCast(grace_ms));
+        while (std.time.milliTimestamp() < deadline_ms) {
+            var any_running = false;
+            for (stopping.items) |instance| {
+                if (instance.isRunning()) any_running = true;
+            }
+            if (!any_running) break;
+            std.Thread.sleep(10 * std.time.ns_per_ms);
+        }
+
+        var killed = std.array_list.Managed(domain.process.ProcessId).init(allocator);
+        errdefer killed.deinit();
+        for (stopping.items) |instance| {
+            if (!instance.isRunning()) continue;
+            try killed.append(instance.id);
+            signalProcessTree(instance.pid(), std.posix.SIG.KILL);
+        }
+        for (stopping.items) |instance| _ = waitUntilStopped(instance, 2000);
+        for (stopping.items) |instance| self.stopProcess(instance.id) catch {};
+        self.stopAll();
+        return killed.toOwnedSlice();
     }
 
     /// Starts a new process instance for `id`. The id must not already be
@@ -71,22 +154,63 @@ pub const Controller = struct {
         id: domain.process.ProcessId,
         proc_cfg: *const config.schema.ProcessConfig,
     ) !*Instance {
+        try self.releasePause(id);
+
         self.mutex.lock();
         defer self.mutex.unlock();
 
-        if (self.processes.contains(id)) return error.ProcessAlreadyExists;
-        const scrollback = try self.scrollbackForStartLocked(id);
-        scrollback.clear();
-
         const command_spec = (try builder.buildCommand(self.allocator, proc_cfg, self.global_config)) orelse {
             return error.InvalidProcessConfig;
         };
+        return self.launchLocked(id, proc_cfg, command_spec, false);
+    }
+
+    /// Runs the process `pause` command (or the global default) in its
+    /// terminal without starting the real command. Returns null when none is
+    /// configured or `id` still has an instance; `startProcess` replaces the
+    /// placeholder.
+    pub fn startPause(
+        self: *Controller,
+        id: domain.process.ProcessId,
+        proc_cfg: *const config.schema.ProcessConfig,
+    ) !?*Instance {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+
+        if (self.processes.contains(id)) return null;
+        const command_spec = (try builder.buildPauseCommand(self.allocator, proc_cfg, self.global_config)) orelse return null;
+        return try self.launchLocked(id, proc_cfg, command_spec, true);
+    }
+
+    /// Spawns `command_spec` under the held mutex and takes ownership of it.
+    /// Pause commands skip the process log so it only records real runs, and
+    /// keep the scrollback so a stopped process's last output stays visible.
+    fn launchLocked(
+        self: *Controller,
+        id: domain.process.ProcessId,
+        proc_cfg: *const config.schema.ProcessConfig,
+        command_spec: builder.CommandSpec,
+        paused: bool,
+    ) !*Instance {
         var command_spec_owned = true;
         errdefer if (command_spec_owned) command_spec.deinit(self.allocator);
 
+        if (self.processes.contains(id)) return error.ProcessAlreadyExists;
+        try ensureWorkingDirectory(proc_cfg);
+        const scrollback = try self.scrollbackForStartLocked(id);
+        if (!proc_cfg.reuse_pane and !paused) {
+            scrollback.clear();
+        } else if (scrollback.len() > 0) {
+            // Start the new run on its own line below the previous output.
+            _ = scrollback.write("\r\n");
+        }
+
         var env_map = try env.buildMap(self.allocator, proc_cfg);
         defer env_map.deinit();
 
+        var log_file = if (paused) null else try openProcessLog(proc_cfg);
+        errdefer if (log_file) |file| file.close();
+
         var started = try spawn.start(self.allocator, proc_cfg, command_spec, &env_map);
         errdefer started.deinit();
 
@@ -100,7 +224,11 @@ pub const Controller = struct {
             .command_spec = command_spec,
             .handle = started.handle,
             .scrollback = scrollback,
+            .log_file = log_file,
+            .paused = paused,
+            .started_at_ms = std.time.milliTimestamp(),
         };
+        log_file = null;
         command_spec_owned = false;
         started.disarm();
         errdefer instance.deinit();
@@ -109,6 +237,10 @@ pub const Controller = struct {
         instance.wait_thread = try std.Thread.spawn(.{}, spawn.waitForExit, .{instance});
 
         try self.processes.put(id, instance);
+        if (!paused) {
+            _ = self.timed_out.remove(id);
+            _ = self.dismissed.remove(id);
+        }
         return instance;
     }
 
@@ -116,10 +248,17 @@ pub const Controller = struct {
     /// releases the instance with user cleanup hooks enabled.
     pub fn stopProcess(self: *Controller, id: domain.process.ProcessId) !void {
         const instance = self.getInstance(id) orelse return error.ProcessNotFound;
+        if (instance.paused) return self.releasePause(id);
+        instance.markStopRequested();
 
         if (instance.isRunning()) {
-            const stop_signal = resolveStopSignal(instance.config);
-            signalProcessTree(instance.pid(), stop_signal);
+            if (instance.config.stop_sequence.len > 0) {
+                self.sendKeys(id, instance.config.stop_sequence, false) catch {
+                    signalProcessTree(instance.pid(), resolveStopSignal(instance.config));
+                };
+            } else {
+                signalProcessTree(instance.pid(), resolveStopSignal(instance.config));
+            }
             if (!waitUntilStopped(instance, resolveStopTimeoutMs(instance.config))) {
                 signalProcessTree(instance.pid(), std.posix.SIG.KILL);
                 _ = waitUntilStopped(instance, 2000);
@@ -133,11 +272,39 @@ pub const Controller = struct {
     /// is for natural exits and pre-start cleanup, not user-requested stops.
     pub fn cleanupProcess(self: *Controller, id: domain.process.ProcessId) !void {
         const instance = self.getInstance(id) orelse return;
+        if (instance.paused) return self.releasePause(id);
         if (instance.isRunning()) return error.ProcessStillRunning;
 
         try self.releaseProcess(id, instance, false);
     }
 
+    /// Releases an instance that exited on its own and clears its scrollback,
+    /// keeping only the exit status. Returns false when there was none to release.
+    pub fn dismissExited(self: *Controller, id: domain.process.ProcessId) !bool {
+        const instance = self.getInstance(id) orelse return false;
+        if (instance.paused) return false;
+        const status = instance.naturalExitStatus() orelse return false;
+        try self.releaseProcess(id, instance, false);
+
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        try self.dismissed.put(id, status);
+        if (self.scrollbacks.get(id)) |scrollback| scrollback.clear();
+        return true;
+    }
+
+    /// Kills a `pause` placeholder, if one holds `id`, without running
+    /// `on_kill`. Its output stays in scrollback until the next start.
+    fn releasePause(self: *Controller, id: domain.process.ProcessId) !void {
+        const instance = self.getInstance(id) orelse return;
+        if (!instance.paused) return;
+        if (instance.isRunning()) {
+            signalProcessTree(instance.pid(), std.posix.SIG.KILL);
+            _ = waitUntilStopped(instance, 2000);
+        }
+        try self.releaseProcess(id, instance, false);
+    }
+
     fn releaseProcess(
         self: *Controller,
         id: domain.process.ProcessId,
@@ -170,7 +337,68 @@ pub const Controller = struct {
 
     pub fn isRunning(self: *Controller, id: domain.process.ProcessId) bool {
         const instance = self.getInstance(id) orelse return false;
-        return instance.isRunning();
+        return !instance.paused and instance.isRunning();
+    }
+
+    /// Exit status of `id` when it ended on its own and has not been cleaned
+    /// up yet, or was dismissed as a finished task; null while it runs, is
+    /// paused or stopping, or was never started.
+    pub fn exitStatus(self: *Controller, id: domain.process.ProcessId) ?u32 {
+        const instance = self.getInstance(id) orelse {
+            self.mutex.lock();
+            defer self.mutex.unlock();
+            return self.dismissed.get(id);
+        };
+        if (instance.paused) return null;
+        return instance.naturalExitStatus();
+    }
+
+    /// Start time of a running process; null while it is stopped or paused.
+    pub fn runningSinceMs(self: *Controller, id: domain.process.ProcessId) ?i64 {
+        const instance = self.getInstance(id) orelse return null;
+        if (instance.paused or !instance.isRunning()) return null;
+        return instance.started_at_ms;
+    }
+
+    /// Records that `id` is being stopped for running past `max_runtime_secs`,
+    /// so the status display can tell it apart from a normal exit.
+    pub fn markTimedOut(self: *Controller, id: domain.process.ProcessId) !void {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        try self.timed_out.put(id, {});
+    }
+
+    pub fn timedOut(self: *Controller, id: domain.process.ProcessId) bool {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        return self.timed_out.contains(id);
+    }
+
+    /// Reads the live state of every id under one hold of the controller lock,
+    /// matching what `isRunning`, `getPID`, `exitStatus` and `timedOut` would
+    /// report one by one. Snapshots use this instead of four calls per process.
+    pub fn getStates(self: *Controller, ids: []const domain.process.ProcessId, out: []domain.process.LiveState) void {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        for (ids, out) |id, *live| {
+            live.* = .{ .timed_out = self.timed_out.contains(id) };
+            const instance = self.processes.get(id) orelse {
+                live.exit_status = self.dismissed.get(id);
+                continue;
+            };
+            instance.mutex.lock();
+            defer instance.mutex.unlock();
+            if (instance.paused) continue;
+            switch (instance.lifecycle) {
+                .running => {
+                    live.status = .running;
+                    live.pid = @intCast(instance.pid());
+                },
+                .exited => |status| if (!instance.stop_requested) {
+                    live.exit_status = status;
+                },
+            }
+        }
     }
 
     pub fn getProcessStatus(self: *Controller, id: domain.process.ProcessId) domain.process.ProcessStatus {
@@ -182,12 +410,16 @@ pub const Controller = struct {
             .context = self,
             .get_process_status = adapterGetProcessStatus,
             .get_pid = adapterGetPID,
+            .get_exit_status = adapterExitStatus,
+            .get_timed_out = adapterTimedOut,
+            .get_last_line = adapterLastLine,
+            .get_states = adapterGetStates,
         };
     }
 
     pub fn getPID(self: *Controller, id: domain.process.ProcessId) i32 {
         const instance = self.getInstance(id) orelse return -1;
-        if (!instance.isRunning()) return -1;
+        if (instance.paused or !instance.isRunning()) return -1;
         return @intCast(instance.pid());
     }
 
@@ -208,12 +440,134 @@ pub const Controller = struct {
         return scrollback.bytes(allocator);
     }
 
+    /// Returns output `id` wrote after `cursor.*` and advances the cursor, so
+    /// a streaming caller keeps up however much scrollback is retained.
+    pub fn readOutputSince(self: *Controller, allocator: std.mem.Allocator, id: domain.process.ProcessId, cursor: *u64) ![]u8 {
+        const scrollback = self.getScrollbackBuffer(id) orelse return error.ProcessNotFound;
+        return scrollback.readSince(allocator, cursor);
+    }
+
+    /// Drops the retained output of `id`. Viewers see the buffer shrink and
+    /// start over from the next bytes the process writes.
+    pub fn clearScrollback(self: *Controller, id: domain.process.ProcessId) !void {
+        const scrollback = self.getScrollbackBuffer(id) orelse return error.ProcessNotFound;
+        scrollback.clear();
+    }
+
+    /// Returns retained output without trailing whitespace, optionally limited
+    /// to the last `max_lines` lines. Status and readiness features read this.
+    pub fn captureOutput(
+        self: *Controller,
+        allocator: std.mem.Allocator,
+        id: domain.process.ProcessId,
+        max_lines: ?usize,
+    ) ![]u8 {
+        const bytes = try self.getScrollback(allocator, id);
+        defer allocator.free(bytes);
+        return allocator.dupe(u8, tailLines(bytes, max_lines));
+    }
+
+    /// Last non-blank output line of `id` without escape sequences, cut to
+    /// `preview_max_bytes` and re-read at most every `preview_interval_ms`.
+    /// The slice is reused by the next call for `id`, so callers serialize.
+    pub fn lastLine(self: *Controller, id: domain.process.ProcessId) []const u8 {
+        const scrollback = self.getScrollbackBuffer(id) orelse return "";
+        const preview = self.previewFor(id) catch return "";
+        const now = std.time.milliTimestamp();
+        if (preview.refreshed_ms != 0 and now - preview.refreshed_ms < preview_interval_ms) return preview.buf[0..preview.len];
+        preview.refreshed_ms = now;
+
+        var tail_buf: [4096]u8 = undefined;
+        const text = clipboard.plainText(self.allocator, scrollback.tail(&tail_buf)) catch return preview.buf[0..preview.len];
+        defer self.allocator.free(text);
+        const line = std.mem.trimLeft(u8, tailLines(text, 1), " \t");
+        var end = @min(line.len, preview_max_bytes);
+        while (end > 0 and end < line.len and (line[end] & 0xc0) == 0x80) end -= 1;
+        for (line[0..end], 0..) |byte, index| {
+            preview.buf[index] = if (byte < 0x20 or byte == 0x7f) ' ' else byte;
+        }
+        preview.len = end;
+        return preview.buf[0..preview.len];
+    }
+
+    fn previewFor(self: *Controller, id: domain.process.ProcessId) !*Preview {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        const entry = try self.previews.getOrPut(id);
+        if (!entry.found_existing) {
+            entry.value_ptr.* = self.allocator.create(Preview) catch |err| {
+                self.previews.removeByPtr(entry.key_ptr);
+                return err;
+            };
+            entry.value_ptr.*.* = .{};
+        }
+        return entry.value_ptr.*;
+    }
+
+    /// Polls the process's `ready_when` checks until all pass. On timeout the
+    /// failing check is logged and `error.ReadinessTimeout` returned.
+    pub fn waitUntilReady(self: *Controller, id: domain.process.ProcessId, label: []const u8) !void {
+        const instance = self.getInstance(id) orelse return error.ProcessNotFound;
+        const check = instance.config.ready_when;
+        if (!check.enabled()) return;
+
+        const timeout_ms = ready.timeoutMs(check);
+        const deadline = std.time.milliTimestamp() + @as(i64, @intCast(timeout_ms));
+        while (true) {
+            if (!instance.isRunning()) {
+                log.warn("process '{s}' exited before it was ready", .{label});
+                return error.ProcessExitedBeforeReady;
+            }
+
+            const failing = try self.failingReadyCheck(id, instance.config, deadline);
+            if (failing == null) return;
+
+            if (std.time.milliTimestamp() >= deadline) {
+                log.warn("process '{s}' not ready after {d}ms: ready_when.{s} check failed", .{
+                    label,
+                    timeout_ms,
+                    @tagName(failing.?),
+                });
+                return error.ReadinessTimeout;
+            }
+            std.Thread.sleep(ready.intervalMs(check) * std.time.ns_per_ms);
+        }
+    }
+
+    fn failingReadyCheck(
+        self: *Controller,
+        id: domain.process.ProcessId,
+        proc_cfg: *const config.schema.ProcessConfig,
+        deadline: i64,
+    ) !?ready.Check {
+        const check = proc_cfg.ready_when;
+        if (check.output.len > 0) {
+            const captured = try self.getScrollback(self.allocator, id);
+            defer self.allocator.free(captured);
+            if (std.mem.indexOf(u8, captured, check.output) == null) return .output;
+        }
+        if (check.command.len > 0) {
+            const remaining_ms: u64 = @intCast(@max(deadline - std.time.milliTimestamp(), 1));
+            if (!try ready.commandSucceeds(self.allocator, proc_cfg, self.global_config, remaining_ms)) return .command;
+        }
+        return null;
+    }
+
     pub fn sendBytes(self: *Controller, id: domain.process.ProcessId, bytes: []const u8) !void {
         const instance = self.getInstance(id) orelse return error.ProcessNotFound;
-        if (!instance.isRunning()) return error.ProcessNotRunning;
+        if (instance.paused or !instance.isRunning()) return error.ProcessNotRunning;
         try instance.sendBytes(bytes);
     }
 
+    /// Sends bracketed key names (`<C-c>`, `<Enter>`, ...) or literal text to
+    /// a running process, mirroring what a user would type into its terminal.
+    /// Stop sequences are sent this way.
+    pub fn sendKeys(self: *Controller, id: domain.process.ProcessId, key_names: []const u8, literal: bool) !void {
+        const bytes = try keys.encode(self.allocator, key_names, literal);
+        defer self.allocator.free(bytes);
+        try self.sendBytes(id, bytes);
+    }
+
     fn getInstance(self: *Controller, id: domain.process.ProcessId) ?*Instance {
         self.mutex.lock();
         defer self.mutex.unlock();
@@ -253,6 +607,62 @@ fn adapterGetPID(context: *anyopaque, id: domain.process.ProcessId) i32 {
     return self.getPID(id);
 }
 
+fn adapterExitStatus(context: *anyopaque, id: domain.process.ProcessId) ?u32 {
+    const self: *Controller = @ptrCast(@alignCast(context));
+    return self.exitStatus(id);
+}
+
+fn adapterTimedOut(context: *anyopaque, id: domain.process.ProcessId) bool {
+    const self: *Controller = @ptrCast(@alignCast(context));
+    return self.timedOut(id);
+}
+
+fn adapterGetStates(context: *anyopaque, ids: []const domain.process.ProcessId, out: []domain.process.LiveState) void {
+    const self: *Controller = @ptrCast(@alignCast(context));
+    self.getStates(ids, out);
+}
+
+fn adapterLastLine(context: *anyopaque, id: domain.process.ProcessId) []const u8 {
+    const self: *Controller = @ptrCast(@alignCast(context));
+    return self.lastLine(id);
+}
+
+/// Trims trailing whitespace and keeps at most `max_lines` trailing lines.
+pub fn tailLines(bytes: []const u8, max_lines: ?usize) []const u8 {
+    const trimmed = std.mem.trimRight(u8, bytes, " \t\r\n");
+    const limit = max_lines orelse return trimmed;
+    if (limit == 0) return trimmed[trimmed.len..];
+
+    var seen: usize = 0;
+    var index = trimmed.len;
+    while (index > 0) : (index -= 1) {
+        if (trimmed[index - 1] != '\n') continue;
+        seen += 1;
+        if (seen == limit) return trimmed[index..];
+    }
+    return trimmed;
+}
+
+/// Opens the per-process output log, creating missing parent directories.
+/// `log_append: false` truncates the file on every start.
+fn openProcessLog(proc_cfg: *const config.schema.ProcessConfig) !?std.fs.File {
+    if (proc_cfg.log_file.len == 0) return null;
+    if (std.fs.path.dirname(proc_cfg.log_file)) |dir| try std.fs.cwd().makePath(dir);
+
+    const file = try std.fs.cwd().createFile(proc_cfg.log_file, .{ .truncate = !proc_cfg.log_append });
+    errdefer file.close();
+    if (proc_cfg.log_append) try file.seekFromEnd(0);
+    return file;
+}
+
+/// Fails before fork so a bad `cwd` surfaces as an error instead of a child
+/// that silently exits 127 after `chdir` fails.
+fn ensureWorkingDirectory(proc_cfg: *const config.schema.ProcessConfig) !void {
+    if (proc_cfg.cwd.len == 0) return;
+    var dir = std.fs.cwd().openDir(proc_cfg.cwd, .{}) catch return error.ProcessCwdNotFound;
+    dir.close();
+}
+
 fn resolveStopSignal(proc_cfg: *const config.schema.ProcessConfig) u8 {
     if (proc_cfg.stop > 0) return @intCast(proc_cfg.stop);
     return std.posix.SIG.TERM;
@@ -5,7 +5,8 @@ const std = @import("std");
 const config = @import("../config/root.zig");
 
 /// Builds the child environment from parent process state plus process config.
-/// Configured env values override inherited values after PATH augmentation.
+/// Configured env values override inherited values after PATH augmentation and
+/// may reference inherited variables as `$NAME` or `${NAME}`.
 pub fn buildMap(
     allocator: std.mem.Allocator,
     proc_cfg: *const config.schema.ProcessConfig,
@@ -25,10 +26,37 @@ pub fn buildMap(
         try env_map.put("PATH", path.items);
     }
 
+    // Expand every value before applying any override so references always
+    // see the inherited environment, independent of map iteration order.
+    var expanded = std.array_list.Managed([]u8).init(allocator);
+    defer {
+        for (expanded.items) |value| allocator.free(value);
+        expanded.deinit();
+    }
+    var expand_it = proc_cfg.env.iterator();
+    while (expand_it.next()) |entry| {
+        try expanded.append(try expandValue(allocator, entry.value_ptr.*, &env_map));
+    }
+
     var it = proc_cfg.env.iterator();
-    while (it.next()) |entry| {
-        try env_map.put(entry.key_ptr.*, entry.value_ptr.*);
+    var index: usize = 0;
+    while (it.next()) |entry| : (index += 1) {
+        try env_map.put(entry.key_ptr.*, expanded.items[index]);
     }
 
+    // `--set` values are literal and win over everything from the config.
+    var set_it = proc_cfg.set_env.iterator();
+    while (set_it.next()) |entry| try env_map.put(entry.key_ptr.*, entry.value_ptr.*);
+
     return env_map;
 }
+
+/// Expands `$NAME` and `${NAME}` references against `env_map`. Unknown names
+/// expand to an empty string; see `config.expand` for the full syntax.
+pub fn expandValue(
+    allocator: std.mem.Allocator,
+    value: []const u8,
+    env_map: *const std.process.EnvMap,
+) ![]u8 {
+    return config.expand.expand(allocator, value, env_map, .{});
+}
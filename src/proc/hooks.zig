@@ -0,0 +1,274 @@
+//! Lifecycle hook commands (`on_start`, `on_stop`, `on_success`, `on_failure`) and the bounded child wait shared with `on_kill`.
+//! Hooks run on the host beside proctmux rather than in a process terminal; a failing hook is logged and never undoes the lifecycle action.
+
+const std = @import("std");
+const config = @import("../config/root.zig");
+const builder = @import("builder.zig");
+const env = @import("env.zig");
+
+const log = std.log.scoped(.proc_hooks);
+
+const default_timeout_ms = 10_000;
+
+pub const Event = enum {
+    start,
+    stop,
+    success,
+    failure,
+
+    /// Exit event for a process that ended on its own with `exit_code`.
+    pub fn forExit(exit_code: u32) Event {
+        return if (exit_code == 0) .success else .failure;
+    }
+
+    fn fieldName(self: Event) []const u8 {
+        return switch (self) {
+            .start => "on_start",
+            .stop => "on_stop",
+            .success => "on_success",
+            .failure => "on_failure",
+        };
+    }
+};
+
+/// Command configured for `event`; empty when the process sets none.
+pub fn commandFor(event: Event, proc_cfg: *const config.schema.ProcessConfig) []const u8 {
+    return switch (event) {
+        .start => proc_cfg.on_start,
+        .stop => proc_cfg.on_stop,
+        .success => proc_cfg.on_success,
+        .failure => proc_cfg.on_failure,
+    };
+}
+
+/// Hooks spawned by `run` whose command has not finished yet.
+var pending = std.atomic.Value(usize).init(0);
+
+/// Runs the process hook for `event`, if one is set, after the lifecycle
+/// action succeeded. `pid` is the process that was started or stopped;
+/// `exit_code` is set for the exit events only. The command is spawned here
+/// and waited on from a detached thread, so callers holding the command lock
+/// are not held up by a slow hook.
+pub fn run(
+    allocator: std.mem.Allocator,
+    event: Event,
+    label: []const u8,
+    proc_cfg: *const config.schema.ProcessConfig,
+    global_config: ?*const config.schema.Config,
+    pid: i32,
+    exit_code: ?u32,
+) void {
+    const command_line = commandFor(event, proc_cfg);
+    if (std.mem.trim(u8, command_line, " \t\r\n").len == 0) return;
+
+    const waiter = Waiter.create(allocator, event, label) catch |err| return logFailure(event, label, err);
+    waiter.child = spawnHook(allocator, command_line, label, proc_cfg, global_config, pid, exit_code) catch |err| {
+        waiter.destroy();
+        return logFailure(event, label, err);
+    };
+    _ = pending.fetchAdd(1, .seq_cst);
+    const thread = std.Thread.spawn(.{}, Waiter.finish, .{waiter}) catch {
+        waiter.finish();
+        return;
+    };
+    thread.detach();
+}
+
+/// Blocks until every hook started by `run` has finished. Hooks are bounded
+/// by their timeout, so this returns once the slowest one is reaped.
+pub fn waitForPending() void {
+    while (pending.load(.seq_cst) > 0) std.Thread.sleep(5 * std.time.ns_per_ms);
+}
+
+/// Owns a spawned hook until its detached waiter reaps it; the label is
+/// copied because a reload may free the process definition meanwhile.
+const Waiter = struct {
+    allocator: std.mem.Allocator,
+    event: Event,
+    label: []const u8,
+    child: std.process.Child = undefined,
+
+    fn create(allocator: std.mem.Allocator, event: Event, label: []const u8) !*Waiter {
+        const waiter = try allocator.create(Waiter);
+        errdefer allocator.destroy(waiter);
+        waiter.* = .{ .allocator = allocator, .event = event, .label = try allocator.dupe(u8, label) };
+        return waiter;
+    }
+
+    fn destroy(self: *Waiter) void {
+        self.allocator.free(self.label);
+        self.allocator.destroy(self);
+    }
+
+    fn finish(self: *Waiter) void {
+        defer _ = pending.fetchSub(1, .seq_cst);
+        defer self.destroy();
+        finishHook(&self.child, self.label, default_timeout_ms) catch |err| logFailure(self.event, self.label, err);
+    }
+};
+
+fn logFailure(event: Event, label: []const u8, err: anyerror) void {
+    log.warn("{s} hook for process '{s}' failed: {s}", .{ event.fieldName(), label, @errorName(err) });
+}
+
+/// Runs `command_line` through the process shell with its `cwd`/`env` plus
+/// `PROCTMUX_PROCESS`, `PROCTMUX_PID`, and `PROCTMUX_EXIT_CODE` when an exit
+/// code is given. Non-zero exits are errors.
+pub fn execute(
+    allocator: std.mem.Allocator,
+    command_line: []const u8,
+    label: []const u8,
+    proc_cfg: *const config.schema.ProcessConfig,
+    global_config: ?*const config.schema.Config,
+    pid: i32,
+    exit_code: ?u32,
+    timeout_ms: u64,
+) !void {
+    var child = try spawnHook(allocator, command_line, label, proc_cfg, global_config, pid, exit_code);
+    try finishHook(&child, label, timeout_ms);
+}
+
+fn spawnHook(
+    allocator: std.mem.Allocator,
+    command_line: []const u8,
+    label: []const u8,
+    proc_cfg: *const config.schema.ProcessConfig,
+    global_config: ?*const config.schema.Config,
+    pid: i32,
+    exit_code: ?u32,
+) !std.process.Child {
+    const command_spec = try builder.buildShellCommand(allocator, command_line, proc_cfg, global_config);
+    defer command_spec.deinit(allocator);
+
+    var env_map = try env.buildMap(allocator, proc_cfg);
+    defer env_map.deinit();
+    try env_map.put("PROCTMUX_PROCESS", label);
+    var pid_buf: [16]u8 = undefined;
+    try env_map.put("PROCTMUX_PID", try std.fmt.bufPrint(&pid_buf, "{d}", .{pid}));
+    var exit_buf: [16]u8 = undefined;
+    if (exit_code) |code| try env_map.put("PROCTMUX_EXIT_CODE", try std.fmt.bufPrint(&exit_buf, "{d}", .{code}));
+
+    var child = std.process.Child.init(command_spec.argv, allocator);
+    child.stdin_behavior = .Ignore;
+    child.stdout_behavior = .Ignore;
+    child.stderr_behavior = .Ignore;
+    if (proc_cfg.cwd.len > 0) child.cwd = proc_cfg.cwd;
+    child.env_map = &env_map;
+
+    try child.spawn();
+    // Only `spawn` reads these; they are freed when this returns.
+    child.env_map = null;
+    child.cwd = null;
+    return child;
+}
+
+fn finishHook(child: *std.process.Child, label: []const u8, timeout_ms: u64) !void {
+    const term = try waitWithTimeout(child, timeout_ms);
+    log.debug("hook for process '{s}' finished: {any}", .{ label, term });
+    switch (term) {
+        .Exited => |code| if (code != 0) return error.HookFailed,
+        else => return error.HookFailed,
+    }
+}
+
+/// Waits for a spawned child for at most `timeout_ms`; on timeout the child
+/// is killed and reaped before `error.HookTimedOut` is returned.
+pub fn waitWithTimeout(child: *std.process.Child, timeout_ms: u64) !std.process.Child.Term {
+    const child_pid = child.id;
+    var wait_state = WaitState{ .child = child };
+    const wait_thread = try std.Thread.spawn(.{}, waitChild, .{&wait_state});
+    if (!waitForChild(&wait_state.done, timeout_ms)) {
+        std.posix.kill(child_pid, std.posix.SIG.KILL) catch {};
+        wait_thread.join();
+        return error.HookTimedOut;
+    }
+    wait_thread.join();
+
+    return switch (wait_state.result) {
+        .running => error.HookTimedOut,
+        .failed => |err| err,
+        .exited => |term| term,
+    };
+}
+
+const WaitResult = union(enum) {
+    running,
+    exited: std.process.Child.Term,
+    failed: anyerror,
+};
+
+const WaitState = struct {
+    child: *std.process.Child,
+    done: std.atomic.Value(bool) = std.atomic.Value(bool).init(false),
+    result: WaitResult = .running,
+};
+
+fn waitChild(state: *WaitState) void {
+    state.result = .{ .exited = state.child.wait() catch |err| {
+        state.result = .{ .failed = err };
+        state.done.store(true, .release);
+        return;
+    } };
+    state.done.store(true, .release);
+}
+
+fn waitForChild(done: *const std.atomic.Value(bool), timeout_ms: u64) bool {
+    const sleep_ms: u64 = 5;
+    var elapsed_ms: u64 = 0;
+
+    while (elapsed_ms < timeout_ms) {
+        if (done.load(.acquire)) return true;
+        const remaining_ms = timeout_ms - elapsed_ms;
+        const current_sleep_ms: u64 = @min(sleep_ms, remaining_ms);
+        std.Thread.sleep(current_sleep_ms * @as(u64, std.time.ns_per_ms));
+        elapsed_ms += current_sleep_ms;
+    }
+
+    return done.load(.acquire);
+}
+
+test "lifecycle hooks see the process name pid and exit code" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+
+    const cwd = try tmp.dir.realpathAlloc(std.testing.allocator, ".");
+    defer std.testing.allocator.free(cwd);
+
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    proc_cfg.cwd = cwd;
+
+    try execute(std.testing.allocator, "printf '%s:%s' \"$PROCTMUX_PROCESS\" \"$PROCTMUX_PID\" > hook.txt", "api", &proc_cfg, null, 4242, null, 5000);
+    const written = try tmp.dir.readFileAlloc(std.testing.allocator, "hook.txt", 64);
+    defer std.testing.allocator.free(written);
+    try std.testing.expectEqualStrings("api:4242", written);
+
+    try execute(std.testing.allocator, "printf '%s' \"$PROCTMUX_EXIT_CODE\" > exit.txt", "api", &proc_cfg, null, 4242, 7, 5000);
+    const exit_code = try tmp.dir.readFileAlloc(std.testing.allocator, "exit.txt", 64);
+    defer std.testing.allocator.free(exit_code);
+    try std.testing.expectEqualStrings("7", exit_code);
+    try std.testing.expectEqual(Event.failure, Event.forExit(7));
+    try std.testing.expectEqual(Event.success, Event.forExit(0));
+
+    try std.testing.expectError(error.HookFailed, execute(std.testing.allocator, "exit 3", "api", &proc_cfg, null, 4242, null, 5000));
+}
+
+test "lifecycle hooks run without blocking the caller" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+
+    const cwd = try tmp.dir.realpathAlloc(std.testing.allocator, ".");
+    defer std.testing.allocator.free(cwd);
+
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    proc_cfg.cwd = cwd;
+    proc_cfg.on_start = "sleep 0.3; echo done > hook.txt";
+
+    run(std.testing.allocator, .start, "api", &proc_cfg, null, 4242, null);
+    try std.testing.expectError(error.FileNotFound, tmp.dir.access("hook.txt", .{}));
+    waitForPending();
+    const written = try tmp.dir.readFileAlloc(std.testing.allocator, "hook.txt", 64);
+    defer std.testing.allocator.free(written);
+    try std.testing.expectEqualStrings("done\n", written);
+}
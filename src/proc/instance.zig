@@ -35,7 +35,7 @@ pub const ProcessHandle = union(enum) {
 
     pub fn wait(self: *ProcessHandle) !u32 {
         return switch (self.*) {
-            .pty => |pty| std.posix.waitpid(pty.pid, 0).status,
+            .pty => |pty| waitStatus(std.posix.waitpid(pty.pid, 0).status),
             .pipe => |*pipe| termStatus(try pipe.child.wait()),
         };
     }
@@ -86,14 +86,24 @@ pub const Instance = struct {
     command_spec: builder.CommandSpec,
     handle: ProcessHandle,
     scrollback: *ring.RingBuffer,
+    log_file: ?std.fs.File = null,
     output_thread: ?std.Thread = null,
     wait_thread: ?std.Thread = null,
     mutex: std.Thread.Mutex = .{},
     lifecycle: Lifecycle = .running,
+    /// Set for the `pause` placeholder, which holds the terminal until the
+    /// real command starts and never counts as the process running.
+    paused: bool = false,
+    /// Set once a user stop begins, so the exit it causes is not mistaken
+    /// for the process ending on its own.
+    stop_requested: bool = false,
+    /// Wall-clock launch time, used to enforce `max_runtime_secs`.
+    started_at_ms: i64 = 0,
 
     pub fn deinit(self: *Instance) void {
         if (self.output_thread) |thread| thread.join();
         if (self.wait_thread) |thread| thread.join();
+        if (self.log_file) |file| file.close();
         self.handle.deinit();
         self.command_spec.deinit(self.allocator);
     }
@@ -119,8 +129,34 @@ pub const Instance = struct {
         defer self.mutex.unlock();
         self.lifecycle = .{ .exited = term_status };
     }
+
+    pub fn markStopRequested(self: *Instance) void {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        self.stop_requested = true;
+    }
+
+    /// Exit status of a process that ended on its own; null while it runs or
+    /// once a stop has been requested.
+    pub fn naturalExitStatus(self: *Instance) ?u32 {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        if (self.stop_requested) return null;
+        return switch (self.lifecycle) {
+            .running => null,
+            .exited => |status| status,
+        };
+    }
 };
 
+/// Normalizes a raw waitpid status like `termStatus`: the exit code, or
+/// 128 + signal when the process was killed.
+fn waitStatus(status: u32) u32 {
+    if (std.posix.W.IFEXITED(status)) return std.posix.W.EXITSTATUS(status);
+    if (std.posix.W.IFSIGNALED(status)) return 128 + @as(u32, std.posix.W.TERMSIG(status));
+    return status;
+}
+
 fn termStatus(term: std.process.Child.Term) u32 {
     return switch (term) {
         .Exited => |code| code,
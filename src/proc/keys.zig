@@ -0,0 +1,114 @@
+//! Key-name encoding for process input.
+//! Bracketed key names such as `<C-c>`, `<ctrl+c>` or `<Enter>` are turned into the bytes a terminal would send, so callers can script input without embedding control characters.
+
+const std = @import("std");
+
+const NamedKey = struct {
+    name: []const u8,
+    bytes: []const u8,
+};
+
+const named_keys = [_]NamedKey{
+    .{ .name = "enter", .bytes = "\r" },
+    .{ .name = "tab", .bytes = "\t" },
+    .{ .name = "escape", .bytes = "\x1b" },
+    .{ .name = "esc", .bytes = "\x1b" },
+    .{ .name = "space", .bytes = " " },
+    .{ .name = "backspace", .bytes = "\x7f" },
+    .{ .name = "bspace", .bytes = "\x7f" },
+    .{ .name = "up", .bytes = "\x1b[A" },
+    .{ .name = "down", .bytes = "\x1b[B" },
+    .{ .name = "right", .bytes = "\x1b[C" },
+    .{ .name = "left", .bytes = "\x1b[D" },
+    .{ .name = "home", .bytes = "\x1b[H" },
+    .{ .name = "end", .bytes = "\x1b[F" },
+    .{ .name = "pageup", .bytes = "\x1b[5~" },
+    .{ .name = "pagedown", .bytes = "\x1b[6~" },
+    .{ .name = "delete", .bytes = "\x1b[3~" },
+};
+
+/// Encodes `keys` into bytes for a process terminal, such as `q<Enter>` or
+/// `<C-c>`. Text in angle brackets is a key name; anything else, including
+/// unknown names and the spaces between words, is sent as written. Literal
+/// input is copied unchanged.
+pub fn encode(allocator: std.mem.Allocator, keys: []const u8, literal: bool) ![]u8 {
+    if (literal) return allocator.dupe(u8, keys);
+
+    var out = std.array_list.Managed(u8).init(allocator);
+    errdefer out.deinit();
+
+    var index: usize = 0;
+    while (index < keys.len) {
+        if (keys[index] == '<') {
+            if (std.mem.indexOfScalarPos(u8, keys, index + 1, '>')) |close| {
+                const name = keys[index + 1 .. close];
+                if (controlByte(name)) |byte| {
+                    try out.append(byte);
+                    index = close + 1;
+                    continue;
+                }
+                if (namedKeyBytes(name)) |bytes| {
+                    try out.appendSlice(bytes);
+                    index = close + 1;
+                    continue;
+                }
+            }
+        }
+        try out.append(keys[index]);
+        index += 1;
+    }
+
+    return out.toOwnedSlice();
+}
+
+fn controlByte(word: []const u8) ?u8 {
+    const letter = if (std.mem.startsWith(u8, word, "C-") and word.len == 3)
+        word[2]
+    else if (std.ascii.startsWithIgnoreCase(word, "ctrl+") and word.len == 6)
+        word[5]
+    else
+        return null;
+
+    const lower = std.ascii.toLower(letter);
+    if (lower < 'a' or lower > 'z') return null;
+    return lower - 'a' + 1;
+}
+
+fn namedKeyBytes(word: []const u8) ?[]const u8 {
+    for (named_keys) |key| {
+        if (std.ascii.eqlIgnoreCase(word, key.name)) return key.bytes;
+    }
+    return null;
+}
+
+test "key names encode to terminal bytes" {
+    const encoded = try encode(std.testing.allocator, "<C-c><ctrl+d><Enter><up>", false);
+    defer std.testing.allocator.free(encoded);
+    try std.testing.expectEqualStrings("\x03\x04\r\x1b[A", encoded);
+}
+
+test "stop sequences translate bracketed key names" {
+    const quit = try encode(std.testing.allocator, "q<Enter>", false);
+    defer std.testing.allocator.free(quit);
+    try std.testing.expectEqualStrings("q\r", quit);
+
+    const raw = try encode(std.testing.allocator, "exit <nope>", false);
+    defer std.testing.allocator.free(raw);
+    try std.testing.expectEqualStrings("exit <nope>", raw);
+}
+
+test "text between key names keeps its spaces" {
+    const encoded = try encode(std.testing.allocator, "git status<Enter>", false);
+    defer std.testing.allocator.free(encoded);
+    try std.testing.expectEqualStrings("git status\r", encoded);
+
+    const bare = try encode(std.testing.allocator, "git status Enter", false);
+    defer std.testing.allocator.free(bare);
+    try std.testing.expectEqualStrings("git status Enter", bare);
+}
+
+test "literal keys are sent as text" {
+    const encoded = try encode(std.testing.allocator, "<C-c>", true);
+    defer std.testing.allocator.free(encoded);
+    try std.testing.expectEqualStrings("<C-c>", encoded);
+}
@@ -4,6 +4,9 @@
 const std = @import("std");
 const config = @import("../config/root.zig");
 const env = @import("env.zig");
+const hooks = @import("hooks.zig");
+
+const log = std.log.scoped(.proc_on_kill);
 
 const default_timeout_ms = 30_000;
 
@@ -34,63 +37,14 @@ pub fn executeWithTimeoutMs(
     child.env_map = &env_map;
 
     try child.spawn();
-    const child_pid = child.id;
-
-    var wait_state = WaitState{ .child = &child };
-    const wait_thread = try std.Thread.spawn(.{}, waitChild, .{&wait_state});
-    if (!waitForChild(&wait_state.done, timeout_ms)) {
-        std.posix.kill(child_pid, std.posix.SIG.KILL) catch {};
-        wait_thread.join();
-        return error.OnKillFailed;
-    }
-    wait_thread.join();
-
-    const term = switch (wait_state.result) {
-        .running, .failed => return error.OnKillFailed,
-        .exited => |term| term,
-    };
+    const term = hooks.waitWithTimeout(&child, timeout_ms) catch return error.OnKillFailed;
+    log.debug("on_kill {s} finished: {any}", .{ proc_cfg.on_kill.items[0], term });
     switch (term) {
         .Exited => |code| if (code != 0) return error.OnKillFailed,
         else => return error.OnKillFailed,
     }
 }
 
-const WaitResult = union(enum) {
-    running,
-    exited: std.process.Child.Term,
-    failed: anyerror,
-};
-
-const WaitState = struct {
-    child: *std.process.Child,
-    done: std.atomic.Value(bool) = std.atomic.Value(bool).init(false),
-    result: WaitResult = .running,
-};
-
-fn waitChild(state: *WaitState) void {
-    state.result = .{ .exited = state.child.wait() catch |err| {
-        state.result = .{ .failed = err };
-        state.done.store(true, .release);
-        return;
-    } };
-    state.done.store(true, .release);
-}
-
-fn waitForChild(done: *const std.atomic.Value(bool), timeout_ms: u64) bool {
-    const sleep_ms: u64 = 5;
-    var elapsed_ms: u64 = 0;
-
-    while (elapsed_ms < timeout_ms) {
-        if (done.load(.acquire)) return true;
-        const remaining_ms = timeout_ms - elapsed_ms;
-        const current_sleep_ms: u64 = @min(sleep_ms, remaining_ms);
-        std.Thread.sleep(current_sleep_ms * @as(u64, std.time.ns_per_ms));
-        elapsed_ms += current_sleep_ms;
-    }
-
-    return done.load(.acquire);
-}
-
 test "on kill hook times out and kills long running hook" {
     var tmp = std.testing.tmpDir(.{});
     defer tmp.cleanup();
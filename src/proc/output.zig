@@ -6,8 +6,9 @@ const instance_mod = @import("instance.zig");
 
 const log = std.log.scoped(.proc_output);
 
-/// Copies child output into the process scrollback until the handle closes.
-/// Errors end capture instead of surfacing through the controller thread.
+/// Copies child output into the process scrollback, and the process log file
+/// when configured, until the handle closes. Errors end capture instead of
+/// surfacing through the controller thread.
 pub fn capture(instance: *instance_mod.Instance) void {
     var file = instance.handle.outputFile();
 
@@ -19,5 +20,10 @@ pub fn capture(instance: *instance_mod.Instance) void {
         };
         if (n == 0) return;
         _ = instance.scrollback.write(buf[0..n]);
+        if (instance.log_file) |log_file| log_file.writeAll(buf[0..n]) catch |err| {
+            log.warn("process log write failed: {s}", .{@errorName(err)});
+            log_file.close();
+            instance.log_file = null;
+        };
     }
 }
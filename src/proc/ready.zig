@@ -0,0 +1,83 @@
+//! Readiness probes for `ready_when`.
+//! Command checks run outside the managed process, with its cwd and environment, so a probe can never write into the process terminal.
+
+const std = @import("std");
+const config = @import("../config/root.zig");
+const builder = @import("builder.zig");
+const env = @import("env.zig");
+
+pub const default_timeout_ms = 30_000;
+pub const default_interval_ms = 250;
+
+pub const Check = enum {
+    command,
+    output,
+};
+
+pub fn timeoutMs(check: config.schema.ReadyCheck) u64 {
+    if (check.timeout_ms > 0) return @intCast(check.timeout_ms);
+    return default_timeout_ms;
+}
+
+pub fn intervalMs(check: config.schema.ReadyCheck) u64 {
+    if (check.interval_ms > 0) return @intCast(check.interval_ms);
+    return default_interval_ms;
+}
+
+/// Runs `ready_when.command` once and reports whether it exited 0 within
+/// `timeout_ms`. A probe that overruns is killed and counts as not ready.
+pub fn commandSucceeds(
+    allocator: std.mem.Allocator,
+    proc_cfg: *const config.schema.ProcessConfig,
+    global_config: ?*const config.schema.Config,
+    timeout_ms: u64,
+) !bool {
+    const spec = try builder.buildShellCommand(allocator, proc_cfg.ready_when.command, proc_cfg, global_config);
+    defer spec.deinit(allocator);
+
+    var env_map = try env.buildMap(allocator, proc_cfg);
+    defer env_map.deinit();
+
+    var child = std.process.Child.init(spec.argv, allocator);
+    child.stdin_behavior = .Ignore;
+    child.stdout_behavior = .Ignore;
+    child.stderr_behavior = .Ignore;
+    if (proc_cfg.cwd.len > 0) child.cwd = proc_cfg.cwd;
+    child.env_map = &env_map;
+    try child.spawn();
+
+    const deadline = std.time.milliTimestamp() + @as(i64, @intCast(timeout_ms));
+    while (true) {
+        const result = std.posix.waitpid(child.id, std.posix.W.NOHANG);
+        if (result.pid == child.id) {
+            return std.posix.W.IFEXITED(result.status) and std.posix.W.EXITSTATUS(result.status) == 0;
+        }
+        if (std.time.milliTimestamp() >= deadline) {
+            std.posix.kill(child.id, std.posix.SIG.KILL) catch {};
+            _ = std.posix.waitpid(child.id, 0);
+            return false;
+        }
+        std.Thread.sleep(5 * std.time.ns_per_ms);
+    }
+}
+
+test "ready command reports exit status" {
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+
+    proc_cfg.ready_when.command = "exit 0";
+    try std.testing.expect(try commandSucceeds(std.testing.allocator, &proc_cfg, null, 1000));
+
+    proc_cfg.ready_when.command = "exit 3";
+    try std.testing.expect(!try commandSucceeds(std.testing.allocator, &proc_cfg, null, 1000));
+}
+
+test "ready command that overruns its timeout is not ready" {
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    proc_cfg.ready_when.command = "sleep 5";
+
+    const started = std.time.milliTimestamp();
+    try std.testing.expect(!try commandSucceeds(std.testing.allocator, &proc_cfg, null, 50));
+    try std.testing.expect(std.time.milliTimestamp() - started < 1000);
+}
@@ -6,20 +6,30 @@ const config = @import("../config/root.zig");
 const domain = @import("../domain/root.zig");
 
 pub const builder = @import("builder.zig");
+pub const clipboard = @import("clipboard.zig");
 pub const controller = @import("controller.zig");
 pub const env = @import("env.zig");
+pub const hooks = @import("hooks.zig");
 pub const instance = @import("instance.zig");
+pub const keys = @import("keys.zig");
 pub const on_kill = @import("on_kill.zig");
 pub const output = @import("output.zig");
+pub const ready = @import("ready.zig");
+pub const setup = @import("setup.zig");
 pub const spawn = @import("spawn.zig");
 
 test {
     _ = builder;
+    _ = clipboard;
     _ = controller;
     _ = env;
+    _ = hooks;
     _ = instance;
+    _ = keys;
     _ = on_kill;
     _ = output;
+    _ = ready;
+    _ = setup;
     _ = spawn;
 }
 
@@ -56,6 +66,27 @@ test "command builder uses custom shell command" {
     try std.testing.expectEqualStrings("echo custom", spec.argv[2]);
 }
 
+test "command builder prefers process shell_cmd and keeps quotes in one argument" {
+    var global = config.schema.Config.empty(std.testing.allocator);
+    defer global.deinit();
+    try config.schema.appendOwned(std.testing.allocator, &global.shell_cmd, "/usr/bin/fish");
+    try config.schema.appendOwned(std.testing.allocator, &global.shell_cmd, "-c");
+
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.shell_cmd, "/bin/bash");
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.shell_cmd, "-c");
+    proc_cfg.shell = "echo \"it's\" && FOO='a b' env";
+
+    const spec = try builder.buildCommand(std.testing.allocator, &proc_cfg, &global) orelse return error.ExpectedCommand;
+    defer spec.deinit(std.testing.allocator);
+
+    try std.testing.expectEqual(@as(usize, 3), spec.argv.len);
+    try std.testing.expectEqualStrings("/bin/bash", spec.argv[0]);
+    try std.testing.expectEqualStrings("-c", spec.argv[1]);
+    try std.testing.expectEqualStrings("echo \"it's\" && FOO='a b' env", spec.argv[2]);
+}
+
 test "command builder prefers shell over cmd array" {
     var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
     defer proc_cfg.deinit(std.testing.allocator);
@@ -89,6 +120,27 @@ test "command builder uses argv command and returns null for empty command" {
     try std.testing.expectEqualStrings("/tmp", spec.argv[2]);
 }
 
+test "command builder appends args to shell quoted and to cmd as argv" {
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    proc_cfg.shell = "server --log-level debug";
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.args, "--port=8080");
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.args, "it's here; rm -rf");
+
+    const shell_spec = try builder.buildCommand(std.testing.allocator, &proc_cfg, null) orelse return error.ExpectedCommand;
+    defer shell_spec.deinit(std.testing.allocator);
+    try std.testing.expectEqual(@as(usize, 3), shell_spec.argv.len);
+    try std.testing.expectEqualStrings("server --log-level debug --port=8080 'it'\\''s here; rm -rf'", shell_spec.argv[2]);
+
+    proc_cfg.shell = "";
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "server");
+    const cmd_spec = try builder.buildCommand(std.testing.allocator, &proc_cfg, null) orelse return error.ExpectedCommand;
+    defer cmd_spec.deinit(std.testing.allocator);
+    try std.testing.expectEqual(@as(usize, 3), cmd_spec.argv.len);
+    try std.testing.expectEqualStrings("server", cmd_spec.argv[0]);
+    try std.testing.expectEqualStrings("it's here; rm -rf", cmd_spec.argv[2]);
+}
+
 test "environment builder appends add_path and custom env like legacy behavior" {
     var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
     defer proc_cfg.deinit(std.testing.allocator);
@@ -155,6 +207,39 @@ test "controller rejects duplicate starts and missing stops" {
     try std.testing.expectError(error.ProcessNotFound, ctl.stopProcess(id));
 }
 
+test "env values expand inherited variables" {
+    var base = std.process.EnvMap.init(std.testing.allocator);
+    defer base.deinit();
+    try base.put("HOME", "/home/dev");
+    try base.put("APP_ENV", "development");
+
+    const braced = try env.expandValue(std.testing.allocator, "${HOME}/cache", &base);
+    defer std.testing.allocator.free(braced);
+    try std.testing.expectEqualStrings("/home/dev/cache", braced);
+
+    const bare = try env.expandValue(std.testing.allocator, "$APP_ENV-$MISSING!", &base);
+    defer std.testing.allocator.free(bare);
+    try std.testing.expectEqualStrings("development-!", bare);
+
+    const literal = try env.expandValue(std.testing.allocator, "cost: 5$ $", &base);
+    defer std.testing.allocator.free(literal);
+    try std.testing.expectEqualStrings("cost: 5$ $", literal);
+}
+
+test "controller rejects missing working directory before spawning" {
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    proc_cfg.shell = "echo unreachable";
+    proc_cfg.cwd = "/tmp/proctmux-zig-missing-cwd-does-not-exist";
+
+    var ctl = controller.Controller.init(std.testing.allocator, null);
+    defer ctl.deinit();
+
+    const id = domain.process.ProcessId.fromInt(9);
+    try std.testing.expectError(error.ProcessCwdNotFound, ctl.startProcess(id, &proc_cfg));
+    try std.testing.expect(!ctl.isRunning(id));
+}
+
 test "controller runs on kill hook after user stop" {
     var tmp = std.testing.tmpDir(.{});
     defer tmp.cleanup();
@@ -247,6 +332,26 @@ test "controller deinit skips on kill hook after natural exit" {
     try std.testing.expectError(error.FileNotFound, tmp.dir.access("on_kill.txt", .{}));
 }
 
+test "controller keeps earlier output on restart when reuse_pane is set" {
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    proc_cfg.shell = "printf 'REUSE_RUN'";
+    proc_cfg.reuse_pane = true;
+
+    var ctl = controller.Controller.init(std.testing.allocator, null);
+    defer ctl.deinit();
+
+    const id = domain.process.ProcessId.fromInt(10);
+    _ = try ctl.startProcess(id, &proc_cfg);
+    try waitForScrollbackContains(&ctl, id, "REUSE_RUN");
+    try waitForControllerStopped(&ctl, id);
+    try ctl.cleanupProcess(id);
+
+    _ = try ctl.startProcess(id, &proc_cfg);
+    try waitForScrollbackContains(&ctl, id, "REUSE_RUN\r\nREUSE_RUN");
+    try waitForControllerStopped(&ctl, id);
+}
+
 test "controller clears retained scrollback when process starts again" {
     var tmp = std.testing.tmpDir(.{});
     defer tmp.cleanup();
@@ -306,6 +411,173 @@ test "controller starts process in pty and forwards input" {
     try ctl.stopProcess(id);
 }
 
+test "controller sends named keys to running process" {
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    proc_cfg.stop_timeout_ms = 500;
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "sh");
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "-c");
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "printf ready; IFS= read line; printf 'got:%s' \"$line\"");
+
+    var ctl = controller.Controller.init(std.testing.allocator, null);
+    defer ctl.deinit();
+
+    const id = domain.process.ProcessId.fromInt(6);
+    _ = try ctl.startProcess(id, &proc_cfg);
+    try waitForScrollbackContains(&ctl, id, "ready");
+
+    try ctl.sendKeys(id, "send keys<Enter>", false);
+    try waitForScrollbackContains(&ctl, id, "got:send keys");
+
+    try ctl.stopProcess(id);
+    try std.testing.expectError(error.ProcessNotFound, ctl.sendKeys(id, "<C-c>", false));
+}
+
+test "controller stop sequence is typed instead of signaling" {
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    proc_cfg.stop_sequence = "q<Enter>";
+    proc_cfg.stop_timeout_ms = 2000;
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "sh");
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "-c");
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "trap '' TERM; printf ready; IFS= read line; printf 'quit:%s' \"$line\"");
+
+    var ctl = controller.Controller.init(std.testing.allocator, null);
+    defer ctl.deinit();
+
+    const id = domain.process.ProcessId.fromInt(7);
+    _ = try ctl.startProcess(id, &proc_cfg);
+    try waitForScrollbackContains(&ctl, id, "ready");
+
+    try ctl.stopProcess(id);
+
+    const bytes = try ctl.getScrollback(std.testing.allocator, id);
+    defer std.testing.allocator.free(bytes);
+    try std.testing.expect(std.mem.indexOf(u8, bytes, "quit:q") != null);
+}
+
+test "controller writes process output to log file and creates its directory" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+
+    const root = try tmp.dir.realpathAlloc(std.testing.allocator, ".");
+    defer std.testing.allocator.free(root);
+    const log_path = try std.fs.path.join(std.testing.allocator, &.{ root, "logs", "worker.log" });
+    defer std.testing.allocator.free(log_path);
+
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    proc_cfg.log_file = log_path;
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "sh");
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "-c");
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "printf logged");
+
+    var ctl = controller.Controller.init(std.testing.allocator, null);
+    defer ctl.deinit();
+
+    const id = domain.process.ProcessId.fromInt(8);
+    _ = try ctl.startProcess(id, &proc_cfg);
+    try waitForControllerStopped(&ctl, id);
+    try ctl.cleanupProcess(id);
+    _ = try ctl.startProcess(id, &proc_cfg);
+    try waitForControllerStopped(&ctl, id);
+    try ctl.cleanupProcess(id);
+
+    const contents = try tmp.dir.readFileAlloc(std.testing.allocator, "logs/worker.log", 1024);
+    defer std.testing.allocator.free(contents);
+    try std.testing.expectEqualStrings("loggedlogged", contents);
+}
+
+test "scrollback tail keeps the last lines without trailing whitespace" {
+    try std.testing.expectEqualStrings("one\ntwo\nthree", controller.tailLines("one\ntwo\nthree\n\n", null));
+    try std.testing.expectEqualStrings("two\nthree", controller.tailLines("one\ntwo\nthree\n", 2));
+    try std.testing.expectEqualStrings("one\ntwo", controller.tailLines("one\ntwo", 5));
+    try std.testing.expectEqualStrings("", controller.tailLines("one\ntwo", 0));
+}
+
+test "controller captures retained output for a process" {
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "sh");
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "-c");
+    try config.schema.appendOwned(std.testing.allocator, &proc_cfg.cmd, "printf 'first\\nsecond\\nlast\\n'");
+
+    var ctl = controller.Controller.init(std.testing.allocator, null);
+    defer ctl.deinit();
+
+    const id = domain.process.ProcessId.fromInt(10);
+    _ = try ctl.startProcess(id, &proc_cfg);
+    try waitForControllerStopped(&ctl, id);
+    try ctl.cleanupProcess(id);
+
+    const captured = try ctl.captureOutput(std.testing.allocator, id, 1);
+    defer std.testing.allocator.free(captured);
+    try std.testing.expectEqualStrings("last", captured);
+    try std.testing.expectError(error.ProcessNotFound, ctl.captureOutput(std.testing.allocator, domain.process.ProcessId.fromInt(11), null));
+}
+
+test "controller previews the last output line without escape sequences" {
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    proc_cfg.shell = "printf 'booting\\n\\033[32m  ready on :3000\\033[0m\\n\\n'; sleep 5";
+    proc_cfg.stop_timeout_ms = 500;
+
+    var ctl = controller.Controller.init(std.testing.allocator, null);
+    defer ctl.deinit();
+
+    const id = domain.process.ProcessId.fromInt(15);
+    try std.testing.expectEqualStrings("", ctl.lastLine(id));
+    _ = try ctl.startProcess(id, &proc_cfg);
+    try waitForScrollbackContains(&ctl, id, "[0m");
+    try std.testing.expectEqualStrings("ready on :3000", ctl.lastLine(id));
+    try ctl.stopProcess(id);
+}
+
+test "controller waits for ready_when output before returning" {
+    var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer proc_cfg.deinit(std.testing.allocator);
+    proc_cfg.shell = "sleep 0.2; echo listening; sleep 5";
+    proc_cfg.stop_timeout_ms = 500;
+    proc_cfg.ready_when = .{ .output = "listening", .timeout_ms = 3000, .interval_ms = 20 };
+
+    var ctl = controller.Controller.init(std.testing.allocator, null);
+    defer ctl.deinit();
+
+    const id = domain.process.ProcessId.fromInt(12);
+    _ = try ctl.startProcess(id, &proc_cfg);
+    try ctl.waitUntilReady(id, "api");
+
+    const captured = try ctl.captureOutput(std.testing.allocator, id, null);
+    defer std.testing.allocator.free(captured);
+    try std.testing.expect(std.mem.indexOf(u8, captured, "listening") != null);
+    try ctl.stopProcess(id);
+}
+
+test "controller reports ready_when timeouts and early exits" {
+    var waiting_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer waiting_cfg.deinit(std.testing.allocator);
+    waiting_cfg.shell = "sleep 5";
+    waiting_cfg.stop_timeout_ms = 500;
+    waiting_cfg.ready_when = .{ .command = "exit 1", .timeout_ms = 100, .interval_ms = 20 };
+
+    var exiting_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer exiting_cfg.deinit(std.testing.allocator);
+    exiting_cfg.shell = "exit 0";
+    exiting_cfg.ready_when = .{ .output = "never printed", .timeout_ms = 3000, .interval_ms = 20 };
+
+    var ctl = controller.Controller.init(std.testing.allocator, null);
+    defer ctl.deinit();
+
+    const waiting = domain.process.ProcessId.fromInt(13);
+    _ = try ctl.startProcess(waiting, &waiting_cfg);
+    try std.testing.expectError(error.ReadinessTimeout, ctl.waitUntilReady(waiting, "db"));
+    try ctl.stopProcess(waiting);
+
+    const exiting = domain.process.ProcessId.fromInt(14);
+    _ = try ctl.startProcess(exiting, &exiting_cfg);
+    try std.testing.expectError(error.ProcessExitedBeforeReady, ctl.waitUntilReady(exiting, "migrate"));
+}
+
 test "controller exposes pid and managed process ids" {
     var proc_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
     defer proc_cfg.deinit(std.testing.allocator);
@@ -358,6 +630,65 @@ test "controller adapts to domain process view controller" {
     try std.testing.expectEqual(@as(i32, -1), halted_view.pid);
 }
 
+test "controller reads every process state in one call" {
+    var sleeper_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer sleeper_cfg.deinit(std.testing.allocator);
+    sleeper_cfg.shell = "sleep 5";
+    sleeper_cfg.stop_timeout_ms = 500;
+    var failing_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer failing_cfg.deinit(std.testing.allocator);
+    failing_cfg.shell = "exit 3";
+
+    var ctl = controller.Controller.init(std.testing.allocator, null);
+    defer ctl.deinit();
+
+    const sleeper = domain.process.ProcessId.fromInt(1);
+    const failing = domain.process.ProcessId.fromInt(2);
+    const never_started = domain.process.ProcessId.fromInt(3);
+    _ = try ctl.startProcess(sleeper, &sleeper_cfg);
+    _ = try ctl.startProcess(failing, &failing_cfg);
+    try waitForControllerStopped(&ctl, failing);
+
+    const ids = [_]domain.process.ProcessId{ sleeper, failing, never_started };
+    var live: [ids.len]domain.process.LiveState = undefined;
+    ctl.processController().getStates(&ids, &live);
+    for (ids, live) |id, state| {
+        try std.testing.expectEqual(ctl.getProcessStatus(id), state.status);
+        try std.testing.expectEqual(ctl.getPID(id), state.pid);
+        try std.testing.expectEqual(ctl.exitStatus(id), state.exit_status);
+        try std.testing.expectEqual(ctl.timedOut(id), state.timed_out);
+    }
+    try std.testing.expectEqual(domain.process.ProcessStatus.running, live[0].status);
+    try std.testing.expectEqual(@as(?u32, 3), live[1].exit_status);
+    try std.testing.expectEqual(domain.process.ProcessStatus.halted, live[2].status);
+}
+
+test "controller stops everything within a grace period and kills the rest" {
+    var polite_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer polite_cfg.deinit(std.testing.allocator);
+    polite_cfg.shell = "sleep 5";
+    var stubborn_cfg = config.schema.ProcessConfig.empty(std.testing.allocator);
+    defer stubborn_cfg.deinit(std.testing.allocator);
+    try config.schema.appendOwned(std.testing.allocator, &stubborn_cfg.cmd, "sh");
+    try config.schema.appendOwned(std.testing.allocator, &stubborn_cfg.cmd, "-c");
+    try config.schema.appendOwned(std.testing.allocator, &stubborn_cfg.cmd, "trap '' TERM; printf ready; while :; do sleep 1; done");
+
+    var ctl = controller.Controller.init(std.testing.allocator, null);
+    defer ctl.deinit();
+
+    const polite = domain.process.ProcessId.fromInt(1);
+    const stubborn = domain.process.ProcessId.fromInt(2);
+    _ = try ctl.startProcess(polite, &polite_cfg);
+    _ = try ctl.startProcess(stubborn, &stubborn_cfg);
+    try waitForScrollbackContains(&ctl, stubborn, "ready");
+
+    const killed = try ctl.stopAllWithin(std.testing.allocator, 200);
+    defer std.testing.allocator.free(killed);
+    try std.testing.expectEqualSlices(domain.process.ProcessId, &.{stubborn}, killed);
+    try std.testing.expect(!ctl.isRunning(polite));
+    try std.testing.expect(!ctl.isRunning(stubborn));
+}
+
 fn contains(environment: []const []const u8, needle: []const u8) bool {
     for (environment) |entry| {
         if (std.mem.eql(u8, entry, needle)) return true;
@@ -0,0 +1,100 @@
+//! Project-wide `setup` and `teardown` commands.
+//! Each runs once on the host through the global `shell_cmd`, in the config file's directory with the top-level `environment`; output is captured into the log.
+
+const std = @import("std");
+const config = @import("../config/root.zig");
+const builder = @import("builder.zig");
+const env = @import("env.zig");
+
+const log = std.log.scoped(.proc_setup);
+
+const max_output_bytes = 1024 * 1024;
+
+pub const Phase = enum {
+    setup,
+    teardown,
+
+    fn failure(self: Phase) anyerror {
+        return switch (self) {
+            .setup => error.SetupFailed,
+            .teardown => error.TeardownFailed,
+        };
+    }
+};
+
+/// Runs the command for `phase`, if one is set, and waits for it. A non-zero
+/// exit logs the captured stderr as a warning and returns an error.
+pub fn run(allocator: std.mem.Allocator, phase: Phase, cfg: *const config.schema.Config) !void {
+    const command_line = switch (phase) {
+        .setup => cfg.setup,
+        .teardown => cfg.teardown,
+    };
+    if (std.mem.trim(u8, command_line, " \t\r\n").len == 0) return;
+
+    var host_cfg = config.schema.ProcessConfig.empty(allocator);
+    defer host_cfg.deinit(allocator);
+    var env_it = cfg.environment.iterator();
+    while (env_it.next()) |entry| {
+        try config.schema.putOwnedString(allocator, &host_cfg.env, entry.key_ptr.*, entry.value_ptr.*);
+    }
+
+    const command_spec = try builder.buildShellCommand(allocator, command_line, &host_cfg, cfg);
+    defer command_spec.deinit(allocator);
+    var env_map = try env.buildMap(allocator, &host_cfg);
+    defer env_map.deinit();
+
+    log.info("running {s}: {s}", .{ @tagName(phase), command_line });
+    const result = try std.process.Child.run(.{
+        .allocator = allocator,
+        .argv = command_spec.argv,
+        .cwd = std.fs.path.dirname(cfg.file_path),
+        .env_map = &env_map,
+        .max_output_bytes = max_output_bytes,
+    });
+    defer allocator.free(result.stdout);
+    defer allocator.free(result.stderr);
+
+    logOutput(phase, "stdout", result.stdout);
+    const succeeded = switch (result.term) {
+        .Exited => |code| code == 0,
+        else => false,
+    };
+    if (succeeded) {
+        logOutput(phase, "stderr", result.stderr);
+        return;
+    }
+
+    log.warn("{s} command failed ({any})", .{ @tagName(phase), result.term });
+    const stderr = std.mem.trimRight(u8, result.stderr, "\r\n");
+    if (stderr.len > 0) log.warn("{s} stderr:\n{s}", .{ @tagName(phase), stderr });
+    return phase.failure();
+}
+
+fn logOutput(phase: Phase, stream: []const u8, bytes: []const u8) void {
+    const text = std.mem.trimRight(u8, bytes, "\r\n");
+    if (text.len == 0) return;
+    log.info("{s} {s}:\n{s}", .{ @tagName(phase), stream, text });
+}
+
+test "setup runs in the config directory with the global environment" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    const dir_path = try tmp.dir.realpathAlloc(std.testing.allocator, ".");
+    defer std.testing.allocator.free(dir_path);
+    const file_path = try std.fs.path.join(std.testing.allocator, &.{ dir_path, "proctmux.yaml" });
+    defer std.testing.allocator.free(file_path);
+
+    var cfg = config.schema.Config.empty(std.testing.allocator);
+    defer cfg.deinit();
+    cfg.file_path = file_path;
+    cfg.setup = "printf '%s' \"$NETWORK\" > setup.txt";
+    cfg.teardown = "echo 'network busy' >&2; exit 4";
+    try config.schema.putOwnedString(std.testing.allocator, &cfg.environment, "NETWORK", "dev-net");
+
+    try run(std.testing.allocator, .setup, &cfg);
+    const written = try tmp.dir.readFileAlloc(std.testing.allocator, "setup.txt", 64);
+    defer std.testing.allocator.free(written);
+    try std.testing.expectEqualStrings("dev-net", written);
+
+    try std.testing.expectError(error.TeardownFailed, run(std.testing.allocator, .teardown, &cfg));
+}
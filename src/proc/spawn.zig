@@ -7,6 +7,8 @@ const builder = @import("builder.zig");
 const instance_mod = @import("instance.zig");
 const pty = @import("pty.zig");
 
+const log = std.log.scoped(.proc_spawn);
+
 const default_terminal_rows = 24;
 const default_terminal_cols = 80;
 
@@ -34,19 +36,35 @@ pub fn start(
     command_spec: builder.CommandSpec,
     env_map: *std.process.EnvMap,
 ) !Started {
-    return if (shouldUsePipeProcess())
+    const started = if (shouldUsePipeProcess())
         try startPipe(allocator, proc_cfg, command_spec, env_map)
     else
         try startPty(allocator, proc_cfg, command_spec, env_map);
+    log.debug("started pid {d}: {f}", .{ started.handle.pid(), ArgvFormatter{ .argv = command_spec.argv } });
+    return started;
 }
 
+/// Prints argv space-separated for debug logs without allocating.
+const ArgvFormatter = struct {
+    argv: []const []const u8,
+
+    pub fn format(self: ArgvFormatter, writer: *std.Io.Writer) std.Io.Writer.Error!void {
+        for (self.argv, 0..) |arg, index| {
+            if (index > 0) try writer.writeByte(' ');
+            try writer.writeAll(arg);
+        }
+    }
+};
+
 /// Exit watcher thread entrypoint. It records terminal status on the Instance;
 /// cleanup is still owned by Controller release paths.
 pub fn waitForExit(instance: *instance_mod.Instance) void {
-    const status = instance.handle.wait() catch {
+    const status = instance.handle.wait() catch |err| {
+        log.debug("wait for pid {d} failed: {s}", .{ instance.pid(), @errorName(err) });
         instance.markExited(1);
         return;
     };
+    log.debug("pid {d} exited with status {d}", .{ instance.pid(), status });
     instance.markExited(status);
 }
 
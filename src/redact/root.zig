@@ -64,6 +64,28 @@ pub fn configForIPC(
     out.log_file = try dupeOptional(allocator, source.log_file);
     out.stdout_debug_log_file = try dupeOptional(allocator, source.stdout_debug_log_file);
     out.owns_log_paths = out.log_file.len > 0 or out.stdout_debug_log_file.len > 0;
+    out.control_socket = try dupeOptional(allocator, source.control_socket);
+    out.owns_control_socket = out.control_socket.len > 0;
+    out.http_status_addr = try dupeOptional(allocator, source.http_status_addr);
+    out.owns_http_status_addr = out.http_status_addr.len > 0;
+    out.clipboard_command = try dupeOptional(allocator, source.clipboard_command);
+    out.owns_clipboard_command = out.clipboard_command.len > 0;
+    out.state_file = try dupeOptional(allocator, source.state_file);
+    out.owns_state_file = out.state_file.len > 0;
+    out.pause = try dupeOptional(allocator, source.pause);
+    out.owns_pause = out.pause.len > 0;
+    out.setup = try dupeOptional(allocator, source.setup);
+    out.owns_setup = out.setup.len > 0;
+    out.teardown = try dupeOptional(allocator, source.teardown);
+    out.owns_teardown = out.teardown.len > 0;
+    out.start_delay_ms = source.start_delay_ms;
+    out.confirm_quit_when_running = source.confirm_quit_when_running;
+    out.mouse = source.mouse;
+    out.remain_on_exit = source.remain_on_exit;
+    out.start_when_running = source.start_when_running;
+    out.ipc_timeout_ms = source.ipc_timeout_ms;
+    out.refresh_interval_ms = source.refresh_interval_ms;
+    out.version = source.version;
 
     out.layout = source.layout;
     out.style = source.style;
@@ -89,19 +111,48 @@ pub fn processConfig(
 
     out.owns_scalar_strings = true;
     out.shell = try dupeOptional(allocator, source.shell);
+    out.pause = try dupeOptional(allocator, source.pause);
     out.cwd = try dupeOptional(allocator, source.cwd);
+    out.on_start = try dupeOptional(allocator, source.on_start);
+    out.on_stop = try dupeOptional(allocator, source.on_stop);
+    out.on_success = try dupeOptional(allocator, source.on_success);
+    out.on_failure = try dupeOptional(allocator, source.on_failure);
     out.description = try dupeOptional(allocator, source.description);
     out.docs = try dupeOptional(allocator, source.docs);
+    out.hotkey = try dupeOptional(allocator, source.hotkey);
+    out.color = try dupeOptional(allocator, source.color);
     out.stop = source.stop;
     out.stop_timeout_ms = source.stop_timeout_ms;
+    out.stop_sequence = try dupeOptional(allocator, source.stop_sequence);
+    out.max_runtime_secs = source.max_runtime_secs;
     out.autostart = source.autostart;
+    out.order = source.order;
+    out.start_delay_ms = source.start_delay_ms;
     out.autofocus = source.autofocus;
     out.terminal_rows = source.terminal_rows;
     out.terminal_cols = source.terminal_cols;
+    out.log_file = try dupeOptional(allocator, source.log_file);
+    out.log_append = source.log_append;
+    out.reuse_pane = source.reuse_pane;
+    out.restart = source.restart;
+    out.restart_max_retries = source.restart_max_retries;
+    out.restart_backoff_ms = source.restart_backoff_ms;
+    out.task = source.task;
+    out.keep_on_failure = source.keep_on_failure;
+    out.remain_on_exit = source.remain_on_exit;
+    out.ready_when = .{
+        .command = try dupeOptional(allocator, source.ready_when.command),
+        .output = try dupeOptional(allocator, source.ready_when.output),
+        .timeout_ms = source.ready_when.timeout_ms,
+        .interval_ms = source.ready_when.interval_ms,
+    };
 
     try cloneStringList(allocator, &out.cmd, source.cmd.items);
+    try cloneStringList(allocator, &out.args, source.args.items);
     try cloneStringList(allocator, &out.meta_tags, source.meta_tags.items);
     try cloneStringList(allocator, &out.categories, source.categories.items);
+    try cloneStringList(allocator, &out.depends_on, source.depends_on.items);
+    try cloneStringList(allocator, &out.shell_cmd, source.shell_cmd.items);
     try cloneStringList(allocator, &out.add_path, source.add_path.items);
     try cloneStringList(allocator, &out.on_kill, source.on_kill.items);
     return out;
@@ -118,13 +169,27 @@ fn cloneKeybindingConfig(
     try cloneStringList(allocator, &out.start, source.start.items);
     try cloneStringList(allocator, &out.stop, source.stop.items);
     try cloneStringList(allocator, &out.restart, source.restart.items);
+    try cloneStringList(allocator, &out.start_all, source.start_all.items);
+    try cloneStringList(allocator, &out.stop_all, source.stop_all.items);
     try cloneStringList(allocator, &out.filter, source.filter.items);
     try cloneStringList(allocator, &out.submit_filter, source.submit_filter.items);
     try cloneStringList(allocator, &out.toggle_running, source.toggle_running.items);
+    try cloneStringList(allocator, &out.next_running, source.next_running.items);
+    try cloneStringList(allocator, &out.next_stopped, source.next_stopped.items);
     try cloneStringList(allocator, &out.toggle_help, source.toggle_help.items);
     try cloneStringList(allocator, &out.toggle_focus, source.toggle_focus.items);
     try cloneStringList(allocator, &out.focus_client, source.focus_client.items);
     try cloneStringList(allocator, &out.focus_server, source.focus_server.items);
+    try cloneStringList(allocator, &out.toggle_zoom, source.toggle_zoom.items);
+    try cloneStringList(allocator, &out.grow_output, source.grow_output.items);
+    try cloneStringList(allocator, &out.shrink_output, source.shrink_output.items);
+    try cloneStringList(allocator, &out.copy_mode, source.copy_mode.items);
+    try cloneStringList(allocator, &out.send_input, source.send_input.items);
+    try cloneStringList(allocator, &out.reload_config, source.reload_config.items);
+    try cloneStringList(allocator, &out.edit_config, source.edit_config.items);
+    try cloneStringList(allocator, &out.clear_output, source.clear_output.items);
+    try cloneStringList(allocator, &out.copy_output, source.copy_output.items);
+    try cloneStringList(allocator, &out.command_palette, source.command_palette.items);
     try cloneStringList(allocator, &out.docs, source.docs.items);
 }
 
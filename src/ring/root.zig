@@ -56,6 +56,8 @@ pub const RingBuffer = struct {
     mutex: std.Thread.Mutex = .{},
     readers: std.array_list.Managed(Reader),
     next_id: usize = 0,
+    /// Bytes written since init; `readSince` cursors count against it.
+    written: u64 = 0,
 
     pub fn init(allocator: std.mem.Allocator, capacity: usize) !RingBuffer {
         if (capacity == 0) return error.InvalidCapacity;
@@ -93,6 +95,7 @@ pub const RingBuffer = struct {
             }
         }
 
+        self.written += data.len;
         for (self.readers.items) |*reader| reader.enqueue(data);
         return data.len;
     }
@@ -104,6 +107,28 @@ pub const RingBuffer = struct {
         return self.copyBytesLocked(allocator);
     }
 
+    /// Copies the newest bytes that fit in `out` and returns them, oldest first.
+    pub fn tail(self: *RingBuffer, out: []u8) []u8 {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+
+        return self.copyNewestLocked(out);
+    }
+
+    /// Copies what was written after `cursor.*` and is still retained, then
+    /// moves the cursor to the end. Unlike live readers this never drops bytes
+    /// the buffer still holds; output that scrolled out or was cleared is skipped.
+    pub fn readSince(self: *RingBuffer, allocator: std.mem.Allocator, cursor: *u64) ![]u8 {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+
+        const stored = if (self.full) self.buf.len else self.w;
+        const count: usize = @intCast(@min(self.written -| cursor.*, stored));
+        const out = try allocator.alloc(u8, count);
+        cursor.* = self.written;
+        return self.copyNewestLocked(out);
+    }
+
     pub fn len(self: *RingBuffer) usize {
         self.mutex.lock();
         defer self.mutex.unlock();
@@ -181,6 +206,17 @@ pub const RingBuffer = struct {
         return null;
     }
 
+    fn copyNewestLocked(self: *RingBuffer, out: []u8) []u8 {
+        const stored = if (self.full) self.buf.len else self.w;
+        const count = @min(stored, out.len);
+        var index: usize = 0;
+        while (index < count) : (index += 1) {
+            const offset = (self.w + self.buf.len - count + index) % self.buf.len;
+            out[index] = self.buf[offset];
+        }
+        return out[0..count];
+    }
+
     fn copyBytesLocked(self: *RingBuffer, allocator: std.mem.Allocator) ![]u8 {
         if (!self.full) return allocator.dupe(u8, self.buf[0..self.w]);
 
@@ -217,6 +253,45 @@ test "ring buffer keeps only newest data after overflow" {
     try std.testing.expectEqual(@as(usize, 10), rb.len());
 }
 
+test "ring buffer tail copies the newest bytes across a wrap" {
+    var rb = try RingBuffer.init(std.testing.allocator, 10);
+    defer rb.deinit();
+
+    var out: [4]u8 = undefined;
+    try std.testing.expectEqualStrings("", rb.tail(&out));
+    _ = rb.write("ab");
+    try std.testing.expectEqualStrings("ab", rb.tail(&out));
+    _ = rb.write("0123456789xyz");
+    try std.testing.expectEqualStrings("9xyz", rb.tail(&out));
+}
+
+test "ring buffer cursors read every retained byte across wraps and clears" {
+    var rb = try RingBuffer.init(std.testing.allocator, 10);
+    defer rb.deinit();
+    var cursor: u64 = 0;
+
+    _ = rb.write("abc");
+    const first = try rb.readSince(std.testing.allocator, &cursor);
+    defer std.testing.allocator.free(first);
+    try std.testing.expectEqualStrings("abc", first);
+
+    _ = rb.write("0123456789xyz");
+    const wrapped = try rb.readSince(std.testing.allocator, &cursor);
+    defer std.testing.allocator.free(wrapped);
+    try std.testing.expectEqualStrings("3456789xyz", wrapped);
+
+    _ = rb.write("dropped");
+    rb.clear();
+    _ = rb.write("new");
+    const cleared = try rb.readSince(std.testing.allocator, &cursor);
+    defer std.testing.allocator.free(cleared);
+    try std.testing.expectEqualStrings("new", cleared);
+
+    const idle = try rb.readSince(std.testing.allocator, &cursor);
+    defer std.testing.allocator.free(idle);
+    try std.testing.expectEqualStrings("", idle);
+}
+
 test "ring buffer preserves chronological order across multiple wraps" {
     var rb = try RingBuffer.init(std.testing.allocator, 10);
     defer rb.deinit();
@@ -8,6 +8,7 @@ pub const std_options: std.Options = .{
 };
 
 pub const version = @import("version.zig");
+pub const logging = @import("logging.zig");
 pub const config = @import("config/root.zig");
 pub const domain = @import("domain/root.zig");
 pub const discover = @import("discover/root.zig");
@@ -27,6 +28,7 @@ pub const unified = @import("unified/root.zig");
 
 test {
     _ = version;
+    _ = logging;
     _ = config;
     _ = domain;
     _ = discover;
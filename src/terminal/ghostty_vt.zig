@@ -53,6 +53,18 @@ pub const Terminal = struct {
         self.inner.stream.nextSlice(bytes);
     }
 
+    /// Moves the viewport into scrollback by `delta` rows; up is negative.
+    /// The viewport then stays put while new output arrives.
+    pub fn scrollViewport(self: *Terminal, delta: i32) void {
+        if (delta == 0) return;
+        self.inner.terminal.scrollViewport(.{ .delta = delta });
+    }
+
+    /// Returns the viewport to the newest output.
+    pub fn scrollToBottom(self: *Terminal) void {
+        self.inner.terminal.scrollViewport(.bottom);
+    }
+
     pub fn renderText(self: *Terminal, allocator: std.mem.Allocator) ![]const u8 {
         try self.inner.render_state.update(self.allocator, &self.inner.terminal);
         return renderStateText(allocator, &self.inner.render_state);
@@ -326,3 +338,20 @@ test "ghostty vt resize updates visible viewport" {
 
     try std.testing.expectEqualStrings("three\nfour", rendered);
 }
+
+test "ghostty vt viewport scrolls back into history" {
+    var term = try Terminal.init(std.testing.allocator, 10, 2);
+    defer term.deinit();
+
+    try term.write("one\r\ntwo\r\nthree\r\nfour");
+    term.scrollViewport(-2);
+    try term.write("\r\nfive");
+    const scrolled = try term.renderText(std.testing.allocator);
+    defer std.testing.allocator.free(scrolled);
+    try std.testing.expectEqualStrings("one\ntwo", scrolled);
+
+    term.scrollToBottom();
+    const live = try term.renderText(std.testing.allocator);
+    defer std.testing.allocator.free(live);
+    try std.testing.expectEqualStrings("four\nfive", live);
+}
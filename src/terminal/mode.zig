@@ -2,6 +2,8 @@
 //! This module owns saving/restoring terminal attributes so Runtime Modes can use raw input without leaking terminal state on exit.
 
 const std = @import("std");
+const repaint = @import("repaint.zig");
+const shutdown = @import("shutdown.zig");
 
 /// Saved terminal mode for restoration after raw input. Holding the original
 /// termios value here makes cleanup explicit at Runtime Mode boundaries.
@@ -35,9 +37,59 @@ pub const Mode = struct {
         return .{ .fd = fd, .original = original };
     }
 
+    /// Shutdown cleanup adapter; restoring twice is a no-op.
+    pub fn cleanup(self: *Mode) shutdown.Cleanup {
+        return .{ .context = self, .run = restoreAdapter };
+    }
+
+    fn restoreAdapter(context: *anyopaque) void {
+        const self: *Mode = @ptrCast(@alignCast(context));
+        self.restore();
+    }
+
     pub fn restore(self: *Mode) void {
         const original = self.original orelse return;
         std.posix.tcsetattr(self.fd, .FLUSH, original) catch {};
         self.original = null;
     }
 };
+
+/// Cooked terminal settings lent to a foreground child such as an editor.
+/// `resumeRaw` puts back whatever mode was active before `suspendRaw`.
+pub const Suspended = struct {
+    fd: std.posix.fd_t,
+    saved: ?std.posix.termios = null,
+
+    pub fn resumeRaw(self: *Suspended) void {
+        const saved = self.saved orelse return;
+        std.posix.tcsetattr(self.fd, .FLUSH, saved) catch {};
+        self.saved = null;
+    }
+};
+
+/// Switches `fd` back to line-buffered, echoing input. Signal keys stay off
+/// so ctrl+c in the child cannot stop proctmux. Non-terminals are left alone.
+pub fn suspendRaw(fd: std.posix.fd_t) Suspended {
+    if (!std.posix.isatty(fd)) return .{ .fd = fd };
+    const saved = std.posix.tcgetattr(fd) catch return .{ .fd = fd };
+    var cooked = saved;
+    cooked.iflag.ICRNL = true;
+    cooked.iflag.IXON = true;
+    cooked.lflag.ECHO = true;
+    cooked.lflag.ICANON = true;
+    cooked.lflag.IEXTEN = true;
+    std.posix.tcsetattr(fd, .FLUSH, cooked) catch return .{ .fd = fd };
+    return .{ .fd = fd, .saved = saved };
+}
+
+/// Shutdown cleanup that turns mouse reporting off on stdout, so a signal
+/// does not leave the shell printing click sequences.
+pub fn mouseCleanup() shutdown.Cleanup {
+    return .{ .context = &mouse_cleanup_context, .run = disableMouse };
+}
+
+var mouse_cleanup_context: u8 = 0;
+
+fn disableMouse(_: *anyopaque) void {
+    std.fs.File.stdout().writeAll(repaint.disable_mouse) catch {};
+}
@@ -6,5 +6,9 @@ pub const show_cursor = "\x1b[?25h";
 pub const begin_synchronized_update = "\x1b[?2026h";
 pub const end_synchronized_update = "\x1b[?2026l";
 pub const begin_frame = "\x1b[H";
+pub const clear_screen = "\x1b[2J\x1b[H";
 pub const clear_line_tail = "\x1b[K";
 pub const end_frame = "\x1b[J";
+/// Click reporting in SGR form, for the `mouse` config option.
+pub const enable_mouse = "\x1b[?1000h\x1b[?1006h";
+pub const disable_mouse = "\x1b[?1006l\x1b[?1000l";
@@ -1,14 +1,16 @@
 //! Terminal subsystem namespace.
-//! Importers use this root for dimensions, raw-mode lifecycle, repaint sequences, and VT rendering adapters.
+//! Importers use this root for dimensions, raw-mode lifecycle, signal-driven shutdown cleanup, repaint sequences, and VT rendering adapters.
 
 pub const dimensions = @import("dimensions.zig");
 pub const ghostty_vt = @import("ghostty_vt.zig");
 pub const mode = @import("mode.zig");
 pub const repaint = @import("repaint.zig");
+pub const shutdown = @import("shutdown.zig");
 
 test {
     _ = dimensions;
     _ = ghostty_vt;
     _ = mode;
     _ = repaint;
+    _ = shutdown;
 }
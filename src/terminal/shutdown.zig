@@ -0,0 +1,143 @@
+//! SIGINT/SIGTERM/SIGHUP cleanup for runtime modes.
+//! Signal handlers only record the signal; a watcher thread runs registered cleanups outside signal context and then exits with the conventional 128+signal status.
+
+const std = @import("std");
+
+const log = std.log.scoped(.shutdown);
+
+const max_cleanups = 8;
+const watch_interval_ms = 20;
+
+/// One cleanup step, such as restoring the terminal or stopping processes.
+/// Steps must tolerate running after the normal shutdown path already ran.
+pub const Cleanup = struct {
+    context: *anyopaque,
+    run: *const fn (context: *anyopaque) void,
+};
+
+/// Ordered set of cleanup steps. `runAll` runs newest first and removes each
+/// step before running it, so a second call does nothing.
+pub const Registry = struct {
+    mutex: std.Thread.Mutex = .{},
+    entries: [max_cleanups]?Cleanup = @splat(null),
+
+    pub fn register(self: *Registry, cleanup: Cleanup) !usize {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        for (&self.entries, 0..) |*entry, slot| {
+            if (entry.* == null) {
+                entry.* = cleanup;
+                return slot;
+            }
+        }
+        return error.TooManyCleanups;
+    }
+
+    pub fn unregister(self: *Registry, slot: usize) void {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        self.entries[slot] = null;
+    }
+
+    pub fn runAll(self: *Registry) void {
+        var slot: usize = max_cleanups;
+        while (slot > 0) {
+            slot -= 1;
+            const cleanup = self.take(slot) orelse continue;
+            cleanup.run(cleanup.context);
+        }
+    }
+
+    fn take(self: *Registry, slot: usize) ?Cleanup {
+        self.mutex.lock();
+        defer self.mutex.unlock();
+        const cleanup = self.entries[slot];
+        self.entries[slot] = null;
+        return cleanup;
+    }
+};
+
+var registry: Registry = .{};
+var received_signal = std.atomic.Value(u8).init(0);
+var installed = std.atomic.Value(bool).init(false);
+
+/// Registers a process-wide cleanup step. Pair with `unregister` once the
+/// normal shutdown path has run the same cleanup.
+pub fn register(cleanup: Cleanup) !usize {
+    return registry.register(cleanup);
+}
+
+pub fn unregister(slot: usize) void {
+    registry.unregister(slot);
+}
+
+/// Installs the signal handlers and starts the watcher thread. Later calls
+/// are no-ops.
+pub fn install() !void {
+    if (installed.swap(true, .seq_cst)) return;
+
+    const watcher = try std.Thread.spawn(.{}, watch, .{});
+    watcher.detach();
+
+    var action = std.posix.Sigaction{
+        .handler = .{ .handler = recordSignal },
+        .mask = std.posix.sigemptyset(),
+        .flags = 0,
+    };
+    for ([_]u8{ std.posix.SIG.INT, std.posix.SIG.TERM, std.posix.SIG.HUP }) |sig| {
+        std.posix.sigaction(sig, &action, null);
+    }
+}
+
+fn recordSignal(sig: i32) callconv(.c) void {
+    received_signal.store(@intCast(sig), .seq_cst);
+}
+
+fn watch() void {
+    while (true) {
+        const sig = received_signal.load(.seq_cst);
+        if (sig != 0) {
+            log.info("received signal {d}; cleaning up", .{sig});
+            registry.runAll();
+            std.process.exit(128 +| sig);
+        }
+        std.Thread.sleep(watch_interval_ms * std.time.ns_per_ms);
+    }
+}
+
+const TestCounter = struct {
+    runs: usize = 0,
+
+    fn cleanup(self: *TestCounter) Cleanup {
+        return .{ .context = self, .run = run };
+    }
+
+    fn run(context: *anyopaque) void {
+        const self: *TestCounter = @ptrCast(@alignCast(context));
+        self.runs += 1;
+    }
+};
+
+test "running cleanups twice runs each step once" {
+    var test_registry: Registry = .{};
+    var first = TestCounter{};
+    var second = TestCounter{};
+    _ = try test_registry.register(first.cleanup());
+    _ = try test_registry.register(second.cleanup());
+
+    test_registry.runAll();
+    test_registry.runAll();
+
+    try std.testing.expectEqual(@as(usize, 1), first.runs);
+    try std.testing.expectEqual(@as(usize, 1), second.runs);
+}
+
+test "unregistered cleanups do not run" {
+    var test_registry: Registry = .{};
+    var counter = TestCounter{};
+    const slot = try test_registry.register(counter.cleanup());
+    test_registry.unregister(slot);
+
+    test_registry.runAll();
+    try std.testing.expectEqual(@as(usize, 0), counter.runs);
+}
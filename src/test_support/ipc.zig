@@ -8,13 +8,13 @@ const protocol = @import("../ipc/protocol.zig");
 const server = @import("../ipc/server.zig");
 
 pub const emptySnapshotLine =
-    "{\"type\":\"snapshot\",\"protocol_version\":1,\"current_process_id\":0,\"exiting\":false,\"ui\":{},\"processes\":[]}\n";
+    "{\"type\":\"snapshot\",\"protocol_version\":2,\"current_process_id\":0,\"exiting\":false,\"ui\":{},\"processes\":[]}\n";
 
 pub const apiWorkerSnapshotLine =
-    "{\"type\":\"snapshot\",\"protocol_version\":1,\"current_process_id\":1,\"exiting\":false,\"ui\":{},\"processes\":[{\"id\":1,\"label\":\"api\",\"status\":\"running\",\"pid\":123,\"description\":\"\",\"docs\":\"\",\"categories\":[]},{\"id\":2,\"label\":\"worker\",\"status\":\"halted\",\"pid\":-1,\"description\":\"\",\"docs\":\"\",\"categories\":[]}]}\n";
+    "{\"type\":\"snapshot\",\"protocol_version\":2,\"current_process_id\":1,\"exiting\":false,\"ui\":{},\"processes\":[{\"id\":1,\"label\":\"api\",\"status\":\"running\",\"pid\":123,\"description\":\"\",\"docs\":\"\",\"categories\":[]},{\"id\":2,\"label\":\"worker\",\"status\":\"halted\",\"pid\":-1,\"description\":\"\",\"docs\":\"\",\"categories\":[]}]}\n";
 
 pub const selectedApiSnapshotLine =
-    "{\"type\":\"snapshot\",\"protocol_version\":1,\"current_process_id\":2,\"exiting\":false,\"ui\":{},\"processes\":[{\"id\":2,\"label\":\"api\",\"status\":\"running\",\"pid\":1002,\"description\":\"\",\"docs\":\"\",\"categories\":[]}]}\n";
+    "{\"type\":\"snapshot\",\"protocol_version\":2,\"current_process_id\":2,\"exiting\":false,\"ui\":{},\"processes\":[{\"id\":2,\"label\":\"api\",\"status\":\"running\",\"pid\":1002,\"description\":\"\",\"docs\":\"\",\"categories\":[]}]}\n";
 
 pub fn snapshotLineFromAppState(
     allocator: std.mem.Allocator,
@@ -30,12 +30,16 @@ pub const FakeProcessController = struct {
     status: domain.process.ProcessStatus = .halted,
     pid: i32 = -1,
     running_id: ?domain.process.ProcessId = null,
+    exit_status: ?u32 = null,
+    timed_out: bool = false,
 
     pub fn controller(self: *FakeProcessController) domain.process.ProcessController {
         return .{
             .context = self,
             .get_process_status = getProcessStatus,
             .get_pid = getPID,
+            .get_exit_status = getExitStatus,
+            .get_timed_out = getTimedOut,
         };
     }
 
@@ -55,6 +59,19 @@ pub const FakeProcessController = struct {
         }
         return self.pid;
     }
+
+    fn getExitStatus(context: *anyopaque, id: domain.process.ProcessId) ?u32 {
+        const self: *FakeProcessController = @ptrCast(@alignCast(context));
+        if (self.running_id) |running_id| {
+            if (id == running_id) return null;
+        }
+        return self.exit_status;
+    }
+
+    fn getTimedOut(context: *anyopaque, _: domain.process.ProcessId) bool {
+        const self: *FakeProcessController = @ptrCast(@alignCast(context));
+        return self.timed_out;
+    }
 };
 
 pub const FakeCommandHandler = struct {
@@ -135,7 +152,7 @@ pub const ServerErrorCapture = struct {
 };
 
 pub const successResponseLine =
-    "{\"type\":\"response\",\"protocol_version\":1,\"request_id\":1,\"success\":true,\"error\":\"\"}\n";
+    "{\"type\":\"response\",\"protocol_version\":2,\"request_id\":1,\"success\":true,\"error\":\"\"}\n";
 
 pub const CommandCapture = struct {
     request: [512]u8 = undefined,
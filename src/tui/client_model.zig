@@ -5,6 +5,7 @@ const std = @import("std");
 const config = @import("../config/root.zig");
 const domain = @import("../domain/root.zig");
 const ipc = @import("../ipc/root.zig");
+const palette = @import("palette.zig");
 const test_config = @import("../test_support/config.zig");
 
 /// Command intent emitted by local key handling. The session decides whether it
@@ -12,10 +13,22 @@ const test_config = @import("../test_support/config.zig");
 pub const CommandIntent = struct {
     action: ipc.protocol.Command,
     label: []const u8,
+    /// Text for `send_input`, borrowed from the model until the next key.
+    input: []const u8 = "",
 };
 
 pub const message_timeout_ms: i64 = 5000;
 
+/// Counts behind the status bar. `filter` and `selected` borrow from the
+/// model and are only valid until its next update.
+pub const Summary = struct {
+    total: usize,
+    running: usize,
+    stopped: usize,
+    filter: []const u8,
+    selected: []const u8,
+};
+
 pub const TimedMessage = struct {
     text: []const u8,
     expires_at_ms: i64,
@@ -29,9 +42,28 @@ pub const ClientModel = struct {
     filtered_processes: []domain.client_snapshot.ProcessSummary,
     filter_text: std.array_list.Managed(u8),
     messages: std.array_list.Managed(TimedMessage),
+    input_text: std.array_list.Managed(u8),
+    palette_text: std.array_list.Managed(u8),
+    /// Palette actions matching `palette_text`, best first.
+    palette_actions: []palette.Action,
+    palette_index: usize = 0,
     entering_filter_text: bool = false,
+    /// Set while a line of input for the selected process is being typed.
+    entering_input_text: bool = false,
     show_only_running: bool = false,
     show_help: bool = false,
+    /// Set after a quit key was swallowed to confirm stopping running
+    /// processes; any other key cancels it.
+    quit_pending: bool = false,
+    /// Set by the edit-config key; the runtime opens the editor and clears it.
+    edit_config_requested: bool = false,
+    /// Set while the command palette is open.
+    entering_palette: bool = false,
+    /// Set in unified mode, the only mode with an output pane to zoom.
+    zoom_available: bool = false,
+    /// Set by the palette's zoom action; the unified runtime toggles zoom and
+    /// clears it.
+    zoom_requested: bool = false,
     mode: domain.state.Mode = .normal,
     active_proc_id: domain.process.ProcessId = .none,
     term_width: usize = 80,
@@ -49,6 +81,9 @@ pub const ClientModel = struct {
             .filtered_processes = try allocator.alloc(domain.client_snapshot.ProcessSummary, 0),
             .filter_text = std.array_list.Managed(u8).init(allocator),
             .messages = std.array_list.Managed(TimedMessage).init(allocator),
+            .input_text = std.array_list.Managed(u8).init(allocator),
+            .palette_text = std.array_list.Managed(u8).init(allocator),
+            .palette_actions = try allocator.alloc(palette.Action, 0),
             .active_proc_id = snapshot.currentProcessId(),
         };
         errdefer model.deinit();
@@ -59,6 +94,9 @@ pub const ClientModel = struct {
     pub fn deinit(self: *ClientModel) void {
         self.allocator.free(self.filtered_processes);
         self.filter_text.deinit();
+        self.input_text.deinit();
+        self.palette_text.deinit();
+        self.allocator.free(self.palette_actions);
         for (self.messages.items) |message_entry| self.allocator.free(message_entry.text);
         self.messages.deinit();
     }
@@ -67,6 +105,18 @@ pub const ClientModel = struct {
         return self.filter_text.items;
     }
 
+    pub fn inputText(self: *const ClientModel) []const u8 {
+        return self.input_text.items;
+    }
+
+    pub fn paletteText(self: *const ClientModel) []const u8 {
+        return self.palette_text.items;
+    }
+
+    pub fn paletteActions(self: *const ClientModel) []const palette.Action {
+        return self.palette_actions;
+    }
+
     pub fn addMessage(self: *ClientModel, text: []const u8) !void {
         try self.addMessageAt(text, std.time.milliTimestamp());
     }
@@ -126,8 +176,8 @@ pub const ClientModel = struct {
     }
 
     pub fn activeProcessSummary(self: *const ClientModel) ?domain.client_snapshot.ProcessSummary {
-        for (self.snapshot.processes) |summary| {
-            if (domain.process.ProcessId.fromInt(summary.id) == self.active_proc_id) return summary;
+        for (self.snapshot.processes) |process_summary| {
+            if (domain.process.ProcessId.fromInt(process_summary.id) == self.active_proc_id) return process_summary;
         }
         return null;
     }
@@ -136,6 +186,58 @@ pub const ClientModel = struct {
         return self.activeProcLabel();
     }
 
+    /// Summarizes the latest Client Snapshot. Counts follow the server-pushed
+    /// snapshot rather than the filtered list, so they only change per update.
+    pub fn summary(self: *const ClientModel) Summary {
+        const running = self.runningCount();
+        return .{
+            .total = self.processCount(),
+            .running = running,
+            .stopped = self.processCount() - running,
+            .filter = self.filterText(),
+            .selected = self.activeProcLabel(),
+        };
+    }
+
+    /// True when `label` names a process with a live terminal. The selection
+    /// can rest on a stopped process, which has no output worth focusing.
+    pub fn isRunning(self: *const ClientModel, label: []const u8) bool {
+        for (self.snapshot.processes) |process_summary| {
+            if (std.mem.eql(u8, process_summary.label, label)) return process_summary.status == .running;
+        }
+        return false;
+    }
+
+    /// Decides whether output focus may move to the selected process, leaving
+    /// a message explaining why not when it is missing or stopped.
+    pub fn allowOutputFocus(self: *ClientModel) !bool {
+        const label = self.activeProcLabel();
+        if (label.len == 0) {
+            try self.addMessage("no process selected");
+            return false;
+        }
+        if (self.isRunning(label)) return true;
+
+        const text = try std.fmt.allocPrint(self.allocator, "{s} is not running; start it to view its output", .{label});
+        defer self.allocator.free(text);
+        try self.addMessage(text);
+        return false;
+    }
+
+    /// Puts back a remembered filter and selection. A label that no longer
+    /// exists or is filtered out leaves the first visible process selected.
+    pub fn restoreSelection(self: *ClientModel, label: []const u8, filter: []const u8) !void {
+        self.filter_text.clearRetainingCapacity();
+        try self.filter_text.appendSlice(filter);
+        try self.applyFilterLocal();
+        for (self.filtered_processes) |process_summary| {
+            if (std.mem.eql(u8, process_summary.label, label)) {
+                self.active_proc_id = domain.process.ProcessId.fromInt(process_summary.id);
+                return;
+            }
+        }
+    }
+
     /// Replaces server-provided data while preserving local UI choices such as
     /// filter text, running-only mode, help visibility, and selection.
     pub fn replaceSnapshotPreservingUI(
@@ -157,6 +259,11 @@ pub const ClientModel = struct {
     /// Applies one normalized key. Local UI keys are handled immediately;
     /// process lifecycle keys return an intent for the Client Session to send.
     pub fn handleKey(self: *ClientModel, key: []const u8) !?CommandIntent {
+        const quit_was_pending = self.quit_pending;
+        self.quit_pending = false;
+
+        if (self.entering_input_text) return self.handleInputKey(key);
+        if (self.entering_palette) return self.handlePaletteKey(key);
         if (self.entering_filter_text) {
             if (self.processListIntentForControlModifiedKey(key)) |intent| return intent;
 
@@ -195,11 +302,13 @@ pub const ClientModel = struct {
         }
 
         if (matches(self.snapshot.ui.keybinding.filter, key)) {
-            self.entering_filter_text = true;
-            self.mode = .filter;
-            self.filter_text.clearRetainingCapacity();
-            self.active_proc_id = .none;
-            try self.rebuildProcessList();
+            try self.beginFilter();
+            return null;
+        }
+        if (matches(self.snapshot.ui.keybinding.command_palette, key)) {
+            self.entering_palette = true;
+            self.palette_text.clearRetainingCapacity();
+            try self.refilterPalette();
             return null;
         }
         if (matches(self.snapshot.ui.keybinding.down, key)) {
@@ -210,10 +319,15 @@ pub const ClientModel = struct {
             self.moveSelection(-1);
             return self.switchIntent();
         }
+        if (self.jumpIntentForKey(key)) |intent| return intent;
+        if (matches(self.snapshot.ui.keybinding.next_running, key)) {
+            return self.selectNextWithRunning(true);
+        }
+        if (matches(self.snapshot.ui.keybinding.next_stopped, key)) {
+            return self.selectNextWithRunning(false);
+        }
         if (matches(self.snapshot.ui.keybinding.toggle_running, key)) {
-            self.show_only_running = !self.show_only_running;
-            try self.applyFilterLocal();
-            return self.syncActiveSelection();
+            return self.toggleRunningIntent();
         }
         if (matches(self.snapshot.ui.keybinding.start, key)) {
             return self.commandIntent(.start);
@@ -224,19 +338,230 @@ pub const ClientModel = struct {
         if (matches(self.snapshot.ui.keybinding.restart, key)) {
             return self.commandIntent(.restart);
         }
+        if (matches(self.snapshot.ui.keybinding.start_all, key)) {
+            return .{ .action = .start_all, .label = "" };
+        }
+        if (matches(self.snapshot.ui.keybinding.stop_all, key)) {
+            return .{ .action = .stop_all, .label = "" };
+        }
+        if (matches(self.snapshot.ui.keybinding.send_input, key)) {
+            try self.beginInput();
+            return null;
+        }
+        if (matches(self.snapshot.ui.keybinding.clear_output, key)) {
+            return self.clearOutputIntent();
+        }
+        if (matches(self.snapshot.ui.keybinding.copy_output, key)) {
+            return self.commandIntent(.copy_output);
+        }
+        if (matches(self.snapshot.ui.keybinding.reload_config, key)) {
+            return .{ .action = .reload, .label = "" };
+        }
+        if (matches(self.snapshot.ui.keybinding.edit_config, key)) {
+            self.edit_config_requested = true;
+            return null;
+        }
         if (matches(self.snapshot.ui.keybinding.toggle_help, key)) {
             self.show_help = !self.show_help;
             return null;
         }
         if (matches(self.snapshot.ui.keybinding.quit, key)) {
+            return self.quitIntent(key, quit_was_pending);
+        }
+        return self.hotkeyIntent(key);
+    }
+
+    /// Moves to the next listed process after the selection that is running,
+    /// or stopped when `running` is false, wrapping past the end. Statuses
+    /// come from the last snapshot. With no match the selection stays put.
+    fn selectNextWithRunning(self: *ClientModel, running: bool) !?CommandIntent {
+        const processes = self.filtered_processes;
+        var start: usize = 0;
+        for (processes, 0..) |process_summary, index| {
+            if (domain.process.ProcessId.fromInt(process_summary.id) != self.active_proc_id) continue;
+            start = index + 1;
+            break;
+        }
+        for (0..processes.len) |offset| {
+            const candidate = processes[(start + offset) % processes.len];
+            if ((candidate.status == .running) != running) continue;
+            self.active_proc_id = domain.process.ProcessId.fromInt(candidate.id);
+            return self.switchIntent();
+        }
+        try self.addMessage(if (running) "no running process to jump to" else "no stopped process to jump to");
+        return null;
+    }
+
+    fn beginFilter(self: *ClientModel) !void {
+        self.entering_filter_text = true;
+        self.mode = .filter;
+        self.filter_text.clearRetainingCapacity();
+        self.active_proc_id = .none;
+        try self.rebuildProcessList();
+    }
+
+    fn beginInput(self: *ClientModel) !void {
+        if (self.activeProcLabel().len == 0) {
+            try self.addMessage("no process selected");
+            return;
+        }
+        self.entering_input_text = true;
+        self.input_text.clearRetainingCapacity();
+    }
+
+    fn toggleRunningIntent(self: *ClientModel) !?CommandIntent {
+        self.show_only_running = !self.show_only_running;
+        try self.applyFilterLocal();
+        return self.syncActiveSelection();
+    }
+
+    fn clearOutputIntent(self: *ClientModel) !?CommandIntent {
+        const label = self.activeProcLabel();
+        if (label.len > 0 and !self.isRunning(label)) {
+            const text = try std.fmt.allocPrint(self.allocator, "{s} is not running; nothing to clear", .{label});
+            defer self.allocator.free(text);
+            try self.addMessage(text);
+            return null;
+        }
+        return self.commandIntent(.clear_output);
+    }
+
+    /// Quits, or with processes running asks for `key` to be pressed again
+    /// unless it already was.
+    fn quitIntent(self: *ClientModel, key: []const u8, quit_was_pending: bool) !?CommandIntent {
+        const running = self.runningCount();
+        if (self.snapshot.ui.confirm_quit_when_running and running > 0 and !quit_was_pending) {
+            self.quit_pending = true;
+            const text = try std.fmt.allocPrint(self.allocator, "{d} running; press {s} again to quit and stop them", .{ running, key });
+            defer self.allocator.free(text);
+            try self.addMessage(text);
+            return null;
+        }
+        return .{
+            .action = .stop_running,
+            .label = "",
+        };
+    }
+
+    /// Narrows the palette as text is typed; up and down move the highlight,
+    /// enter runs it and esc closes the palette.
+    fn handlePaletteKey(self: *ClientModel, key: []const u8) !?CommandIntent {
+        const closes = std.mem.eql(u8, key, "esc") or
+            (self.palette_text.items.len == 0 and matches(self.snapshot.ui.keybinding.command_palette, key));
+        if (closes) {
+            self.entering_palette = false;
+            return null;
+        }
+        if (std.mem.eql(u8, key, "enter")) {
+            self.entering_palette = false;
+            if (self.palette_actions.len == 0) return null;
+            return self.runPaletteAction(self.palette_actions[self.palette_index]);
+        }
+        if (std.mem.eql(u8, key, "up") or std.mem.eql(u8, key, "ctrl+p")) {
+            if (self.palette_index > 0) self.palette_index -= 1;
+            return null;
+        }
+        if (std.mem.eql(u8, key, "down") or std.mem.eql(u8, key, "ctrl+n")) {
+            if (self.palette_index + 1 < self.palette_actions.len) self.palette_index += 1;
+            return null;
+        }
+        if (std.mem.eql(u8, key, "delete") or std.mem.eql(u8, key, "backspace")) {
+            if (self.palette_text.items.len > 0) self.palette_text.items.len -= 1;
+            try self.refilterPalette();
+            return null;
+        }
+        if (isTextInputKey(key)) {
+            try self.palette_text.appendSlice(key);
+            try self.refilterPalette();
+        }
+        return null;
+    }
+
+    fn refilterPalette(self: *ClientModel) !void {
+        const actions = try palette.filter(self.allocator, self.palette_text.items, self.zoom_available);
+        self.allocator.free(self.palette_actions);
+        self.palette_actions = actions;
+        self.palette_index = 0;
+    }
+
+    /// Does what the action's key does, so the palette and the keybindings
+    /// cannot drift apart.
+    fn runPaletteAction(self: *ClientModel, action: palette.Action) !?CommandIntent {
+        const bindings = &self.snapshot.ui.keybinding;
+        switch (action) {
+            .start => return self.commandIntent(.start),
+            .stop => return self.commandIntent(.stop),
+            .restart => return self.commandIntent(.restart),
+            .start_all => return .{ .action = .start_all, .label = "" },
+            .stop_all => return .{ .action = .stop_all, .label = "" },
+            .send_input => try self.beginInput(),
+            .clear_output => return self.clearOutputIntent(),
+            .copy_output => return self.commandIntent(.copy_output),
+            .filter => try self.beginFilter(),
+            .toggle_running => return self.toggleRunningIntent(),
+            .next_running => return self.selectNextWithRunning(true),
+            .next_stopped => return self.selectNextWithRunning(false),
+            .toggle_zoom => self.zoom_requested = true,
+            .reload_config => return .{ .action = .reload, .label = "" },
+            .edit_config => self.edit_config_requested = true,
+            .toggle_help => self.show_help = !self.show_help,
+            .quit => return self.quitIntent(if (bindings.quit.len > 0) bindings.quit[0] else "quit", false),
+        }
+        return null;
+    }
+
+    /// Jumps the selection to the process whose `hotkey` is `key`. A process
+    /// hidden by the filter is reported rather than selected.
+    fn hotkeyIntent(self: *ClientModel, key: []const u8) !?CommandIntent {
+        if (!isTextInputKey(key)) return null;
+        for (self.snapshot.processes) |process_summary| {
+            if (!std.mem.eql(u8, process_summary.hotkey, key)) continue;
+            for (self.filtered_processes) |visible| {
+                if (visible.id != process_summary.id) continue;
+                self.active_proc_id = domain.process.ProcessId.fromInt(process_summary.id);
+                return self.switchIntent();
+            }
+            const text = try std.fmt.allocPrint(self.allocator, "{s} is hidden by the filter", .{process_summary.label});
+            defer self.allocator.free(text);
+            try self.addMessage(text);
+            return null;
+        }
+        return null;
+    }
+
+    /// Edits the input line; enter sends it followed by a carriage return, the
+    /// byte a terminal sends for Enter, and esc discards it.
+    fn handleInputKey(self: *ClientModel, key: []const u8) !?CommandIntent {
+        if (std.mem.eql(u8, key, "enter")) {
+            self.entering_input_text = false;
+            try self.input_text.append('\r');
             return .{
-                .action = .stop_running,
-                .label = "",
+                .action = .send_input,
+                .label = self.activeProcLabel(),
+                .input = self.input_text.items,
             };
         }
+        if (std.mem.eql(u8, key, "esc")) {
+            self.entering_input_text = false;
+            self.input_text.clearRetainingCapacity();
+            return null;
+        }
+        if (std.mem.eql(u8, key, "delete") or std.mem.eql(u8, key, "backspace")) {
+            if (self.input_text.items.len > 0) self.input_text.items.len -= 1;
+            return null;
+        }
+        if (isTextInputKey(key)) try self.input_text.appendSlice(key);
         return null;
     }
 
+    fn runningCount(self: *const ClientModel) usize {
+        var count: usize = 0;
+        for (self.snapshot.processes) |process_summary| {
+            if (process_summary.status == .running) count += 1;
+        }
+        return count;
+    }
+
     fn applyFilterLocal(self: *ClientModel) !void {
         try self.rebuildProcessList();
         if (self.filtered_processes.len == 0) {
@@ -290,7 +615,58 @@ pub const ClientModel = struct {
             if (self.active_proc_id.isNone()) return null;
             return self.switchIntent();
         }
-        return null;
+        return self.jumpIntentForKey(key);
+    }
+
+    /// Page and home/end keys move by a screenful or to either end of the
+    /// list. Unlike up/down they stop at the edges instead of wrapping.
+    fn jumpIntentForKey(self: *ClientModel, key: []const u8) ?CommandIntent {
+        const count = self.filtered_processes.len;
+        if (count == 0) return null;
+
+        const current = self.selectedIndex();
+        const page = self.pageSize();
+        const target = if (std.mem.eql(u8, key, "pagedown"))
+            @min(current + page, count - 1)
+        else if (std.mem.eql(u8, key, "pageup"))
+            current -| page
+        else if (std.mem.eql(u8, key, "home"))
+            0
+        else if (std.mem.eql(u8, key, "end"))
+            count - 1
+        else
+            return null;
+        self.active_proc_id = domain.process.ProcessId.fromInt(self.filtered_processes[target].id);
+        return self.switchIntent();
+    }
+
+    /// Selects the process drawn on list row `row` (0-based, counted from the
+    /// top of the list area), given where process rows start and which slice
+    /// of the visible list is on screen. Null when the row holds no process.
+    pub fn selectListRow(self: *ClientModel, row: usize, first_row: usize, window_start: usize, window_end: usize) ?CommandIntent {
+        if (self.show_help or self.entering_filter_text or self.entering_input_text or self.entering_palette) return null;
+        if (row < first_row) return null;
+        const index = window_start + (row - first_row);
+        if (index >= window_end or index >= self.filtered_processes.len) return null;
+        self.active_proc_id = domain.process.ProcessId.fromInt(self.filtered_processes[index].id);
+        return self.switchIntent();
+    }
+
+    /// Process rows one screen of the list holds; the whole list when the
+    /// terminal height is unknown.
+    fn pageSize(self: *const ClientModel) usize {
+        if (self.term_height == 0) return @max(self.filtered_processes.len, 1);
+        const header_rows: usize = if (self.show_panel_headers) 1 else 0;
+        const status_rows: usize = if (self.snapshot.ui.layout.show_status_bar) 1 else 0;
+        return @max(self.term_height -| (header_rows + status_rows), 1);
+    }
+
+    /// Index of the selection in the visible list; 0 when nothing is selected.
+    fn selectedIndex(self: *const ClientModel) usize {
+        for (self.filtered_processes, 0..) |process_summary, index| {
+            if (domain.process.ProcessId.fromInt(process_summary.id) == self.active_proc_id) return index;
+        }
+        return 0;
     }
 
     fn commandIntent(self: *ClientModel, action: ipc.protocol.Command) CommandIntent {
@@ -311,8 +687,8 @@ pub const ClientModel = struct {
         }
 
         var current_index: ?usize = null;
-        for (self.filtered_processes, 0..) |summary, index| {
-            if (domain.process.ProcessId.fromInt(summary.id) == self.active_proc_id) {
+        for (self.filtered_processes, 0..) |process_summary, index| {
+            if (domain.process.ProcessId.fromInt(process_summary.id) == self.active_proc_id) {
                 current_index = index;
                 break;
             }
@@ -332,8 +708,8 @@ pub const ClientModel = struct {
     }
 
     fn activeProcLabel(self: *const ClientModel) []const u8 {
-        const summary = self.activeProcessSummary() orelse return "";
-        return summary.label;
+        const process_summary = self.activeProcessSummary() orelse return "";
+        return process_summary.label;
     }
 
     fn rebuildProcessList(self: *ClientModel) !void {
@@ -372,6 +748,73 @@ fn replaceTestBinding(list: *config.schema.StringList, values: []const []const u
     for (values) |value| try config.schema.appendOwned(std.testing.allocator, list, value);
 }
 
+test "client model collects a line of input for the selected process" {
+    var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    var app_state = try domain.state.AppState.init(std.testing.allocator, &cfg);
+    defer app_state.deinit();
+    app_state.current_proc_id = domain.process.ProcessId.fromInt(1);
+
+    var views = test_config.standardClientModelViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, app_state.current_proc_id, views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey("i"));
+    try std.testing.expect(model.entering_input_text);
+    for ([_][]const u8{ "y", "e", "s", "x", "backspace", ";", " ", "q" }) |key| {
+        try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey(key));
+    }
+    try std.testing.expectEqualStrings("yes; q", model.inputText());
+
+    const intent = (try model.handleKey("enter")).?;
+    try std.testing.expectEqual(ipc.protocol.Command.send_input, intent.action);
+    try std.testing.expectEqualStrings(model.activeProcessLabel(), intent.label);
+    try std.testing.expectEqualStrings("yes; q\r", intent.input);
+    try std.testing.expect(!model.entering_input_text);
+
+    _ = try model.handleKey("i");
+    _ = try model.handleKey("n");
+    try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey("esc"));
+    try std.testing.expect(!model.entering_input_text);
+    try std.testing.expectEqualStrings("", model.inputText());
+}
+
+test "client model only allows output focus for a running selection" {
+    var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    const worker = domain.process.ProcessId.fromInt(2);
+    var views = test_config.standardClientModelViews(&cfg);
+    var stopped = try test_config.snapshotFromViews(std.testing.allocator, &cfg, worker, views[0..]);
+    defer stopped.deinit(std.testing.allocator);
+
+    var model = try ClientModel.init(std.testing.allocator, stopped.view());
+    defer model.deinit();
+
+    try std.testing.expect(model.isRunning("alpha-api"));
+    try std.testing.expect(!model.isRunning("beta-worker"));
+    try std.testing.expect(!model.isRunning("missing"));
+    try std.testing.expect(!try model.allowOutputFocus());
+    try std.testing.expectEqualStrings("beta-worker is not running; start it to view its output", model.message(0));
+
+    views[1].status = .running;
+    var running = try test_config.snapshotFromViews(std.testing.allocator, &cfg, worker, views[0..]);
+    defer running.deinit(std.testing.allocator);
+    try model.replaceSnapshotPreservingUI(running.view());
+    try std.testing.expect(try model.allowOutputFocus());
+    try std.testing.expectEqual(@as(usize, 1), model.messageCount());
+
+    views[1].status = .exited;
+    var exited = try test_config.snapshotFromViews(std.testing.allocator, &cfg, worker, views[0..]);
+    defer exited.deinit(std.testing.allocator);
+    try model.replaceSnapshotPreservingUI(exited.view());
+    try std.testing.expect(!try model.allowOutputFocus());
+}
+
 test "client model enters filter mode with configured filter key" {
     var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
     defer cfg.deinit();
@@ -430,6 +873,72 @@ test "client model typing filter narrows list and selects first match" {
     try std.testing.expectEqualStrings("gamma-db", submitted.?.label);
 }
 
+test "client model restores a remembered filter and selection" {
+    var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    var views = test_config.standardClientModelViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, domain.process.ProcessId.fromInt(1), views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    try model.restoreSelection("gamma-db", "a");
+    try std.testing.expectEqualStrings("a", model.filterText());
+    try std.testing.expectEqualStrings("gamma-db", model.activeProcessLabel());
+
+    try model.restoreSelection("removed", "");
+    try std.testing.expectEqual(@as(usize, 3), model.visibleCount());
+    try std.testing.expectEqualStrings("alpha-api", model.activeProcessLabel());
+
+    try model.restoreSelection("beta-worker", "gamma");
+    try std.testing.expectEqualStrings("gamma-db", model.activeProcessLabel());
+}
+
+test "client model hotkeys jump to their process" {
+    var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
+    defer cfg.deinit();
+    cfg.procs.getPtr("gamma-db").?.hotkey = try std.testing.allocator.dupe(u8, "3");
+
+    var views = test_config.standardClientModelViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, domain.process.ProcessId.fromInt(1), views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    const intent = (try model.handleKey("3")).?;
+    try std.testing.expectEqual(ipc.protocol.Command.switch_process, intent.action);
+    try std.testing.expectEqualStrings("gamma-db", intent.label);
+    try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey("4"));
+
+    try model.restoreSelection("alpha-api", "alpha");
+    try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey("3"));
+    try std.testing.expectEqualStrings("alpha-api", model.activeProcessLabel());
+    try std.testing.expectEqualStrings("gamma-db is hidden by the filter", model.message(0));
+}
+
+test "client model clears output only for a running selection" {
+    var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    var views = test_config.standardClientModelViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, domain.process.ProcessId.fromInt(1), views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    const intent = (try model.handleKey("ctrl+l")).?;
+    try std.testing.expectEqual(ipc.protocol.Command.clear_output, intent.action);
+    try std.testing.expectEqualStrings("alpha-api", intent.label);
+
+    _ = try model.handleKey("j");
+    try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey("ctrl+l"));
+    try std.testing.expectEqualStrings("beta-worker is not running; nothing to clear", model.message(0));
+}
+
 test "client model backspace edits filter text" {
     var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
     defer cfg.deinit();
@@ -514,6 +1023,70 @@ test "client model control-modified process list keys work while typing filter"
     try std.testing.expectEqualStrings(model.activeProcessLabel(), stop.?.label);
 }
 
+test "client model pages and jumps through the list without overshooting" {
+    var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    var app_state = try domain.state.AppState.init(std.testing.allocator, &cfg);
+    defer app_state.deinit();
+
+    var views = test_config.standardClientModelViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, app_state.current_proc_id, views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+    model.term_height = 2;
+    model.active_proc_id = domain.process.ProcessId.fromInt(1);
+
+    const down = try model.handleKey("pagedown");
+    try std.testing.expectEqual(ipc.protocol.Command.switch_process, down.?.action);
+    try std.testing.expectEqualStrings("gamma-db", down.?.label);
+
+    _ = try model.handleKey("pagedown");
+    try std.testing.expectEqualStrings("gamma-db", model.activeProcessLabel());
+
+    _ = try model.handleKey("pageup");
+    try std.testing.expectEqualStrings("alpha-api", model.activeProcessLabel());
+    _ = try model.handleKey("pageup");
+    try std.testing.expectEqualStrings("alpha-api", model.activeProcessLabel());
+
+    _ = try model.handleKey("end");
+    try std.testing.expectEqualStrings("gamma-db", model.activeProcessLabel());
+    _ = try model.handleKey("home");
+    try std.testing.expectEqualStrings("alpha-api", model.activeProcessLabel());
+
+    model.term_height = 0;
+    _ = try model.handleKey("pagedown");
+    try std.testing.expectEqualStrings("gamma-db", model.activeProcessLabel());
+}
+
+test "client model selects the process under a clicked list row" {
+    var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    var app_state = try domain.state.AppState.init(std.testing.allocator, &cfg);
+    defer app_state.deinit();
+
+    var views = test_config.standardClientModelViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, app_state.current_proc_id, views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    const clicked = model.selectListRow(2, 1, 1, 3);
+    try std.testing.expectEqual(ipc.protocol.Command.switch_process, clicked.?.action);
+    try std.testing.expectEqualStrings("gamma-db", clicked.?.label);
+
+    try std.testing.expect(model.selectListRow(0, 1, 0, 3) == null);
+    try std.testing.expect(model.selectListRow(4, 1, 0, 3) == null);
+    try std.testing.expectEqualStrings("gamma-db", model.activeProcessLabel());
+
+    model.show_help = true;
+    try std.testing.expect(model.selectListRow(1, 1, 0, 3) == null);
+}
+
 test "client model navigates on special up and down keys while typing filter" {
     var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
     defer cfg.deinit();
@@ -642,6 +1215,58 @@ test "client model down key moves selection and wraps within visible list" {
     try std.testing.expectEqualStrings("alpha-api", wrapped.?.label);
 }
 
+test "client model jumps to the next running or stopped process and wraps" {
+    var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    var views = test_config.standardClientModelViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, domain.process.ProcessId.fromInt(1), views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    const gamma = (try model.handleKey("n")).?;
+    try std.testing.expectEqual(ipc.protocol.Command.switch_process, gamma.action);
+    try std.testing.expectEqualStrings("gamma-db", gamma.label);
+    const wrapped = (try model.handleKey("n")).?;
+    try std.testing.expectEqualStrings("alpha-api", wrapped.label);
+
+    const beta = (try model.handleKey("N")).?;
+    try std.testing.expectEqualStrings("beta-worker", beta.label);
+    const only_stopped = (try model.handleKey("N")).?;
+    try std.testing.expectEqualStrings("beta-worker", only_stopped.label);
+}
+
+test "client model next running and next stopped keep the selection when nothing matches" {
+    var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    var views = test_config.standardClientModelViews(&cfg);
+    for (&views) |*view| view.status = .halted;
+    views[2].status = .exited;
+    var stopped_snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, domain.process.ProcessId.fromInt(2), views[0..]);
+    defer stopped_snapshot.deinit(std.testing.allocator);
+    for (&views) |*view| view.status = .running;
+    var running_snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, domain.process.ProcessId.fromInt(2), views[0..]);
+    defer running_snapshot.deinit(std.testing.allocator);
+
+    var model = try ClientModel.init(std.testing.allocator, stopped_snapshot.view());
+    defer model.deinit();
+
+    try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey("n"));
+    try std.testing.expectEqualStrings("beta-worker", model.activeProcessLabel());
+    try std.testing.expectEqualStrings("no running process to jump to", model.message(0));
+    try std.testing.expectEqualStrings("gamma-db", (try model.handleKey("N")).?.label);
+
+    try model.replaceSnapshotPreservingUI(running_snapshot.view());
+
+    try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey("N"));
+    try std.testing.expectEqualStrings("gamma-db", model.activeProcessLabel());
+    try std.testing.expectEqualStrings("no stopped process to jump to", model.message(1));
+    try std.testing.expectEqualStrings("alpha-api", (try model.handleKey("n")).?.label);
+}
+
 test "client model running-only toggle filters visible list and selects first running process" {
     var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
     defer cfg.deinit();
@@ -668,6 +1293,42 @@ test "client model running-only toggle filters visible list and selects first ru
     try std.testing.expectEqualStrings("alpha-api", intent.?.label);
 }
 
+test "client model summarizes process counts filter and selection" {
+    var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    var app_state = try domain.state.AppState.init(std.testing.allocator, &cfg);
+    defer app_state.deinit();
+    app_state.current_proc_id = domain.process.ProcessId.fromInt(2);
+
+    var views = test_config.standardClientModelViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, app_state.current_proc_id, views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    var current = model.summary();
+    try std.testing.expectEqual(@as(usize, 3), current.total);
+    try std.testing.expectEqual(@as(usize, 2), current.running);
+    try std.testing.expectEqual(@as(usize, 1), current.stopped);
+    try std.testing.expectEqualStrings("", current.filter);
+    try std.testing.expectEqualStrings("beta-worker", current.selected);
+
+    _ = try model.handleKey("/");
+    for ("gamma") |ch| {
+        const key = [_]u8{ch};
+        _ = try model.handleKey(key[0..]);
+    }
+    _ = try model.handleKey("enter");
+
+    current = model.summary();
+    try std.testing.expectEqual(@as(usize, 3), current.total);
+    try std.testing.expectEqual(@as(usize, 2), current.running);
+    try std.testing.expectEqualStrings("gamma", current.filter);
+    try std.testing.expectEqualStrings("gamma-db", current.selected);
+}
+
 test "client model process control keys target active process" {
     var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
     defer cfg.deinit();
@@ -699,6 +1360,52 @@ test "client model process control keys target active process" {
     try std.testing.expectEqualStrings("beta-worker", restart.?.label);
 }
 
+test "client model palette narrows actions and runs the highlighted one" {
+    var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    var app_state = try domain.state.AppState.init(std.testing.allocator, &cfg);
+    defer app_state.deinit();
+    app_state.current_proc_id = domain.process.ProcessId.fromInt(2);
+
+    var views = test_config.standardClientModelViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, app_state.current_proc_id, views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey(":"));
+    try std.testing.expect(model.entering_palette);
+    try std.testing.expectEqual(palette.Action.start, model.paletteActions()[0]);
+    for ([_][]const u8{ "s", "t", "o", "p" }) |key| _ = try model.handleKey(key);
+    try std.testing.expectEqualSlices(palette.Action, &.{ .stop, .stop_all }, model.paletteActions());
+    try std.testing.expectEqualStrings("stop", model.paletteText());
+
+    _ = try model.handleKey("down");
+    const stop_all = (try model.handleKey("enter")).?;
+    try std.testing.expectEqual(ipc.protocol.Command.stop_all, stop_all.action);
+    try std.testing.expect(!model.entering_palette);
+
+    _ = try model.handleKey(":");
+    for ([_][]const u8{ "z", "o", "o", "m" }) |key| _ = try model.handleKey(key);
+    try std.testing.expectEqual(@as(usize, 0), model.paletteActions().len);
+    _ = try model.handleKey("esc");
+    try std.testing.expect(!model.entering_palette);
+
+    model.zoom_available = true;
+    _ = try model.handleKey(":");
+    _ = try model.handleKey("z");
+    try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey("enter"));
+    try std.testing.expect(model.zoom_requested);
+
+    _ = try model.handleKey(":");
+    for ([_][]const u8{ "q", "u", "i", "t" }) |key| _ = try model.handleKey(key);
+    try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey("enter"));
+    try std.testing.expectEqualStrings("2 running; press q again to quit and stop them", model.message(0));
+    try std.testing.expectEqual(ipc.protocol.Command.stop_running, (try model.handleKey("q")).?.action);
+}
+
 test "client model help key toggles help visibility" {
     var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
     defer cfg.deinit();
@@ -723,6 +1430,7 @@ test "client model help key toggles help visibility" {
 test "client model quit key emits stop-running intent" {
     var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
     defer cfg.deinit();
+    cfg.confirm_quit_when_running = false;
 
     var app_state = try domain.state.AppState.init(std.testing.allocator, &cfg);
     defer app_state.deinit();
@@ -740,6 +1448,30 @@ test "client model quit key emits stop-running intent" {
     try std.testing.expectEqualStrings("", intent.?.label);
 }
 
+test "client model asks for a second quit key while processes run" {
+    var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    var app_state = try domain.state.AppState.init(std.testing.allocator, &cfg);
+    defer app_state.deinit();
+
+    var views = test_config.standardClientModelViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, app_state.current_proc_id, views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey("q"));
+    try std.testing.expectEqualStrings("2 running; press q again to quit and stop them", model.message(0));
+
+    _ = try model.handleKey("?");
+    try std.testing.expectEqual(@as(?CommandIntent, null), try model.handleKey("q"));
+
+    const intent = try model.handleKey("q");
+    try std.testing.expectEqual(ipc.protocol.Command.stop_running, intent.?.action);
+}
+
 test "client model prunes messages after five second timeout" {
     var cfg = try test_config.standardClientModelConfig(std.testing.allocator);
     defer cfg.deinit();
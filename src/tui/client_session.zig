@@ -7,6 +7,7 @@ const ipc = @import("../ipc/root.zig");
 const test_config = @import("../test_support/config.zig");
 const test_ipc = @import("../test_support/ipc.zig");
 const client_model = @import("client_model.zig");
+const render = @import("render.zig");
 
 /// Transport seam used by Client Session. Production uses `ipc.client.Client`;
 /// tests provide fake snapshots and command results without a socket.
@@ -20,6 +21,12 @@ pub const Transport = struct {
         action: ipc.protocol.Command,
         label: []const u8,
     ) anyerror!CommandResult,
+    send_input: *const fn (
+        context: *anyopaque,
+        allocator: std.mem.Allocator,
+        label: []const u8,
+        input: []const u8,
+    ) anyerror!CommandResult,
 
     fn readSnapshot(self: Transport, allocator: std.mem.Allocator) !ipc.protocol.SnapshotUpdate {
         return self.read_snapshot(self.context, allocator);
@@ -37,14 +44,26 @@ pub const Transport = struct {
     ) !CommandResult {
         return self.send_command(self.context, allocator, action, label);
     }
+
+    fn sendInput(
+        self: Transport,
+        allocator: std.mem.Allocator,
+        label: []const u8,
+        input: []const u8,
+    ) !CommandResult {
+        return self.send_input(self.context, allocator, label, input);
+    }
 };
 
 pub const CommandResult = struct {
     success: bool,
     error_message: []const u8,
+    /// Note from a successful command, such as where copied output went.
+    message: []const u8 = "",
 
     pub fn deinit(self: *const CommandResult, allocator: std.mem.Allocator) void {
         allocator.free(self.error_message);
+        allocator.free(self.message);
     }
 };
 
@@ -58,6 +77,13 @@ pub const KeyInteraction = struct {
     handled_command: bool = false,
     stop: bool = false,
     render_now: bool = false,
+    /// The selected process's output was cleared; cached output views are stale.
+    cleared_output: bool = false,
+    /// The edit-config key was pressed; the runtime suspends the TUI for the
+    /// editor and then calls `reloadAfterEdit`.
+    edit_config: bool = false,
+    /// The palette's zoom action ran; the unified runtime toggles output zoom.
+    toggle_zoom: bool = false,
 };
 
 /// TUI-facing session that combines local ClientModel state with IPC Snapshot
@@ -107,7 +133,15 @@ pub const ClientSession = struct {
         key: []const u8,
         options: KeyInteractionOptions,
     ) !KeyInteraction {
-        const action = (try self.handleKeyAction(key)) orelse return .{};
+        const action = (try self.handleKeyAction(key)) orelse {
+            if (self.model.zoom_requested) {
+                self.model.zoom_requested = false;
+                return .{ .toggle_zoom = true };
+            }
+            if (!self.model.edit_config_requested) return .{};
+            self.model.edit_config_requested = false;
+            return .{ .edit_config = true };
+        };
         if (ipc.protocol.commandNeedsImmediateSnapshotSync(action)) {
             try self.readSnapshotUpdate();
             if (options.sync_selection_after_command) try self.syncSelectionAfterAction(action);
@@ -116,37 +150,59 @@ pub const ClientSession = struct {
             .handled_command = true,
             .stop = action == .stop_running,
             .render_now = ipc.protocol.commandShouldRenderImmediately(action),
+            .cleared_output = action == .clear_output,
         };
     }
 
     pub fn handleKeyAction(self: *ClientSession, key: []const u8) !?ipc.protocol.Command {
-        if (try self.model.handleKey(key)) |intent| {
-            if (ipc.protocol.commandRequiresSelectedProcess(intent.action) and intent.label.len == 0) {
-                try self.model.addMessage("no process selected");
-                return null;
-            }
+        const intent = (try self.model.handleKey(key)) orelse return null;
+        if (ipc.protocol.commandRequiresSelectedProcess(intent.action) and intent.label.len == 0) {
+            try self.model.addMessage("no process selected");
+            return null;
+        }
+        if (!try self.sendIntent(intent)) return null;
+        return intent.action;
+    }
 
-            const result = self.transport.sendCommand(
-                self.allocator,
-                intent.action,
-                intent.label,
-            ) catch |err| {
-                try self.model.addMessage(@errorName(err));
-                return null;
-            };
-            defer result.deinit(self.allocator);
-
-            if (!result.success) {
-                const message = if (result.error_message.len == 0)
-                    "command failed"
-                else
-                    result.error_message;
-                try self.model.addMessage(message);
-                return null;
-            }
-            return intent.action;
+    /// Selects the process under a click on row `row` of the process list and
+    /// tells the server; false when the row holds no process.
+    pub fn clickListRow(self: *ClientSession, row: usize) !bool {
+        const window = try render.processListWindow(self.allocator, &self.model);
+        const intent = self.model.selectListRow(row, window.first_row, window.start, window.end) orelse return false;
+        _ = try self.sendIntent(intent);
+        return true;
+    }
+
+    /// Reloads the config after the editor exits. A config that fails to load
+    /// leaves the running one in place and the error in the message panel.
+    pub fn reloadAfterEdit(self: *ClientSession) !void {
+        if (!try self.sendIntent(.{ .action = .reload, .label = "" })) return;
+        try self.readSnapshotUpdate();
+    }
+
+    /// Sends one intent and reports failures as messages; true on success.
+    fn sendIntent(self: *ClientSession, intent: client_model.CommandIntent) !bool {
+        const sent = if (intent.action == .send_input)
+            self.transport.sendInput(self.allocator, intent.label, intent.input)
+        else
+            self.transport.sendCommand(self.allocator, intent.action, intent.label);
+        const result = sent catch |err| {
+            try self.model.addMessage(@errorName(err));
+            return false;
+        };
+        defer result.deinit(self.allocator);
+
+        if (!result.success) {
+            const message = if (result.error_message.len == 0)
+                "command failed"
+            else
+                result.error_message;
+            try self.model.addMessage(message);
+            return false;
         }
-        return null;
+        if (result.message.len > 0) try self.model.addMessage(result.message);
+        if (intent.action == .reload) try self.model.addMessage("config reloaded");
+        return true;
     }
 
     fn syncSelectionAfterAction(self: *ClientSession, action: ipc.protocol.Command) !void {
@@ -156,6 +212,13 @@ pub const ClientSession = struct {
         }
     }
 
+    /// Applies a filter and selection remembered from an earlier launch and
+    /// tells the server which process is now selected.
+    pub fn restoreUiState(self: *ClientSession, label: []const u8, filter: []const u8) !void {
+        try self.model.restoreSelection(label, filter);
+        try self.switchToActiveProcess();
+    }
+
     pub fn switchToActiveProcess(self: *ClientSession) !void {
         const label = self.model.activeProcessLabel();
         if (label.len == 0) return;
@@ -213,6 +276,7 @@ pub const IpcTransport = struct {
             .read_snapshot = readSnapshot,
             .read_latest_snapshot = readLatestSnapshot,
             .send_command = sendCommand,
+            .send_input = sendInput,
         };
     }
 
@@ -245,11 +309,28 @@ pub const IpcTransport = struct {
             };
         }
 
+        return readCommandResult(client, allocator, request_id);
+    }
+
+    fn sendInput(
+        context: *anyopaque,
+        allocator: std.mem.Allocator,
+        label: []const u8,
+        input: []const u8,
+    ) anyerror!CommandResult {
+        const client: *ipc.client.Client = @ptrCast(@alignCast(context));
+        return readCommandResult(client, allocator, try client.sendInput(label, input));
+    }
+
+    fn readCommandResult(client: *ipc.client.Client, allocator: std.mem.Allocator, request_id: u64) !CommandResult {
         var response = try client.readResponseFor(request_id);
         defer response.deinit(client.allocator);
+        const error_message = try allocator.dupe(u8, response.error_message);
+        errdefer allocator.free(error_message);
         return .{
             .success = response.success,
-            .error_message = try allocator.dupe(u8, response.error_message),
+            .error_message = error_message,
+            .message = try allocator.dupe(u8, response.message),
         };
     }
 };
@@ -364,6 +445,73 @@ test "client session records command failures as messages without exiting" {
     try std.testing.expectEqualStrings("already running", session.model.message(0));
 }
 
+test "client session shows the note from a successful copy" {
+    var cfg = try test_config.standardSessionConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    var app_state = try domain.state.AppState.init(std.testing.allocator, &cfg);
+    defer app_state.deinit();
+    app_state.current_proc_id = domain.process.ProcessId.fromInt(2);
+
+    var fake_controller = test_ipc.FakeProcessController{ .running_id = domain.process.ProcessId.fromInt(2) };
+    const line = try test_ipc.snapshotLineFromAppState(
+        std.testing.allocator,
+        &app_state,
+        fake_controller.controller(),
+    );
+    defer std.testing.allocator.free(line);
+
+    var fake = FakeTransport{
+        .snapshot_line = line,
+        .command_message = "copied beta-worker output to the clipboard",
+    };
+    var session = try ClientSession.init(std.testing.allocator, FakeTransport.transport(&fake));
+    defer session.deinit();
+
+    const interaction = try session.handleKeyInteraction("y", .{});
+
+    try std.testing.expect(interaction.render_now);
+    try std.testing.expectEqual(ipc.protocol.Command.copy_output, fake.last_action.?);
+    try std.testing.expectEqualStrings("beta-worker", fake.last_label_buf[0..fake.last_label_len]);
+    try std.testing.expectEqualStrings("copied beta-worker output to the clipboard", session.model.message(0));
+}
+
+test "client session hands the edit-config key to the runtime and reloads after" {
+    var cfg = try test_config.standardSessionConfig(std.testing.allocator);
+    defer cfg.deinit();
+
+    var app_state = try domain.state.AppState.init(std.testing.allocator, &cfg);
+    defer app_state.deinit();
+
+    var fake_controller = test_ipc.FakeProcessController{ .running_id = domain.process.ProcessId.fromInt(2) };
+    const line = try test_ipc.snapshotLineFromAppState(
+        std.testing.allocator,
+        &app_state,
+        fake_controller.controller(),
+    );
+    defer std.testing.allocator.free(line);
+
+    var fake = FakeTransport{ .snapshot_line = line };
+    var session = try ClientSession.init(std.testing.allocator, FakeTransport.transport(&fake));
+    defer session.deinit();
+
+    const interaction = try session.handleKeyInteraction("e", .{});
+    try std.testing.expect(interaction.edit_config);
+    try std.testing.expect(!interaction.handled_command);
+    try std.testing.expect(!session.model.edit_config_requested);
+    try std.testing.expectEqual(@as(?ipc.protocol.Command, null), fake.last_action);
+
+    try session.reloadAfterEdit();
+    try std.testing.expectEqual(ipc.protocol.Command.reload, fake.last_action.?);
+    try std.testing.expectEqualStrings("config reloaded", session.model.message(0));
+
+    fake.command_success = false;
+    fake.command_error_message = "ParseFailure";
+    try session.reloadAfterEdit();
+    try std.testing.expectEqual(@as(usize, 2), session.model.messageCount());
+    try std.testing.expectEqualStrings("ParseFailure", session.model.message(1));
+}
+
 test "client session records no process selected locally without IPC command" {
     var cfg = try test_config.standardSessionConfig(std.testing.allocator);
     defer cfg.deinit();
@@ -486,9 +634,12 @@ const FakeTransport = struct {
     snapshot_read_count: usize = 0,
     command_success: bool = true,
     command_error_message: []const u8 = "",
+    command_message: []const u8 = "",
     last_action: ?ipc.protocol.Command = null,
     last_label_buf: [64]u8 = undefined,
     last_label_len: usize = 0,
+    last_input_buf: [64]u8 = undefined,
+    last_input_len: usize = 0,
 
     fn transport(self: *FakeTransport) Transport {
         return .{
@@ -496,9 +647,14 @@ const FakeTransport = struct {
             .read_snapshot = readSnapshot,
             .read_latest_snapshot = readSnapshot,
             .send_command = sendCommand,
+            .send_input = sendInput,
         };
     }
 
+    fn lastInput(self: *const FakeTransport) []const u8 {
+        return self.last_input_buf[0..self.last_input_len];
+    }
+
     fn lastLabel(self: *const FakeTransport) []const u8 {
         return self.last_label_buf[0..self.last_label_len];
     }
@@ -526,6 +682,19 @@ const FakeTransport = struct {
         return .{
             .success = self.command_success,
             .error_message = try allocator.dupe(u8, self.command_error_message),
+            .message = try allocator.dupe(u8, self.command_message),
         };
     }
+
+    fn sendInput(
+        context: *anyopaque,
+        allocator: std.mem.Allocator,
+        label: []const u8,
+        input: []const u8,
+    ) anyerror!CommandResult {
+        const self: *FakeTransport = @ptrCast(@alignCast(context));
+        @memcpy(self.last_input_buf[0..input.len], input);
+        self.last_input_len = input.len;
+        return sendCommand(context, allocator, .send_input, label);
+    }
 };
@@ -0,0 +1,90 @@
+//! External editor launch for the edit-config key.
+//! The terminal is handed to `$EDITOR` in cooked mode while it runs; callers repaint and reload once it exits.
+
+const std = @import("std");
+const terminal = @import("../terminal/root.zig");
+const client_session = @import("client_session.zig");
+
+const log = std.log.scoped(.tui_editor);
+
+pub const fallback_editor = "vi";
+
+/// Command line for editing `path`: `editor` split on whitespace, so values
+/// like `code -w` work, or `vi` when it is unset or blank.
+pub fn argv(allocator: std.mem.Allocator, editor: ?[]const u8, path: []const u8) ![]const []const u8 {
+    var parts = std.array_list.Managed([]const u8).init(allocator);
+    errdefer parts.deinit();
+    if (editor) |value| {
+        var words = std.mem.tokenizeAny(u8, value, " \t");
+        while (words.next()) |word| try parts.append(word);
+    }
+    if (parts.items.len == 0) try parts.append(fallback_editor);
+    try parts.append(path);
+    return parts.toOwnedSlice();
+}
+
+/// Runs the editor on `path` in the foreground and waits for it. `tty_fd` is
+/// switched out of raw mode for the duration. A non-zero exit is only logged;
+/// the caller still reloads so a saved file is picked up.
+pub fn edit(allocator: std.mem.Allocator, path: []const u8, tty_fd: ?std.posix.fd_t) !void {
+    const editor = std.process.getEnvVarOwned(allocator, "EDITOR") catch null;
+    defer if (editor) |value| allocator.free(value);
+    const command = try argv(allocator, editor, path);
+    defer allocator.free(command);
+
+    var suspended: ?terminal.mode.Suspended = if (tty_fd) |fd| terminal.mode.suspendRaw(fd) else null;
+    defer if (suspended) |*mode| mode.resumeRaw();
+
+    var child = std.process.Child.init(command, allocator);
+    child.stdin_behavior = .Inherit;
+    child.stdout_behavior = .Inherit;
+    child.stderr_behavior = .Inherit;
+    const term = try child.spawnAndWait();
+    switch (term) {
+        .Exited => |code| if (code != 0) log.warn("editor {s} exited with status {d}", .{ command[0], code }),
+        else => log.warn("editor {s} ended abnormally: {any}", .{ command[0], term }),
+    }
+}
+
+/// Handles the edit-config key for a runtime loop: hands the screen to the
+/// editor, then reloads. `output` needs `writeAll`; the caller repaints after.
+/// With `mouse`, reporting is switched off while the editor runs and back on
+/// after, so the editor does not receive proctmux's click sequences.
+pub fn editAndReload(
+    session: *client_session.ClientSession,
+    path: []const u8,
+    tty_fd: ?std.posix.fd_t,
+    output: anytype,
+    mouse: bool,
+) !void {
+    if (mouse) try output.writeAll(terminal.repaint.disable_mouse);
+    try output.writeAll(terminal.repaint.clear_screen ++ terminal.repaint.show_cursor);
+    const edited = edit(session.allocator, path, tty_fd);
+    try output.writeAll(terminal.repaint.clear_screen ++ terminal.repaint.hide_cursor);
+    if (mouse) try output.writeAll(terminal.repaint.enable_mouse);
+    edited catch |err| {
+        const text = try std.fmt.allocPrint(session.allocator, "cannot open editor: {s}", .{@errorName(err)});
+        defer session.allocator.free(text);
+        try session.model.addMessage(text);
+        return;
+    };
+    try session.reloadAfterEdit();
+}
+
+test "editor command splits EDITOR and falls back to vi" {
+    const with_args = try argv(std.testing.allocator, "code  -w", "/tmp/proctmux.yaml");
+    defer std.testing.allocator.free(with_args);
+    try std.testing.expectEqual(@as(usize, 3), with_args.len);
+    try std.testing.expectEqualStrings("code", with_args[0]);
+    try std.testing.expectEqualStrings("-w", with_args[1]);
+    try std.testing.expectEqualStrings("/tmp/proctmux.yaml", with_args[2]);
+
+    const unset = try argv(std.testing.allocator, null, "proctmux.yaml");
+    defer std.testing.allocator.free(unset);
+    try std.testing.expectEqualStrings("vi", unset[0]);
+
+    const blank = try argv(std.testing.allocator, " ", "proctmux.yaml");
+    defer std.testing.allocator.free(blank);
+    try std.testing.expectEqual(@as(usize, 2), blank.len);
+    try std.testing.expectEqualStrings("vi", blank[0]);
+}
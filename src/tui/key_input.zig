@@ -77,6 +77,36 @@ pub fn keyForInput(bytes: []const u8, index: *usize, scratch: *[1]u8) ?[]const u
     return keyForByte(bytes[current], scratch);
 }
 
+/// One SGR mouse report. Coordinates are 0-based cells; `button` is the raw
+/// report code, so 0 is a left press and 64/65 are wheel steps.
+pub const Mouse = struct {
+    button: u16,
+    col: usize,
+    row: usize,
+    pressed: bool,
+
+    pub fn isLeftPress(self: Mouse) bool {
+        return self.pressed and self.button == 0;
+    }
+};
+
+/// Decodes an SGR (`CSI < b ; x ; y M`) mouse report at `index` and advances
+/// past it. Reports must be consumed before keys so they never leak as text.
+pub fn mouseForInput(bytes: []const u8, index: *usize) ?Mouse {
+    const remaining = bytes[index.*..];
+    if (!std.mem.startsWith(u8, remaining, "\x1b[<")) return null;
+    const end = std.mem.indexOfAny(u8, remaining, "Mm") orelse return null;
+
+    var parts = std.mem.splitScalar(u8, remaining[3..end], ';');
+    const button = std.fmt.parseInt(u16, parts.next() orelse return null, 10) catch return null;
+    const col = std.fmt.parseInt(usize, parts.next() orelse return null, 10) catch return null;
+    const row = std.fmt.parseInt(usize, parts.next() orelse return null, 10) catch return null;
+    if (parts.next() != null or col == 0 or row == 0) return null;
+
+    index.* += end + 1;
+    return .{ .button = button, .col = col - 1, .row = row - 1, .pressed = remaining[end] == 'M' };
+}
+
 const ParsedKey = struct {
     key: []const u8,
     len: usize,
@@ -302,3 +332,21 @@ test "key input maps terminal navigation and function sequences" {
     try std.testing.expectEqualStrings("f12", keyForInput("\x1b[24~", &index, &scratch).?);
     try std.testing.expectEqual(@as(usize, 5), index);
 }
+
+test "key input decodes sgr mouse reports before keys" {
+    var index: usize = 0;
+    const press = mouseForInput("\x1b[<0;12;3Mq", &index).?;
+    try std.testing.expect(press.isLeftPress());
+    try std.testing.expectEqual(@as(usize, 11), press.col);
+    try std.testing.expectEqual(@as(usize, 2), press.row);
+    try std.testing.expectEqual(@as(usize, 10), index);
+
+    index = 0;
+    const release = mouseForInput("\x1b[<0;12;3m", &index).?;
+    try std.testing.expect(!release.pressed);
+    try std.testing.expect(!release.isLeftPress());
+
+    index = 0;
+    try std.testing.expect(mouseForInput("\x1b[A", &index) == null);
+    try std.testing.expectEqual(@as(usize, 0), index);
+}
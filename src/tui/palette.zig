@@ -0,0 +1,105 @@
+//! Command palette actions for the TUI.
+//! The palette names the actions behind the keybindings and narrows them with the process-list fuzzy matcher; the Client Model dispatches the chosen one.
+
+const std = @import("std");
+const domain = @import("../domain/root.zig");
+
+pub const Action = enum {
+    start,
+    stop,
+    restart,
+    start_all,
+    stop_all,
+    send_input,
+    clear_output,
+    copy_output,
+    filter,
+    toggle_running,
+    next_running,
+    next_stopped,
+    toggle_zoom,
+    reload_config,
+    edit_config,
+    toggle_help,
+    quit,
+};
+
+pub const Entry = struct {
+    action: Action,
+    name: []const u8,
+};
+
+/// Palette entries in display order when nothing has been typed.
+pub const entries = [_]Entry{
+    .{ .action = .start, .name = "start" },
+    .{ .action = .stop, .name = "stop" },
+    .{ .action = .restart, .name = "restart" },
+    .{ .action = .start_all, .name = "start all" },
+    .{ .action = .stop_all, .name = "stop all" },
+    .{ .action = .send_input, .name = "send input" },
+    .{ .action = .clear_output, .name = "clear output" },
+    .{ .action = .copy_output, .name = "copy output" },
+    .{ .action = .filter, .name = "filter" },
+    .{ .action = .toggle_running, .name = "toggle running only" },
+    .{ .action = .next_running, .name = "next running" },
+    .{ .action = .next_stopped, .name = "next stopped" },
+    .{ .action = .toggle_zoom, .name = "zoom output" },
+    .{ .action = .reload_config, .name = "reload config" },
+    .{ .action = .edit_config, .name = "edit config" },
+    .{ .action = .toggle_help, .name = "toggle help" },
+    .{ .action = .quit, .name = "quit" },
+};
+
+pub fn name(action: Action) []const u8 {
+    for (entries) |entry| {
+        if (entry.action == action) return entry.name;
+    }
+    unreachable;
+}
+
+/// Actions whose name fuzzy-matches `query`, best match first; every action in
+/// list order for an empty query. Zoom only exists in unified mode, so it is
+/// left out unless `zoom_available`. Caller owns the result.
+pub fn filter(allocator: std.mem.Allocator, query: []const u8, zoom_available: bool) ![]Action {
+    var matches = std.array_list.Managed(domain.fuzzy.Match).init(allocator);
+    defer matches.deinit();
+
+    const trimmed = std.mem.trim(u8, query, " ");
+    for (entries, 0..) |entry, index| {
+        if (entry.action == .toggle_zoom and !zoom_available) continue;
+        if (trimmed.len == 0) {
+            try matches.append(.{ .index = index, .score = 0 });
+        } else if (domain.fuzzy.score(trimmed, entry.name)) |score| {
+            try matches.append(.{ .index = index, .score = score });
+        }
+    }
+    domain.fuzzy.sortMatches(matches.items);
+
+    const actions = try allocator.alloc(Action, matches.items.len);
+    for (matches.items, actions) |match, *action| action.* = entries[match.index].action;
+    return actions;
+}
+
+test "palette filter fuzzy-matches action names and keeps list order" {
+    const all = try filter(std.testing.allocator, "", false);
+    defer std.testing.allocator.free(all);
+    try std.testing.expectEqual(entries.len - 1, all.len);
+    try std.testing.expectEqual(Action.start, all[0]);
+    try std.testing.expect(std.mem.indexOfScalar(Action, all, .toggle_zoom) == null);
+
+    const restart = try filter(std.testing.allocator, "rst", false);
+    defer std.testing.allocator.free(restart);
+    try std.testing.expectEqual(Action.restart, restart[0]);
+
+    const reload = try filter(std.testing.allocator, "RELOAD", false);
+    defer std.testing.allocator.free(reload);
+    try std.testing.expectEqualSlices(Action, &.{.reload_config}, reload);
+
+    const zoom = try filter(std.testing.allocator, "zoom", true);
+    defer std.testing.allocator.free(zoom);
+    try std.testing.expectEqualSlices(Action, &.{.toggle_zoom}, zoom);
+
+    const none = try filter(std.testing.allocator, "xyzzy", true);
+    defer std.testing.allocator.free(none);
+    try std.testing.expectEqual(@as(usize, 0), none.len);
+}
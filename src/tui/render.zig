@@ -7,6 +7,10 @@ const domain = @import("../domain/root.zig");
 const test_ansi = @import("../test_support/ansi.zig");
 const test_config = @import("../test_support/config.zig");
 const client_model = @import("client_model.zig");
+const palette = @import("palette.zig");
+
+/// Palette rows shown below its prompt; more matches need more typing.
+const palette_rows = 8;
 
 /// Renders the process-list pane from local UI state and the current Client
 /// Snapshot. The renderer does not mutate model or perform IPC.
@@ -14,26 +18,17 @@ pub fn renderProcessList(allocator: std.mem.Allocator, model: *const client_mode
     var out = std.array_list.Managed(u8).init(allocator);
     errdefer out.deinit();
 
-    try appendProcessHeader(&out, model);
-    try appendHelpPanel(&out, model);
-    try appendSelectedDescription(&out, model);
-    try appendMessagesPanel(&out, model);
-    try appendFilterPanel(&out, model);
+    try appendListPanels(&out, model);
 
     const processes = model.visibleProcesses();
     if (processes.len == 0) {
         try out.appendSlice("No matching processes\n");
+        try appendStatusBar(&out, model);
         return out.toOwnedSlice();
     }
 
-    const process_start = selectedProcessWindowStart(
-        model,
-        renderedLineCount(out.items),
-        processes.len,
-    );
-    const process_end = selectedProcessWindowEnd(model, renderedLineCount(out.items), process_start);
-
-    for (processes[process_start..process_end], process_start..) |summary, index| {
+    const window = windowAfterPanels(model, renderedLineCount(out.items));
+    for (processes[window.start..window.end], window.start..) |summary, index| {
         const selected = if (model.active_proc_id.isNone())
             index == 0
         else
@@ -47,8 +42,9 @@ pub fn renderProcessList(allocator: std.mem.Allocator, model: *const client_mode
 
         try appendStatusMarker(&out, &model.snapshot.ui.style, summary.status, !model.no_color);
         try out.append(' ');
+        if (summary.hotkey.len > 0) try out.writer().print("[{s}] ", .{summary.hotkey});
         if (model.snapshot.ui.layout.enable_debug_process_info) {
-            try out.appendSlice(summary.label);
+            try appendColored(&out, summary.color, summary.label, !model.no_color);
             try out.appendSlice(" [");
             try out.appendSlice(domain.process.statusName(summary.status));
             try out.writer().print("] PID:{}", .{summary.pid});
@@ -61,14 +57,88 @@ pub fn renderProcessList(allocator: std.mem.Allocator, model: *const client_mode
                 try out.append(']');
             }
         } else {
-            try out.appendSlice(summary.label);
+            try appendColored(&out, summary.color, summary.label, !model.no_color);
+        }
+        if (summary.status != .running) {
+            if (summary.timed_out) {
+                try out.appendSlice(" (timed out)");
+            } else if (summary.exit_status) |code| {
+                try out.writer().print(" ({s}exited {d})", .{ if (summary.task) "task " else "", code });
+            }
+        } else if (summary.config_changed) {
+            try out.appendSlice(" (changed; restart to apply)");
+        } else if (summary.task) {
+            try out.appendSlice(" (task)");
+        }
+        if (summary.status == .running and summary.last_line.len > 0) {
+            try out.writer().print("  {s}", .{summary.last_line});
         }
         try out.append('\n');
     }
 
+    try appendStatusBar(&out, model);
+    return out.toOwnedSlice();
+}
+
+/// Rows of the process list as `renderProcessList` draws them: the row of the
+/// first process and the slice of visible processes on screen.
+pub const ListWindow = struct {
+    first_row: usize,
+    start: usize,
+    end: usize,
+};
+
+pub fn processListWindow(allocator: std.mem.Allocator, model: *const client_model.ClientModel) !ListWindow {
+    var panels = std.array_list.Managed(u8).init(allocator);
+    defer panels.deinit();
+    try appendListPanels(&panels, model);
+    return windowAfterPanels(model, renderedLineCount(panels.items));
+}
+
+fn appendListPanels(out: *std.array_list.Managed(u8), model: *const client_model.ClientModel) !void {
+    try appendProcessHeader(out, model);
+    try appendHelpPanel(out, model);
+    try appendSelectedDescription(out, model);
+    try appendMessagesPanel(out, model);
+    try appendFilterPanel(out, model);
+    try appendPalettePanel(out, model);
+}
+
+fn windowAfterPanels(model: *const client_model.ClientModel, reserved_lines: usize) ListWindow {
+    const status_rows: usize = if (model.snapshot.ui.layout.show_status_bar) 1 else 0;
+    const start = selectedProcessWindowStart(model, reserved_lines + status_rows, model.visibleCount());
+    return .{
+        .first_row = reserved_lines,
+        .start = start,
+        .end = selectedProcessWindowEnd(model, reserved_lines + status_rows, start),
+    };
+}
+
+/// Formats the model summary as one status-bar line, without a newline.
+pub fn formatStatusBar(allocator: std.mem.Allocator, summary: client_model.Summary) ![]u8 {
+    var out = std.array_list.Managed(u8).init(allocator);
+    errdefer out.deinit();
+    try out.writer().print("{d} procs  {d} running  {d} stopped", .{ summary.total, summary.running, summary.stopped });
+    if (summary.filter.len > 0) try out.writer().print("  filter: {s}", .{summary.filter});
+    if (summary.selected.len > 0) try out.writer().print("  selected: {s}", .{summary.selected});
     return out.toOwnedSlice();
 }
 
+/// Pads to the last terminal row when the height is known so the status bar
+/// stays at the bottom of the pane.
+fn appendStatusBar(out: *std.array_list.Managed(u8), model: *const client_model.ClientModel) !void {
+    if (!model.snapshot.ui.layout.show_status_bar) return;
+
+    if (model.term_height > 0) {
+        const used = renderedLineCount(out.items);
+        if (used + 1 < model.term_height) try out.appendNTimes('\n', model.term_height - 1 - used);
+    }
+    const line = try formatStatusBar(out.allocator, model.summary());
+    defer out.allocator.free(line);
+    try out.appendSlice(line);
+    try out.append('\n');
+}
+
 fn appendProcessHeader(out: *std.array_list.Managed(u8), model: *const client_model.ClientModel) !void {
     if (!model.show_panel_headers) return;
 
@@ -233,6 +303,11 @@ fn appendSpaces(out: *std.array_list.Managed(u8), count: usize) !void {
 }
 
 fn appendFilterPanel(out: *std.array_list.Managed(u8), model: *const client_model.ClientModel) !void {
+    if (model.entering_input_text) {
+        try out.writer().print("Send to {s}: {s}\n", .{ model.activeProcessLabel(), model.inputText() });
+        return;
+    }
+
     const filter_text = model.filterText();
     if (model.entering_filter_text) {
         try out.appendSlice("Filter: ");
@@ -247,6 +322,38 @@ fn appendFilterPanel(out: *std.array_list.Managed(u8), model: *const client_mode
     try out.appendSlice(" (/ to edit, esc to clear)\n");
 }
 
+/// Renders the open command palette: its prompt, then the matching actions
+/// with the highlighted one marked by the pointer.
+pub fn renderPalette(allocator: std.mem.Allocator, model: *const client_model.ClientModel) ![]const u8 {
+    var out = std.array_list.Managed(u8).init(allocator);
+    errdefer out.deinit();
+    try appendPalettePanel(&out, model);
+    return out.toOwnedSlice();
+}
+
+fn appendPalettePanel(out: *std.array_list.Managed(u8), model: *const client_model.ClientModel) !void {
+    if (!model.entering_palette) return;
+
+    try out.writer().print(": {s}\n", .{model.paletteText()});
+    const actions = model.paletteActions();
+    if (actions.len == 0) {
+        try out.appendSlice("  no matching actions\n");
+        return;
+    }
+    const start = if (model.palette_index >= palette_rows) model.palette_index + 1 - palette_rows else 0;
+    const end = @min(start + palette_rows, actions.len);
+    for (actions[start..end], start..) |action, index| {
+        if (index == model.palette_index) {
+            try out.appendSlice(model.snapshot.ui.style.pointer_char);
+            try out.append(' ');
+        } else {
+            try out.appendSlice("  ");
+        }
+        try out.appendSlice(palette.name(action));
+        try out.append('\n');
+    }
+}
+
 fn appendBinding(out: *std.array_list.Managed(u8), keys: domain.client_snapshot.StringList, label: []const u8) !void {
     for (keys, 0..) |key, index| {
         if (index >= 2) break;
@@ -356,15 +463,21 @@ fn appendStatusMarker(
     status: domain.process.ProcessStatus,
     colors_enabled: bool,
 ) !void {
-    const color = statusMarkerColor(style, status);
+    try appendColored(out, statusMarkerColor(style, status), statusMarker(status), colors_enabled);
+}
+
+/// Writes `text` in `color` when colors are enabled and the color is known.
+fn appendColored(out: *std.array_list.Managed(u8), color: []const u8, text: []const u8, colors_enabled: bool) !void {
     if (colors_enabled) {
-        if (ansiForegroundCode(color)) |code| {
-            try out.writer().print("\x1b[{}m{s}\x1b[0m", .{ code, statusMarker(status) });
+        if (config.color.parse(color)) |foreground| {
+            try config.color.writeSgr(out.writer(), foreground);
+            try out.appendSlice(text);
+            try out.appendSlice("\x1b[0m");
             return;
         }
     }
 
-    try out.appendSlice(statusMarker(status));
+    try out.appendSlice(text);
 }
 
 pub fn renderHelpOverlay(
@@ -386,11 +499,16 @@ pub fn renderHelpOverlay(
     try appendHelpOverlayLine(&out, &lines, height, "Navigation");
     try appendHelpOverlayBindingLine(&out, &lines, height, keys.up, "move up");
     try appendHelpOverlayBindingLine(&out, &lines, height, keys.down, "move down");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.next_running, "next running process");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.next_stopped, "next stopped process");
     try appendHelpOverlayLine(&out, &lines, height, "");
     try appendHelpOverlayLine(&out, &lines, height, "Process");
     try appendHelpOverlayBindingLine(&out, &lines, height, keys.start, "start process");
     try appendHelpOverlayBindingLine(&out, &lines, height, keys.stop, "stop process");
     try appendHelpOverlayBindingLine(&out, &lines, height, keys.restart, "restart process");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.start_all, "start all");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.stop_all, "stop all");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.send_input, "send input");
     try appendHelpOverlayLine(&out, &lines, height, "");
     try appendHelpOverlayLine(&out, &lines, height, "Filter");
     try appendHelpOverlayBindingLine(&out, &lines, height, keys.filter, "filter processes");
@@ -403,10 +521,19 @@ pub fn renderHelpOverlay(
     try appendHelpOverlayBindingLine(&out, &lines, height, keys.toggle_focus, "toggle focus");
     try appendHelpOverlayBindingLine(&out, &lines, height, keys.focus_client, "focus client");
     try appendHelpOverlayBindingLine(&out, &lines, height, keys.focus_server, "focus server");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.toggle_zoom, "toggle output zoom");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.grow_output, "grow output pane");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.shrink_output, "shrink output pane");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.copy_mode, "scroll back output");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.clear_output, "clear output");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.copy_output, "copy output to clipboard");
     try appendHelpOverlayLine(&out, &lines, height, "");
     try appendHelpOverlayLine(&out, &lines, height, "Other");
     try appendHelpOverlayBindingLine(&out, &lines, height, keys.toggle_help, "close help");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.command_palette, "command palette");
     try appendHelpOverlayBindingLine(&out, &lines, height, keys.docs, "show docs");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.reload_config, "reload config");
+    try appendHelpOverlayBindingLine(&out, &lines, height, keys.edit_config, "edit config");
     try appendHelpOverlayBindingLine(&out, &lines, height, keys.quit, "quit");
 
     return out.toOwnedSlice();
@@ -462,64 +589,6 @@ fn statusMarkerColor(style: *const domain.client_snapshot.UiStyleConfig, status:
     };
 }
 
-fn ansiForegroundCode(color: []const u8) ?u8 {
-    const trimmed = std.mem.trim(u8, color, " \t\r\n");
-    if (trimmed.len == 0 or std.ascii.eqlIgnoreCase(trimmed, "none")) return null;
-
-    const named_colors = [_]struct {
-        name: []const u8,
-        code: u8,
-    }{
-        .{ .name = "black", .code = 30 },
-        .{ .name = "red", .code = 31 },
-        .{ .name = "green", .code = 32 },
-        .{ .name = "yellow", .code = 33 },
-        .{ .name = "blue", .code = 34 },
-        .{ .name = "magenta", .code = 35 },
-        .{ .name = "cyan", .code = 36 },
-        .{ .name = "white", .code = 37 },
-        .{ .name = "brightblack", .code = 90 },
-        .{ .name = "gray", .code = 90 },
-        .{ .name = "grey", .code = 90 },
-        .{ .name = "brightred", .code = 91 },
-        .{ .name = "lightred", .code = 91 },
-        .{ .name = "brightgreen", .code = 92 },
-        .{ .name = "lightgreen", .code = 92 },
-        .{ .name = "brightyellow", .code = 93 },
-        .{ .name = "brightblue", .code = 94 },
-        .{ .name = "brightmagenta", .code = 95 },
-        .{ .name = "brightcyan", .code = 96 },
-        .{ .name = "brightwhite", .code = 97 },
-        .{ .name = "ansiblack", .code = 30 },
-        .{ .name = "ansired", .code = 31 },
-        .{ .name = "ansigreen", .code = 32 },
-        .{ .name = "ansiyellow", .code = 33 },
-        .{ .name = "ansiblue", .code = 34 },
-        .{ .name = "ansimagenta", .code = 35 },
-        .{ .name = "ansicyan", .code = 36 },
-        .{ .name = "ansiwhite", .code = 37 },
-        .{ .name = "ansibrightblack", .code = 90 },
-        .{ .name = "ansigray", .code = 90 },
-        .{ .name = "ansigrey", .code = 90 },
-        .{ .name = "ansibrightred", .code = 91 },
-        .{ .name = "ansibrightgreen", .code = 92 },
-        .{ .name = "ansibrightyellow", .code = 93 },
-        .{ .name = "ansibrightblue", .code = 94 },
-        .{ .name = "ansibrightmagenta", .code = 95 },
-        .{ .name = "ansibrightcyan", .code = 96 },
-        .{ .name = "ansibrightwhite", .code = 97 },
-    };
-
-    for (named_colors) |entry| {
-        if (std.ascii.eqlIgnoreCase(trimmed, entry.name)) return entry.code;
-    }
-
-    const color_index = std.fmt.parseUnsigned(u8, trimmed, 10) catch return null;
-    if (color_index <= 7) return 30 + color_index;
-    if (color_index <= 15) return 90 + color_index - 8;
-    return null;
-}
-
 test "process list renderer writes pointer status marker and labels" {
     var cfg = try test_config.standardRenderConfig(std.testing.allocator);
     defer cfg.deinit();
@@ -569,6 +638,33 @@ test "process list renderer colors status markers from config" {
     try std.testing.expect(std.mem.indexOf(u8, rendered, "> \x1b[32m●\x1b[0m beta-worker") != null);
 }
 
+test "process list renderer colors process names from config" {
+    var cfg = try test_config.standardRenderConfig(std.testing.allocator);
+    defer cfg.deinit();
+    cfg.style.pointer_char = ">";
+    cfg.procs.getPtr("alpha-api").?.color = try std.testing.allocator.dupe(u8, "magenta");
+    cfg.procs.getPtr("gamma-db").?.color = try std.testing.allocator.dupe(u8, "#ff8800");
+
+    var views = test_config.standardRenderViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, domain.process.ProcessId.fromInt(2), views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try client_model.ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    const rendered = try renderProcessList(std.testing.allocator, &model);
+    defer std.testing.allocator.free(rendered);
+
+    try std.testing.expect(std.mem.indexOf(u8, rendered, " \x1b[35malpha-api\x1b[0m\n") != null);
+    try std.testing.expect(std.mem.indexOf(u8, rendered, " \x1b[38;2;255;136;0mgamma-db\x1b[0m\n") != null);
+    try std.testing.expect(std.mem.indexOf(u8, rendered, "\x1b[0m beta-worker\n") != null);
+
+    model.no_color = true;
+    const plain = try renderProcessList(std.testing.allocator, &model);
+    defer std.testing.allocator.free(plain);
+    try std.testing.expectEqualStrings("  ■ alpha-api\n> ● beta-worker\n  ■ gamma-db\n", plain);
+}
+
 test "process list renderer omits status colors when disabled" {
     var cfg = try test_config.standardRenderConfig(std.testing.allocator);
     defer cfg.deinit();
@@ -670,6 +766,38 @@ test "process list renderer keeps selected process visible inside terminal heigh
     try test_ansi.expectEqualPlain(std.testing.allocator, "Processes 3/3\n> ■ gamma-db\n", rendered);
 }
 
+test "process list renderer pins the status bar below the list" {
+    var cfg = try test_config.standardRenderConfig(std.testing.allocator);
+    defer cfg.deinit();
+    cfg.style.pointer_char = ">";
+    cfg.layout.show_status_bar = true;
+
+    var app_state = try domain.state.AppState.init(std.testing.allocator, &cfg);
+    defer app_state.deinit();
+    app_state.current_proc_id = domain.process.ProcessId.fromInt(3);
+
+    var views = test_config.standardRenderViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, app_state.current_proc_id, views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try client_model.ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+    model.term_height = 2;
+
+    const short = try renderProcessList(std.testing.allocator, &model);
+    defer std.testing.allocator.free(short);
+    try test_ansi.expectEqualPlain(std.testing.allocator, "> ■ gamma-db\n3 procs  1 running  2 stopped  selected: gamma-db\n", short);
+
+    model.term_height = 6;
+    const padded = try renderProcessList(std.testing.allocator, &model);
+    defer std.testing.allocator.free(padded);
+    try test_ansi.expectEqualPlain(
+        std.testing.allocator,
+        "  ■ alpha-api\n  ● beta-worker\n> ■ gamma-db\n\n\n3 procs  1 running  2 stopped  selected: gamma-db\n",
+        padded,
+    );
+}
+
 test "process list renderer selects first row when active id is zero like legacy behavior" {
     var cfg = try test_config.standardRenderConfig(std.testing.allocator);
     defer cfg.deinit();
@@ -725,6 +853,87 @@ test "process list renderer includes status pid and categories in debug mode" {
     );
 }
 
+test "process list renderer shows how stopped processes exited" {
+    var cfg = try test_config.standardRenderConfig(std.testing.allocator);
+    defer cfg.deinit();
+    cfg.style.pointer_char = ">";
+
+    var app_state = try domain.state.AppState.init(std.testing.allocator, &cfg);
+    defer app_state.deinit();
+    app_state.current_proc_id = domain.process.ProcessId.fromInt(2);
+
+    var views = test_config.standardRenderViews(&cfg);
+    views[0].exit_status = 0;
+    views[2].exit_status = 1;
+    views[1].status = .halted;
+    views[1].timed_out = true;
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, app_state.current_proc_id, views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try client_model.ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    const rendered = try renderProcessList(std.testing.allocator, &model);
+    defer std.testing.allocator.free(rendered);
+
+    try test_ansi.expectEqualPlain(
+        std.testing.allocator,
+        "  ■ alpha-api (exited 0)\n> ■ beta-worker (timed out)\n  ■ gamma-db (exited 1)\n",
+        rendered,
+    );
+}
+
+test "process list renderer marks tasks apart from services" {
+    var cfg = try test_config.standardRenderConfig(std.testing.allocator);
+    defer cfg.deinit();
+    cfg.style.pointer_char = ">";
+    cfg.procs.getPtr("beta-worker").?.task = true;
+    cfg.procs.getPtr("gamma-db").?.task = true;
+
+    var views = test_config.standardRenderViews(&cfg);
+    views[2].exit_status = 2;
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, domain.process.ProcessId.fromInt(1), views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try client_model.ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    const rendered = try renderProcessList(std.testing.allocator, &model);
+    defer std.testing.allocator.free(rendered);
+
+    try test_ansi.expectEqualPlain(
+        std.testing.allocator,
+        "> ■ alpha-api\n  ● beta-worker (task)\n  ■ gamma-db (task exited 2)\n",
+        rendered,
+    );
+}
+
+test "process list renderer previews the last output line of running processes" {
+    var cfg = try test_config.standardRenderConfig(std.testing.allocator);
+    defer cfg.deinit();
+    cfg.style.pointer_char = ">";
+
+    var views = test_config.standardRenderViews(&cfg);
+    views[2].exit_status = 1;
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, domain.process.ProcessId.fromInt(1), views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+    const summaries = @constCast(snapshot.value.processes);
+    summaries[1].last_line = "listening on :8080";
+    summaries[2].last_line = "stale";
+
+    var model = try client_model.ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    const rendered = try renderProcessList(std.testing.allocator, &model);
+    defer std.testing.allocator.free(rendered);
+
+    try test_ansi.expectEqualPlain(
+        std.testing.allocator,
+        "> ■ alpha-api\n  ● beta-worker  listening on :8080\n  ■ gamma-db (exited 1)\n",
+        rendered,
+    );
+}
+
 test "process list renderer shows friendly empty message" {
     var cfg = try test_config.standardRenderConfig(std.testing.allocator);
     defer cfg.deinit();
@@ -999,6 +1208,35 @@ test "process list renderer shows focused filter prompt" {
     try test_ansi.expectEqualPlain(std.testing.allocator, "Filter: alpha\n> ■ alpha-api\n", rendered);
 }
 
+test "process list renderer shows the command palette above the list" {
+    var cfg = try test_config.standardRenderConfig(std.testing.allocator);
+    defer cfg.deinit();
+    cfg.style.pointer_char = ">";
+
+    var app_state = try domain.state.AppState.init(std.testing.allocator, &cfg);
+    defer app_state.deinit();
+
+    var views = test_config.standardRenderViews(&cfg);
+    var snapshot = try test_config.snapshotFromViews(std.testing.allocator, &cfg, app_state.current_proc_id, views[0..]);
+    defer snapshot.deinit(std.testing.allocator);
+
+    var model = try client_model.ClientModel.init(std.testing.allocator, snapshot.view());
+    defer model.deinit();
+
+    _ = try model.handleKey(":");
+    _ = try model.handleKey("r");
+    _ = try model.handleKey("e");
+    _ = try model.handleKey("down");
+
+    const rendered = try renderPalette(std.testing.allocator, &model);
+    defer std.testing.allocator.free(rendered);
+    try std.testing.expectEqualStrings(": re\n  restart\n> reload config\n", rendered);
+
+    const list = try renderProcessList(std.testing.allocator, &model);
+    defer std.testing.allocator.free(list);
+    try std.testing.expect(std.mem.startsWith(u8, list, rendered));
+}
+
 test "process list renderer shows submitted filter indicator" {
     var cfg = try test_config.standardRenderConfig(std.testing.allocator);
     defer cfg.deinit();
@@ -1,16 +1,22 @@
 //! TUI namespace.
-//! Runtime modes import this root to access the client model, session, key input, renderer, and split layout model.
+//! Runtime modes import this root to access the client model, session, editor launch, key input, command palette, renderer, split layout model, and remembered UI state.
 
 pub const client_model = @import("client_model.zig");
 pub const client_session = @import("client_session.zig");
+pub const editor = @import("editor.zig");
 pub const key_input = @import("key_input.zig");
+pub const palette = @import("palette.zig");
 pub const render = @import("render.zig");
 pub const split_model = @import("split_model.zig");
+pub const ui_state = @import("ui_state.zig");
 
 test {
     _ = client_model;
     _ = client_session;
+    _ = editor;
     _ = key_input;
+    _ = palette;
     _ = render;
     _ = split_model;
+    _ = ui_state;
 }
@@ -11,6 +11,8 @@ const min_terminal_width = 32;
 const client_width_padding = 6;
 const min_client_height = 8;
 const min_terminal_height = 10;
+const resize_step = 2;
+const copy_mode_jump = 1_000_000;
 
 pub const Orientation = enum {
     left,
@@ -29,6 +31,22 @@ pub const Size = struct {
     height: i32,
 };
 
+/// A terminal cell resolved to the pane drawn there, in pane-local coordinates.
+pub const Hit = struct {
+    pane: Pane,
+    col: usize,
+    row: usize,
+};
+
+/// How the output viewport moves on the next frame.
+pub const Scroll = union(enum) {
+    /// Track the newest output.
+    follow,
+    /// Copy mode: keep the viewport where it is, moved by this many rows
+    /// (up is negative).
+    hold: i32,
+};
+
 pub const InputSink = struct {
     context: *anyopaque,
     write: *const fn (context: *anyopaque, bytes: []const u8) anyerror!void,
@@ -44,6 +62,10 @@ pub const Model = struct {
     orientation: Orientation,
     app_config: *const config.schema.Config,
     focus: Pane = .client,
+    zoomed: bool = false,
+    copy_mode: bool = false,
+    scroll_delta: i32 = 0,
+    split_adjust: i32 = 0,
     server_input: ?InputSink = null,
     status_height: i32 = 0,
     content_width: i32 = 0,
@@ -66,6 +88,7 @@ pub const Model = struct {
     }
 
     pub fn clientVisible(self: *const Model) bool {
+        if (self.zoomed) return false;
         if (!self.app_config.layout.hide_process_list_when_unfocused) return true;
         return self.focus == .client;
     }
@@ -83,6 +106,14 @@ pub const Model = struct {
     }
 
     pub fn handleKey(self: *Model, key: []const u8) !void {
+        if (self.copy_mode) {
+            self.handleCopyModeKey(key);
+            return;
+        }
+        if (matches(self.app_config.keybinding.copy_mode, key)) {
+            self.enterCopyMode();
+            return;
+        }
         if (std.mem.eql(u8, key, "tab") or std.mem.eql(u8, key, "shift+tab")) {
             self.focus = if (self.focus == .client) .server else .client;
             self.relayoutAfterFocusChange();
@@ -103,6 +134,18 @@ pub const Model = struct {
             self.relayoutAfterFocusChange();
             return;
         }
+        if (matches(self.app_config.keybinding.toggle_zoom, key)) {
+            self.toggleZoom();
+            return;
+        }
+        if (matches(self.app_config.keybinding.grow_output, key)) {
+            self.resizeOutput(resize_step);
+            return;
+        }
+        if (matches(self.app_config.keybinding.shrink_output, key)) {
+            self.resizeOutput(-resize_step);
+            return;
+        }
 
         if (self.focus == .server) {
             if (self.server_input) |sink| {
@@ -112,6 +155,128 @@ pub const Model = struct {
         }
     }
 
+    /// Moves focus to `pane`, as the focus keys do; used for mouse clicks.
+    pub fn focusPane(self: *Model, pane: Pane) void {
+        if (self.copy_mode or self.focus == pane) return;
+        self.focus = pane;
+        self.relayoutAfterFocusChange();
+    }
+
+    /// Resolves a 0-based terminal cell to the pane under it. Cells in the
+    /// status bar, or outside the content area, hit nothing.
+    pub fn paneAt(self: *const Model, col: usize, row: usize) ?Hit {
+        if (row >= positive(self.content_height) or col >= positive(self.content_width)) return null;
+        const client_width = positive(self.client_width);
+        const client_height = positive(self.client_height);
+        if (client_width == 0 or client_height == 0) return .{ .pane = .server, .col = col, .row = row };
+
+        return switch (self.orientation) {
+            .left => if (col < client_width)
+                .{ .pane = .client, .col = col, .row = row }
+            else
+                .{ .pane = .server, .col = col - client_width, .row = row },
+            .right => {
+                const server_width = positive(self.server_width);
+                if (col < server_width) return .{ .pane = .server, .col = col, .row = row };
+                return .{ .pane = .client, .col = col - server_width, .row = row };
+            },
+            .top => if (row < client_height)
+                .{ .pane = .client, .col = col, .row = row }
+            else
+                .{ .pane = .server, .col = col, .row = row - client_height },
+            .bottom => {
+                const server_height = positive(self.server_height);
+                if (row < server_height) return .{ .pane = .server, .col = col, .row = row };
+                return .{ .pane = .client, .col = col, .row = row - server_height };
+            },
+        };
+    }
+
+    /// True when `key` would move focus from the process list to the output
+    /// pane, so callers can refuse it for a process that is not running.
+    pub fn focusesServer(self: *const Model, key: []const u8) bool {
+        if (self.copy_mode or self.focus != .client) return false;
+        return std.mem.eql(u8, key, "tab") or
+            std.mem.eql(u8, key, "shift+tab") or
+            std.mem.eql(u8, key, "ctrl+right") or
+            matches(self.app_config.keybinding.focus_server, key) or
+            matches(self.app_config.keybinding.toggle_focus, key);
+    }
+
+    /// Gives the process output the whole content area. Zooming focuses the
+    /// server pane because the hidden process list cannot take input.
+    pub fn toggleZoom(self: *Model) void {
+        self.zoomed = !self.zoomed;
+        if (self.zoomed) self.focus = .server;
+        if (self.content_width > 0) self.recalculateLayout();
+    }
+
+    /// Freezes the output pane for reading scrollback. Keys scroll instead of
+    /// reaching the process until copy mode is left.
+    pub fn enterCopyMode(self: *Model) void {
+        self.copy_mode = true;
+        self.scroll_delta = 0;
+        if (self.focus == .server) return;
+        self.focus = .server;
+        if (self.app_config.layout.hide_process_list_when_unfocused and self.content_width > 0) {
+            self.recalculateLayout();
+        }
+    }
+
+    pub fn exitCopyMode(self: *Model) void {
+        self.copy_mode = false;
+        self.scroll_delta = 0;
+    }
+
+    /// Returns the viewport movement for the frame being rendered and clears
+    /// the rows queued by copy-mode keys since the last frame.
+    pub fn takeScroll(self: *Model) Scroll {
+        if (!self.copy_mode) return .follow;
+        defer self.scroll_delta = 0;
+        return .{ .hold = self.scroll_delta };
+    }
+
+    fn handleCopyModeKey(self: *Model, key: []const u8) void {
+        if (matches(self.app_config.keybinding.copy_mode, key) or
+            std.mem.eql(u8, key, "q") or
+            std.mem.eql(u8, key, "esc"))
+        {
+            self.exitCopyMode();
+            return;
+        }
+
+        const page = @max(self.server_height - 1, 1);
+        const delta: i32 = if (std.mem.eql(u8, key, "k") or std.mem.eql(u8, key, "up"))
+            -1
+        else if (std.mem.eql(u8, key, "j") or std.mem.eql(u8, key, "down"))
+            1
+        else if (std.mem.eql(u8, key, "pageup"))
+            -page
+        else if (std.mem.eql(u8, key, "pagedown"))
+            page
+        else if (std.mem.eql(u8, key, "g") or std.mem.eql(u8, key, "home"))
+            -copy_mode_jump
+        else if (std.mem.eql(u8, key, "G") or std.mem.eql(u8, key, "end"))
+            copy_mode_jump
+        else
+            return;
+        self.scroll_delta = std.math.clamp(self.scroll_delta + delta, -copy_mode_jump, copy_mode_jump);
+    }
+
+    /// Moves the split by `amount` cells in favor of process output; negative
+    /// amounts give the space back to the process list. Pane minimums win.
+    pub fn resizeOutput(self: *Model, amount: i32) void {
+        if (self.zoomed) return;
+        self.split_adjust -= amount;
+        if (self.content_width <= 0 or !self.clientVisible()) return;
+
+        self.recalculateLayout();
+        self.split_adjust = switch (self.orientation) {
+            .left, .right => self.client_width - self.desiredClientWidth(),
+            .top, .bottom => self.client_height - self.desiredClientHeight(),
+        };
+    }
+
     /// Recomputes pane sizes from terminal dimensions. Invalid dimensions are
     /// ignored because resize probes may fail transiently during startup.
     pub fn resize(self: *Model, width: i32, height: i32) !void {
@@ -146,6 +311,25 @@ pub const Model = struct {
             );
         }
 
+        if (self.copy_mode) {
+            return std.fmt.allocPrint(
+                allocator,
+                "Server  copy mode  [k/j] scroll  [g/G] top/bottom  [q] live output",
+                .{},
+            );
+        }
+
+        if (self.zoomed) {
+            return std.fmt.allocPrint(
+                allocator,
+                "Server  zoomed  [{s}] unzoom  [{s}] quit",
+                .{
+                    firstBinding(self.app_config.keybinding.toggle_zoom),
+                    firstBinding(self.app_config.keybinding.quit),
+                },
+            );
+        }
+
         if (!self.clientVisible()) {
             return std.fmt.allocPrint(
                 allocator,
@@ -162,6 +346,11 @@ pub const Model = struct {
     }
 
     fn relayoutAfterFocusChange(self: *Model) void {
+        if (self.zoomed) {
+            self.zoomed = false;
+            if (self.content_width > 0) self.recalculateLayout();
+            return;
+        }
         if (self.app_config.layout.hide_process_list_when_unfocused and self.content_width > 0) {
             self.recalculateLayout();
         }
@@ -179,7 +368,7 @@ pub const Model = struct {
                     return;
                 }
 
-                var client_width = self.desiredClientWidth();
+                var client_width = @max(self.desiredClientWidth() + self.split_adjust, 0);
                 if (client_width < min_client_width and self.content_width >= min_client_width) {
                     client_width = min_client_width;
                 }
@@ -211,7 +400,7 @@ pub const Model = struct {
                     return;
                 }
 
-                var client_height = @divTrunc(self.content_height * unified_client_ratio, 100);
+                var client_height = @max(self.desiredClientHeight() + self.split_adjust, 0);
                 if (client_height < min_client_height and self.content_height >= min_client_height) {
                     client_height = min_client_height;
                 }
@@ -234,8 +423,19 @@ pub const Model = struct {
         }
     }
 
+    fn desiredClientHeight(self: *const Model) i32 {
+        const percent = if (self.app_config.layout.process_list_percent > 0)
+            self.app_config.layout.process_list_percent
+        else
+            unified_client_ratio;
+        return @divTrunc(self.content_height * percent, 100);
+    }
+
     fn desiredClientWidth(self: *const Model) i32 {
-        var desired: i32 = @max(self.longest_process_label_width + client_width_padding, min_client_width);
+        var desired: i32 = if (self.app_config.layout.process_list_percent > 0)
+            @divTrunc(self.content_width * self.app_config.layout.process_list_percent, 100)
+        else
+            @max(self.longest_process_label_width + client_width_padding, min_client_width);
 
         if (self.content_width <= min_client_width + min_terminal_width) {
             if (self.content_width <= 0) return desired;
@@ -337,6 +537,10 @@ fn matches(bindings: config.schema.StringList, key: []const u8) bool {
     return false;
 }
 
+fn positive(value: i32) usize {
+    return @intCast(@max(value, 0));
+}
+
 fn firstBinding(bindings: config.schema.StringList) []const u8 {
     if (bindings.items.len == 0) return "";
     return bindings.items[0];
@@ -412,6 +616,46 @@ test "split model clamps side client pane to preserve terminal width" {
     try std.testing.expectEqual(@as(i32, 32), model.serverSize().width);
 }
 
+test "split model resize keys move the split within pane minimums" {
+    var cfg = try testConfig(false);
+    defer cfg.deinit();
+
+    var model = Model.init(.left, &cfg);
+    model.setProcessLabels(&.{ "api", "background-worker" });
+    try model.resize(120, 40);
+
+    try model.handleKey("f8");
+    try std.testing.expectEqual(@as(i32, 24), model.clientSize().width);
+
+    try model.handleKey("f7");
+    try std.testing.expectEqual(@as(i32, 26), model.clientSize().width);
+    try std.testing.expectEqual(@as(i32, 94), model.serverSize().width);
+
+    for (0..60) |_| try model.handleKey("f7");
+    try std.testing.expectEqual(@as(i32, 32), model.serverSize().width);
+
+    try model.handleKey("f8");
+    try std.testing.expectEqual(@as(i32, 34), model.serverSize().width);
+}
+
+test "split model honors configured process list percent" {
+    var cfg = try testConfig(false);
+    defer cfg.deinit();
+    cfg.layout.process_list_percent = 50;
+
+    var side = Model.init(.left, &cfg);
+    try side.resize(120, 40);
+    try std.testing.expectEqual(@as(i32, 60), side.clientSize().width);
+    try std.testing.expectEqual(@as(i32, 60), side.serverSize().width);
+
+    var stacked = Model.init(.top, &cfg);
+    try stacked.resize(120, 41);
+    try std.testing.expectEqual(@as(i32, 20), stacked.clientSize().height);
+    try stacked.handleKey("f8");
+    try std.testing.expectEqual(@as(i32, 18), stacked.clientSize().height);
+    try std.testing.expectEqual(@as(i32, 22), stacked.serverSize().height);
+}
+
 test "split model status bar reports hidden process list" {
     var cfg = try testConfig(true);
     defer cfg.deinit();
@@ -439,6 +683,43 @@ test "split model status bar is compact and contextual" {
     try std.testing.expectEqualStrings("Client  [Tab] server  [/] filter  [?] help  [q] quit", status);
 }
 
+test "split model zoom gives output the full content area" {
+    var cfg = try testConfig(false);
+    defer cfg.deinit();
+
+    var model = Model.init(.left, &cfg);
+    try model.resize(120, 40);
+
+    try model.handleKey("f5");
+    try std.testing.expect(model.zoomed);
+    try std.testing.expectEqual(Pane.server, model.focusedPane());
+    try std.testing.expectEqual(Size{ .width = 0, .height = 0 }, model.clientSize());
+    try std.testing.expectEqual(Size{ .width = 120, .height = 39 }, model.serverSize());
+
+    const status = try model.statusBar(std.testing.allocator);
+    defer std.testing.allocator.free(status);
+    try std.testing.expectEqualStrings("Server  zoomed  [f5] unzoom  [q] quit", status);
+
+    try model.handleKey("f5");
+    try std.testing.expect(!model.zoomed);
+    try std.testing.expect(model.clientSize().width > 0);
+}
+
+test "split model focus change leaves zoom" {
+    var cfg = try testConfig(false);
+    defer cfg.deinit();
+
+    var model = Model.init(.left, &cfg);
+    try model.resize(120, 40);
+
+    try model.handleKey("f5");
+    try model.handleKey("tab");
+
+    try std.testing.expect(!model.zoomed);
+    try std.testing.expectEqual(Pane.client, model.focusedPane());
+    try std.testing.expect(model.clientSize().width > 0);
+}
+
 test "split model cycles focus with tab and shift tab" {
     var cfg = try testConfig(false);
     defer cfg.deinit();
@@ -453,6 +734,19 @@ test "split model cycles focus with tab and shift tab" {
     try std.testing.expectEqual(Pane.client, model.focusedPane());
 }
 
+test "split model reports keys that move focus to the output pane" {
+    var cfg = try testConfig(false);
+    defer cfg.deinit();
+
+    var model = Model.init(.left, &cfg);
+    try std.testing.expect(model.focusesServer("tab"));
+    try std.testing.expect(model.focusesServer("ctrl+right"));
+    try std.testing.expect(!model.focusesServer("j"));
+
+    try model.handleKey("tab");
+    try std.testing.expect(!model.focusesServer("tab"));
+}
+
 test "split model intercepts tab before forwarding server input" {
     var cfg = try testConfig(false);
     defer cfg.deinit();
@@ -506,6 +800,36 @@ test "split model forwards server-focused control keys as terminal input" {
     try std.testing.expectEqualStrings("\x04\x0c\x1a\x0a\x0b\x13\x18", capture.bytes());
 }
 
+test "split model copy mode queues scrollback instead of forwarding keys" {
+    var cfg = try testConfig(false);
+    defer cfg.deinit();
+
+    var capture = InputCapture{};
+    var model = Model.init(.left, &cfg);
+    model.setServerInput(InputCapture.sink(&capture));
+    try model.resize(120, 40);
+
+    try model.handleKey("f6");
+    try std.testing.expect(model.copy_mode);
+    try std.testing.expectEqual(Pane.server, model.focusedPane());
+
+    try model.handleKey("k");
+    try model.handleKey("k");
+    try model.handleKey("pageup");
+    try model.handleKey("j");
+    try model.handleKey("x");
+    try std.testing.expectEqual(Scroll{ .hold = -39 }, model.takeScroll());
+    try std.testing.expectEqual(Scroll{ .hold = 0 }, model.takeScroll());
+    try std.testing.expectEqualStrings("", capture.bytes());
+
+    try model.handleKey("q");
+    try std.testing.expect(!model.copy_mode);
+    try std.testing.expectEqual(Scroll.follow, model.takeScroll());
+
+    try model.handleKey("k");
+    try std.testing.expectEqualStrings("k", capture.bytes());
+}
+
 fn testConfig(hide_process_list_when_unfocused: bool) !config.schema.Config {
     var cfg = config.schema.Config.empty(std.testing.allocator);
     errdefer cfg.deinit();
@@ -532,3 +856,25 @@ const InputCapture = struct {
         return self.buffer[0..self.len];
     }
 };
+
+test "split model resolves clicks to the pane under them" {
+    var cfg = try testConfig(false);
+    defer cfg.deinit();
+
+    var model = Model.init(.left, &cfg);
+    try model.resize(100, 30);
+    const client_width: usize = @intCast(model.client_width);
+
+    const list = model.paneAt(1, 4).?;
+    try std.testing.expectEqual(Pane.client, list.pane);
+    try std.testing.expectEqual(@as(usize, 4), list.row);
+
+    const output = model.paneAt(client_width + 2, 0).?;
+    try std.testing.expectEqual(Pane.server, output.pane);
+    try std.testing.expectEqual(@as(usize, 2), output.col);
+
+    try std.testing.expect(model.paneAt(1, @intCast(model.content_height)) == null);
+
+    model.focusPane(.server);
+    try std.testing.expectEqual(Pane.server, model.focusedPane());
+}
@@ -0,0 +1,150 @@
+//! Remembered UI state between launches.
+//! The last selected process and filter text are stored per config file in a small JSON state file, so reopening a project lands where it was left.
+
+const std = @import("std");
+const config = @import("../config/root.zig");
+const client_session = @import("client_session.zig");
+
+const log = std.log.scoped(.tui_ui_state);
+
+/// Largest state file that is read back; anything bigger is treated as corrupt.
+const max_file_bytes = 1024 * 1024;
+
+pub const Entry = struct {
+    process: []const u8 = "",
+    filter: []const u8 = "",
+};
+
+/// On-disk layout: one entry per config file, keyed by its real path.
+const File = std.json.ArrayHashMap(Entry);
+
+/// `$XDG_STATE_HOME/proctmux/state.json`, falling back to `~/.local/state`.
+/// Returns null when neither variable is set.
+pub fn defaultPath(allocator: std.mem.Allocator) !?[]u8 {
+    if (std.process.getEnvVarOwned(allocator, "XDG_STATE_HOME")) |state_home| {
+        defer allocator.free(state_home);
+        if (state_home.len > 0) return try std.fs.path.join(allocator, &.{ state_home, "proctmux", "state.json" });
+    } else |_| {}
+    const home = std.process.getEnvVarOwned(allocator, "HOME") catch return null;
+    defer allocator.free(home);
+    if (home.len == 0) return null;
+    return try std.fs.path.join(allocator, &.{ home, ".local", "state", "proctmux", "state.json" });
+}
+
+/// The configured `state_file`, or the default location when it is empty.
+pub fn pathForConfig(allocator: std.mem.Allocator, cfg: *const config.schema.Config) !?[]u8 {
+    if (cfg.state_file.len > 0) return try allocator.dupe(u8, cfg.state_file);
+    return defaultPath(allocator);
+}
+
+/// Reads the entry saved for `key`. A missing, unreadable, or corrupt file
+/// yields null so startup falls back to the first process.
+pub fn load(arena: std.mem.Allocator, path: []const u8, key: []const u8) ?Entry {
+    const file = readFile(arena, path) orelse return null;
+    return file.map.get(key);
+}
+
+/// Stores `entry` under `key`, keeping entries for other config files. A
+/// corrupt file is replaced rather than blocking the save.
+pub fn save(allocator: std.mem.Allocator, path: []const u8, key: []const u8, entry: Entry) !void {
+    var arena_state = std.heap.ArenaAllocator.init(allocator);
+    defer arena_state.deinit();
+    const arena = arena_state.allocator();
+
+    var file = readFile(arena, path) orelse File{};
+    try file.map.put(arena, key, entry);
+
+    var out = std.array_list.Managed(u8).init(arena);
+    try out.writer().print("{f}\n", .{std.json.fmt(file, .{ .whitespace = .indent_2 })});
+
+    if (std.fs.path.dirname(path)) |parent| try std.fs.cwd().makePath(parent);
+    try std.fs.cwd().writeFile(.{ .sub_path = path, .data = out.items });
+}
+
+fn readFile(arena: std.mem.Allocator, path: []const u8) ?File {
+    const data = std.fs.cwd().readFileAlloc(arena, path, max_file_bytes) catch |err| {
+        if (err != error.FileNotFound) log.warn("cannot read UI state {s}: {s}", .{ path, @errorName(err) });
+        return null;
+    };
+    return std.json.parseFromSliceLeaky(File, arena, data, .{ .ignore_unknown_fields = true }) catch |err| {
+        log.warn("ignoring corrupt UI state {s}: {s}", .{ path, @errorName(err) });
+        return null;
+    };
+}
+
+/// Key for `cfg` in the state file: the real path of its config file, so the
+/// same project matches however it was opened.
+pub fn configKey(allocator: std.mem.Allocator, dir: std.fs.Dir, cfg: *const config.schema.Config) ![]u8 {
+    return dir.realpathAlloc(allocator, cfg.file_path) catch try allocator.dupe(u8, cfg.file_path);
+}
+
+/// Remembered state for one client run. `restore` applies the saved entry and
+/// `remember` writes the current one back; both only log failures.
+pub const Session = struct {
+    allocator: std.mem.Allocator,
+    path: ?[]u8,
+    key: []u8,
+
+    pub fn init(allocator: std.mem.Allocator, dir: std.fs.Dir, cfg: *const config.schema.Config) !Session {
+        const path = try pathForConfig(allocator, cfg);
+        errdefer if (path) |value| allocator.free(value);
+        return .{ .allocator = allocator, .path = path, .key = try configKey(allocator, dir, cfg) };
+    }
+
+    pub fn deinit(self: *Session) void {
+        if (self.path) |path| self.allocator.free(path);
+        self.allocator.free(self.key);
+    }
+
+    /// Saved state is a convenience, so a failure to apply it is logged and
+    /// the client starts with the default selection.
+    pub fn restore(self: *const Session, session: *client_session.ClientSession) void {
+        const path = self.path orelse return;
+        var arena_state = std.heap.ArenaAllocator.init(self.allocator);
+        defer arena_state.deinit();
+        const entry = load(arena_state.allocator(), path, self.key) orelse return;
+        session.restoreUiState(entry.process, entry.filter) catch |err| {
+            log.warn("cannot restore UI state {s}: {s}", .{ path, @errorName(err) });
+        };
+    }
+
+    pub fn remember(self: *const Session, session: *const client_session.ClientSession) void {
+        const path = self.path orelse return;
+        save(self.allocator, path, self.key, .{
+            .process = session.model.activeProcessLabel(),
+            .filter = session.model.filterText(),
+        }) catch |err| {
+            log.warn("cannot save UI state {s}: {s}", .{ path, @errorName(err) });
+        };
+    }
+};
+
+test "ui state round-trips per config and survives a corrupt file" {
+    var tmp = std.testing.tmpDir(.{});
+    defer tmp.cleanup();
+    const root = try tmp.dir.realpathAlloc(std.testing.allocator, ".");
+    defer std.testing.allocator.free(root);
+    const path = try std.fs.path.join(std.testing.allocator, &.{ root, "state", "state.json" });
+    defer std.testing.allocator.free(path);
+
+    var arena_state = std.heap.ArenaAllocator.init(std.testing.allocator);
+    defer arena_state.deinit();
+    const arena = arena_state.allocator();
+
+    try std.testing.expect(load(arena, path, "/a/proctmux.yaml") == null);
+
+    try save(std.testing.allocator, path, "/a/proctmux.yaml", .{ .process = "api", .filter = "ap" });
+    try save(std.testing.allocator, path, "/b/proctmux.yaml", .{ .process = "web" });
+    const a = load(arena, path, "/a/proctmux.yaml") orelse return error.TestUnexpectedResult;
+    try std.testing.expectEqualStrings("api", a.process);
+    try std.testing.expectEqualStrings("ap", a.filter);
+    const b = load(arena, path, "/b/proctmux.yaml") orelse return error.TestUnexpectedResult;
+    try std.testing.expectEqualStrings("web", b.process);
+    try std.testing.expectEqualStrings("", b.filter);
+
+    try tmp.dir.writeFile(.{ .sub_path = "state/state.json", .data = "{not json" });
+    try std.testing.expect(load(arena, path, "/a/proctmux.yaml") == null);
+    try save(std.testing.allocator, path, "/a/proctmux.yaml", .{ .process = "worker" });
+    const replaced = load(arena, path, "/a/proctmux.yaml") orelse return error.TestUnexpectedResult;
+    try std.testing.expectEqualStrings("worker", replaced.process);
+}
@@ -3,10 +3,12 @@
 
 const std = @import("std");
 const pty = @import("../proc/pty.zig");
+const terminal = @import("../terminal/root.zig");
 const tui = @import("../tui/root.zig");
 
 const log = std.log.scoped(.child_primary);
 const max_output = 1024 * 1024;
+const stop_grace_ms = 5000;
 
 pub const OutputCursor = struct {
     offset: u64 = 0,
@@ -59,15 +61,7 @@ pub const ChildPrimary = struct {
     }
 
     pub fn deinit(self: *ChildPrimary) void {
-        if (!self.exited.load(.seq_cst)) {
-            std.posix.kill(self.pid, std.posix.SIG.INT) catch {};
-            std.Thread.sleep(50 * std.time.ns_per_ms);
-        }
-        if (!self.exited.load(.seq_cst)) {
-            std.posix.kill(self.pid, std.posix.SIG.TERM) catch {};
-            std.Thread.sleep(50 * std.time.ns_per_ms);
-        }
-        if (!self.exited.load(.seq_cst)) std.posix.kill(self.pid, std.posix.SIG.KILL) catch {};
+        self.terminate();
 
         if (self.pty_file) |file| {
             file.close();
@@ -89,6 +83,28 @@ pub const ChildPrimary = struct {
         self.allocator.destroy(self);
     }
 
+    /// Asks the child primary to stop its processes and exit, escalating to
+    /// SIGKILL after `stop_grace_ms`. Calling it again after exit does nothing.
+    pub fn terminate(self: *ChildPrimary) void {
+        if (self.exited.load(.seq_cst)) return;
+        std.posix.kill(self.pid, std.posix.SIG.INT) catch {};
+
+        var waited_ms: u64 = 0;
+        while (!self.exited.load(.seq_cst) and waited_ms < stop_grace_ms) : (waited_ms += 10) {
+            std.Thread.sleep(10 * std.time.ns_per_ms);
+        }
+        if (!self.exited.load(.seq_cst)) std.posix.kill(self.pid, std.posix.SIG.KILL) catch {};
+    }
+
+    pub fn shutdownCleanup(self: *ChildPrimary) terminal.shutdown.Cleanup {
+        return .{ .context = self, .run = terminateAdapter };
+    }
+
+    fn terminateAdapter(context: *anyopaque) void {
+        const self: *ChildPrimary = @ptrCast(@alignCast(context));
+        self.terminate();
+    }
+
     pub fn sink(self: *ChildPrimary) tui.split_model.InputSink {
         return .{
             .context = self,
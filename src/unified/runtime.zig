@@ -24,29 +24,31 @@ pub fn run(
     allocator: std.mem.Allocator,
     dir: std.fs.Dir,
     parent_args: []const []const u8,
-    config_file: []const u8,
+    config_files: []const []const u8,
+    set_overrides: []const []const u8,
     orientation: cli.UnifiedSplit,
     input: io.Input,
     output: io.Output,
 ) !void {
     if (builtin.is_test) {
-        try runInProcess(allocator, dir, config_file, orientation, input, output);
+        try runInProcess(allocator, dir, config_files, set_overrides, orientation, input, output);
         return;
     }
 
-    try runWithChildProcess(allocator, dir, parent_args, config_file, orientation, input, output);
+    try runWithChildProcess(allocator, dir, parent_args, config_files, set_overrides, orientation, input, output);
 }
 
 fn runWithChildProcess(
     allocator: std.mem.Allocator,
     dir: std.fs.Dir,
     parent_args: []const []const u8,
-    config_file: []const u8,
+    config_files: []const []const u8,
+    set_overrides: []const []const u8,
     orientation: cli.UnifiedSplit,
     input: io.Input,
     output: io.Output,
 ) !void {
-    var loaded = try config.runtime.loadInDir(allocator, dir, config_file);
+    var loaded = try config.runtime.loadFilesWithOverridesInDir(allocator, dir, config_files, set_overrides);
     defer loaded.deinit();
 
     const child_args = try args_mod.childArgs(allocator, parent_args);
@@ -63,16 +65,20 @@ fn runWithChildProcess(
     var env_map = try std.process.getEnvMap(allocator);
     defer env_map.deinit();
     try env_map.put("PROCTMUX_EMBEDDED_PRIMARY", "1");
+    if (config.debug_pane.log_path.len > 0) try env_map.put(config.debug_pane.env_var, config.debug_pane.log_path);
 
     const child_cwd = std.fs.path.dirname(loaded.config.file_path) orelse ".";
     const child = try child_primary.ChildPrimary.init(allocator, child_argv, &env_map, child_cwd);
     defer child.deinit();
+    const cleanup_slot = try terminal.shutdown.register(child.shutdownCleanup());
+    defer terminal.shutdown.unregister(cleanup_slot);
 
     const socket_path = try ipc.socket.waitPathForConfig(allocator, &loaded.config);
     defer allocator.free(socket_path);
 
     var ipc_client = try ipc.client.Client.connect(allocator, socket_path);
     defer ipc_client.deinit();
+    ipc_client.response_timeout_ms = ipc.client.responseTimeoutMs(&loaded.config);
 
     var session = try tui.client_session.ClientSession.init(
         allocator,
@@ -80,6 +86,11 @@ fn runWithChildProcess(
     );
     defer session.deinit();
 
+    var ui_state = try tui.ui_state.Session.init(allocator, dir, &loaded.config);
+    defer ui_state.deinit();
+    ui_state.restore(&session);
+    defer ui_state.remember(&session);
+
     var split = tui.split_model.Model.init(args_mod.orientationForCli(orientation), &loaded.config);
     split.setServerInput(child.sink());
     const labels = try processLabels(allocator, &session);
@@ -102,12 +113,13 @@ fn runWithChildProcess(
 fn runInProcess(
     allocator: std.mem.Allocator,
     dir: std.fs.Dir,
-    config_file: []const u8,
+    config_files: []const []const u8,
+    set_overrides: []const []const u8,
     orientation: cli.UnifiedSplit,
     input: io.Input,
     output: io.Output,
 ) !void {
-    var loaded = try config.runtime.loadInDir(allocator, dir, config_file);
+    var loaded = try config.runtime.loadFilesWithOverridesInDir(allocator, dir, config_files, set_overrides);
     defer loaded.deinit();
 
     const socket_path = try ipc.socket.createPathForConfig(allocator, &loaded.config);
@@ -116,6 +128,9 @@ fn runInProcess(
 
     var primary_server = try primary.Server.init(allocator, &loaded.config);
     defer primary_server.deinit();
+    primary_server.config_dir = dir;
+    primary_server.config_files = config_files;
+    primary_server.set_overrides = set_overrides;
 
     var stopped = std.atomic.Value(bool).init(false);
     var primary_run = in_process_primary.PrimaryRun{
@@ -134,6 +149,7 @@ fn runInProcess(
 
     var ipc_client = try ipc.client.Client.connect(allocator, socket_path);
     defer ipc_client.deinit();
+    ipc_client.response_timeout_ms = ipc.client.responseTimeoutMs(&loaded.config);
 
     var session = try tui.client_session.ClientSession.init(
         allocator,
@@ -141,6 +157,11 @@ fn runInProcess(
     );
     defer session.deinit();
 
+    var ui_state = try tui.ui_state.Session.init(allocator, dir, &loaded.config);
+    defer ui_state.deinit();
+    ui_state.restore(&session);
+    defer ui_state.remember(&session);
+
     var server_input = in_process_primary.ServerInput{
         .primary_server = &primary_server,
         .session = &session,
@@ -182,6 +203,11 @@ const RuntimeSession = struct {
 fn runInteractiveRuntime(runtime: RuntimeSession) !void {
     try runtime.output.writeAll(terminal.repaint.hide_cursor);
     defer runtime.output.writeAll(terminal.repaint.show_cursor) catch {};
+    const mouse = runtime.split.app_config.mouse;
+    if (mouse) try runtime.output.writeAll(terminal.repaint.enable_mouse);
+    defer if (mouse) runtime.output.writeAll(terminal.repaint.disable_mouse) catch {};
+    const mouse_slot = if (mouse) try terminal.shutdown.register(terminal.mode.mouseCleanup()) else null;
+    defer if (mouse_slot) |slot| terminal.shutdown.unregister(slot);
 
     _ = try resizeLayout(runtime.session, runtime.split, runtime.input, runtime.output);
 
@@ -260,6 +286,10 @@ fn runInputLoop(state: InputLoop) !void {
         var should_render = false;
         var index: usize = 0;
         while (index < n) {
+            if (tui.key_input.mouseForInput(buffer[0..n], &index)) |mouse| {
+                if (try handleClick(state, mouse)) should_render = true;
+                continue;
+            }
             var key_buf: [1]u8 = undefined;
             if (tui.key_input.keyForInput(buffer[0..n], &index, &key_buf)) |key| {
                 const previous_focus = state.split.focusedPane();
@@ -295,12 +325,22 @@ fn handleKey(state: InputLoop, key: []const u8) !KeyHandling {
         const interaction = try state.session.handleKeyInteraction(key, .{
             .sync_selection_after_command = state.sync_selection_after_command,
         });
+        if (interaction.cleared_output) try state.output_state.forgetProcess(state.session.model.active_proc_id);
+        if (interaction.edit_config) {
+            try tui.editor.editAndReload(state.session, state.split.app_config.file_path, state.input.fd, state.output, state.split.app_config.mouse);
+            return .{ .render_now = true };
+        }
+        if (interaction.toggle_zoom) {
+            state.split.toggleZoom();
+            return .{};
+        }
         if (interaction.handled_command) {
             return .{
                 .stop = interaction.stop,
                 .render_now = interaction.render_now,
             };
         }
+        if (state.split.focusesServer(key) and !try state.session.model.allowOutputFocus()) return .{};
 
         try state.split.handleKey(key);
         return .{};
@@ -310,14 +350,32 @@ fn handleKey(state: InputLoop, key: []const u8) !KeyHandling {
     return .{};
 }
 
+/// A left click focuses the pane under it; on the process list it also
+/// selects the clicked process. True when the frame needs a redraw.
+fn handleClick(state: InputLoop, mouse: tui.key_input.Mouse) !bool {
+    if (!mouse.isLeftPress()) return false;
+    const hit = state.split.paneAt(mouse.col, mouse.row) orelse return false;
+    switch (hit.pane) {
+        .client => {
+            state.split.focusPane(.client);
+            _ = try state.session.clickListRow(hit.row);
+        },
+        .server => {
+            if (state.split.focusedPane() == .client and !try state.session.model.allowOutputFocus()) return true;
+            state.split.focusPane(.server);
+        },
+    }
+    return true;
+}
+
 fn renderFrame(
     session: *tui.client_session.ClientSession,
-    split: *const tui.split_model.Model,
+    split: *tui.split_model.Model,
     output_state: *server_output.State,
     output: io.Output,
 ) !void {
     const placeholder = std.mem.trim(u8, split.app_config.layout.placeholder_banner, " \t\r\n");
-    const server_text = try output_state.renderText(split, session.model.active_proc_id, placeholder);
+    const server_text = try output_state.renderText(split, session.model.active_proc_id, placeholder, split.takeScroll());
     defer session.allocator.free(server_text);
     try render.frame(session, split, server_text, output);
 }
@@ -353,6 +411,7 @@ fn syncClientTerminalSize(
     if (height > 0) session.model.term_height = @intCast(height);
 
     session.model.show_panel_headers = true;
+    session.model.zoom_available = true;
 }
 
 fn processLabels(
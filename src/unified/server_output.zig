@@ -80,21 +80,40 @@ pub const State = struct {
         self.processes.deinit();
     }
 
+    /// Drops the cached terminal for `id` after its output was cleared, so the
+    /// next render starts from an empty screen instead of stale contents.
+    pub fn forgetProcess(self: *State, id: domain.process.ProcessId) !void {
+        if (self.processes.fetchRemove(id)) |entry| {
+            var process = entry.value;
+            process.deinit();
+        }
+        if (self.child) |*child| {
+            if (child.selected_process_id != id) return;
+            // renderChild resizes before writing, so the initial size is moot.
+            const fresh = try terminal.ghostty_vt.Terminal.init(self.allocator, 1, 1);
+            child.terminal.deinit();
+            child.terminal = fresh;
+            child.has_output = false;
+        }
+    }
+
     /// Renders the active server pane from either child-primary PTY bytes or an
     /// in-process Primary Server, keeping tests and production on one path.
+    /// `scroll` moves the viewport after new output has been applied.
     pub fn renderText(
         self: *State,
         split: *const tui.split_model.Model,
         active_proc_id: domain.process.ProcessId,
         placeholder: []const u8,
+        scroll: tui.split_model.Scroll,
     ) ![]const u8 {
         const size = split.serverSize();
         const cols = dimension(size.width);
         const rows = dimension(size.height);
 
         return switch (self.target) {
-            .child => |child| self.renderChild(child, active_proc_id, cols, rows, placeholder),
-            .in_process => |server| self.renderProcess(server, active_proc_id, cols, rows, placeholder),
+            .child => |child| self.renderChild(child, active_proc_id, cols, rows, placeholder, scroll),
+            .in_process => |server| self.renderProcess(server, active_proc_id, cols, rows, placeholder, scroll),
         };
     }
 
@@ -142,6 +161,7 @@ pub const State = struct {
         cols: u16,
         rows: u16,
         placeholder: []const u8,
+        scroll: tui.split_model.Scroll,
     ) ![]const u8 {
         if (self.child == null) {
             self.child = .{
@@ -166,6 +186,7 @@ pub const State = struct {
         }
 
         if (!state.has_output) return self.allocator.dupe(u8, placeholder);
+        applyScroll(&state.terminal, scroll);
         return state.terminal.renderText(self.allocator);
     }
 
@@ -176,6 +197,7 @@ pub const State = struct {
         cols: u16,
         rows: u16,
         placeholder: []const u8,
+        scroll: tui.split_model.Scroll,
     ) ![]const u8 {
         if (active_proc_id.isNone()) return self.allocator.dupe(u8, placeholder);
 
@@ -208,6 +230,7 @@ pub const State = struct {
             process.consumed_len = scrollback.len;
         }
 
+        applyScroll(&process.terminal, scroll);
         return process.terminal.renderText(self.allocator);
     }
 };
@@ -223,6 +246,13 @@ fn bytesForSelectedProcess(state: *State.ChildState, bytes: []const u8) ![]const
     return pending[reset_index..];
 }
 
+fn applyScroll(term: *terminal.ghostty_vt.Terminal, scroll: tui.split_model.Scroll) void {
+    switch (scroll) {
+        .follow => term.scrollToBottom(),
+        .hold => |delta| term.scrollViewport(delta),
+    }
+}
+
 fn dimension(value: i32) u16 {
     if (value <= 0) return 1;
     return @intCast(@min(value, std.math.maxInt(u16)));
@@ -252,11 +282,11 @@ test "child target shows placeholder immediately when active process changes bef
     var output = try State.init(std.testing.allocator, .{ .child = &child });
     defer output.deinit();
 
-    const first = try output.renderText(&split, domain.process.ProcessId.fromInt(1), "NO PROCESS");
+    const first = try output.renderText(&split, domain.process.ProcessId.fromInt(1), "NO PROCESS", .follow);
     defer std.testing.allocator.free(first);
     try std.testing.expect(std.mem.indexOf(u8, first, "OLD_RUNNING_OUTPUT") != null);
 
-    const second = try output.renderText(&split, domain.process.ProcessId.fromInt(2), "NO PROCESS");
+    const second = try output.renderText(&split, domain.process.ProcessId.fromInt(2), "NO PROCESS", .follow);
     defer std.testing.allocator.free(second);
     try std.testing.expectEqualStrings("NO PROCESS", second);
 }
@@ -285,17 +315,17 @@ test "child target ignores stale bytes queued before new process snapshot" {
     var output = try State.init(std.testing.allocator, .{ .child = &child });
     defer output.deinit();
 
-    const first = try output.renderText(&split, domain.process.ProcessId.fromInt(1), "NO PROCESS");
+    const first = try output.renderText(&split, domain.process.ProcessId.fromInt(1), "NO PROCESS", .follow);
     defer std.testing.allocator.free(first);
     try std.testing.expect(std.mem.indexOf(u8, first, "OLD_RUNNING_OUTPUT") != null);
 
     try child.output.appendSlice("LATE_OLD_OUTPUT\n");
-    const second = try output.renderText(&split, domain.process.ProcessId.fromInt(2), "NO PROCESS");
+    const second = try output.renderText(&split, domain.process.ProcessId.fromInt(2), "NO PROCESS", .follow);
     defer std.testing.allocator.free(second);
     try std.testing.expectEqualStrings("NO PROCESS", second);
 
     try child.output.appendSlice("\x1b[2J\x1b[HNEW_PROCESS_OUTPUT\n");
-    const third = try output.renderText(&split, domain.process.ProcessId.fromInt(2), "NO PROCESS");
+    const third = try output.renderText(&split, domain.process.ProcessId.fromInt(2), "NO PROCESS", .follow);
     defer std.testing.allocator.free(third);
     try std.testing.expectEqualStrings("NEW_PROCESS_OUTPUT", third);
 }
@@ -325,7 +355,7 @@ test "child target reports pending output only when child output advances" {
     try std.testing.expect(try output.hasPendingOutput(domain.process.ProcessId.fromInt(1)));
 
     try child.output.appendSlice("FIRST\n");
-    const first = try output.renderText(&split, domain.process.ProcessId.fromInt(1), "NO PROCESS");
+    const first = try output.renderText(&split, domain.process.ProcessId.fromInt(1), "NO PROCESS", .follow);
     defer std.testing.allocator.free(first);
 
     try std.testing.expect(!try output.hasPendingOutput(domain.process.ProcessId.fromInt(1)));
@@ -2,6 +2,7 @@
 //! The generated version option is isolated here so CLI and packaging code can read one stable value.
 
 const std = @import("std");
+const builtin = @import("builtin");
 const options = @import("version_options");
 
 pub const app_name = "proctmux";
@@ -11,6 +12,13 @@ pub fn banner() []const u8 {
     return app_name ++ " " ++ version;
 }
 
+/// Toolchain and target the binary was built for, printed under the banner by
+/// `--version` for bug reports.
+pub fn buildInfo() []const u8 {
+    return "zig " ++ builtin.zig_version_string ++ " " ++ @tagName(builtin.cpu.arch) ++ "-" ++ @tagName(builtin.os.tag);
+}
+
 test "banner includes app name and configured version" {
     try std.testing.expectEqualStrings(app_name ++ " " ++ version, banner());
+    try std.testing.expect(std.mem.startsWith(u8, buildInfo(), "zig " ++ builtin.zig_version_string ++ " "));
 }